@@ -0,0 +1,185 @@
+use crate::{pow10, ticks, MAX_TICKS};
+use alloc::vec::Vec;
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+const MANTISSAS: [f64; 3] = [1.0, 2.0, 5.0];
+
+/// Generates ticks for two axes sharing a plot, such as temperature on the left and humidity on
+/// the right, with the same number of ticks at the same relative positions so that the gridlines
+/// of one axis are those of the other.
+///
+/// Each axis gets a step of 1, 2 or 5 times a power of ten and a domain of the same number of
+/// steps enclosing its own domain. The number of steps is searched from about half to twice
+/// `count`, preferring the least unused space on the two axes together and then the number of
+/// steps nearest `count`. Either domain may be reversed.
+///
+/// If either domain is a point or not finite, or `count` is zero, no alignment is possible and
+/// the [`ticks`] of each domain are returned instead.
+///
+/// ```
+/// use axis_ticks::aligned_ticks;
+///
+/// let (celsius, humidity) = aligned_ticks((-5.0, 35.0), (20.0, 90.0), 5);
+///
+/// assert_eq!(celsius, [-5.0, 0.0, 5.0, 10.0, 15.0, 20.0, 25.0, 30.0, 35.0]);
+/// assert_eq!(humidity, [20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0, 100.0]);
+/// ```
+pub fn aligned_ticks<T: Float + FloatConst + FromPrimitive>(
+    a: (T, T),
+    b: (T, T),
+    count: usize,
+) -> (Vec<T>, Vec<T>) {
+    let valid = |(start, stop): (T, T)| start.is_finite() && stop.is_finite() && start != stop;
+    if count == 0 || !valid(a) || !valid(b) {
+        return (ticks(a.0, a.1, count), ticks(b.0, b.1, count));
+    }
+
+    let weight = T::from_f64(0.5).unwrap();
+    let fewest = count.div_ceil(2).max(1);
+    let most = count.saturating_mul(2).min(MAX_TICKS - 1);
+    let best = (fewest..=most)
+        .filter_map(|intervals| {
+            let steps = (aligned_step(a, intervals)?, aligned_step(b, intervals)?);
+            let unused = steps.0.unused + steps.1.unused;
+            let distance =
+                T::from_usize(intervals.abs_diff(count)).unwrap() / T::from_usize(count).unwrap();
+            Some((unused + weight * distance, intervals, steps))
+        })
+        .min_by(|x, y| x.0.partial_cmp(&y.0).unwrap());
+
+    match best {
+        Some((_, intervals, (step_a, step_b))) => {
+            (step_a.ticks(a, intervals), step_b.ticks(b, intervals))
+        }
+        None => (ticks(a.0, a.1, count), ticks(b.0, b.1, count)),
+    }
+}
+
+/// A nice step of `mantissa * 10^power` and the multiple of it an aligned domain starts at.
+#[derive(Debug, Clone, Copy)]
+struct AlignedStep<T> {
+    mantissa: T,
+    power: i32,
+    first: T,
+    /// The fraction of the aligned domain outside the domain.
+    unused: T,
+}
+
+impl<T: Float + FromPrimitive> AlignedStep<T> {
+    /// The `intervals + 1` ticks, in the direction of `domain`, each the value nearest its
+    /// exact decimal.
+    fn ticks(&self, (start, stop): (T, T), intervals: usize) -> Vec<T> {
+        let mut ticks: Vec<T> = (0..=intervals)
+            .map(|i| {
+                let multiple = (self.first + T::from_usize(i).unwrap()) * self.mantissa;
+                if self.power < 0 {
+                    multiple / pow10(-self.power)
+                } else {
+                    multiple * pow10(self.power)
+                }
+            })
+            .collect();
+        if stop < start {
+            ticks.reverse();
+        }
+        ticks
+    }
+}
+
+/// The smallest nice step of which `intervals` enclose `domain` from a multiple of it, or `None`
+/// if there is no such step.
+fn aligned_step<T: Float + FromPrimitive>(
+    (start, stop): (T, T),
+    intervals: usize,
+) -> Option<AlignedStep<T>> {
+    let (lo, hi) = if stop < start {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+    let n = T::from_usize(intervals)?;
+    let exact = (hi - lo) / n;
+    if !exact.is_finite() {
+        return None;
+    }
+
+    // From a decade below the exact step, which `log10` may overshoot, up the 1, 2, 5 ladder.
+    let lowest = exact.log10().floor().to_i32()? - 1;
+    (0..9).find_map(|i| {
+        let mantissa = T::from_f64(MANTISSAS[i % 3]).unwrap();
+        let power = lowest + (i / 3) as i32;
+        let step = if power < 0 {
+            mantissa / pow10(-power)
+        } else {
+            mantissa * pow10(power)
+        };
+        let first = (lo / step).floor();
+        if (first + n) * step >= hi {
+            Some(AlignedStep {
+                mantissa,
+                power,
+                first,
+                unused: T::one() - (hi - lo) / (n * step),
+            })
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_aligned(a: (f64, f64), b: (f64, f64), count: usize) {
+        let (ticks_a, ticks_b) = aligned_ticks(a, b, count);
+
+        assert_eq!(ticks_a.len(), ticks_b.len());
+        for (ticks, (start, stop)) in [(&ticks_a, a), (&ticks_b, b)] {
+            let (lo, hi) = (start.min(stop), start.max(stop));
+            assert!(ticks[0].min(ticks[ticks.len() - 1]) <= lo);
+            assert!(ticks[0].max(ticks[ticks.len() - 1]) >= hi);
+            let step = ticks[1] - ticks[0];
+            assert!(ticks
+                .windows(2)
+                .all(|pair| ((pair[1] - pair[0]) - step).abs() <= step.abs() * 1e-9));
+        }
+    }
+
+    #[test]
+    fn aligns_the_gridlines_of_two_axes() {
+        assert_aligned((-5.0, 35.0), (20.0, 90.0), 5);
+        assert_aligned((0.0, 1.0), (0.0, 1000.0), 10);
+        assert_aligned((0.013, 0.027), (-3e6, 7e6), 4);
+        assert_aligned((100.0, 0.0), (0.5, 0.75), 6);
+        assert_aligned((-1.0, 1.0), (17.0, 19.0), 1);
+
+        assert_eq!(
+            aligned_ticks((0.0, 10.0), (0.0, 100.0), 5),
+            (
+                vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0],
+                vec![0.0, 20.0, 40.0, 60.0, 80.0, 100.0]
+            )
+        );
+        assert_eq!(
+            aligned_ticks((10.0, 0.0), (0.0, 1.0), 2),
+            (vec![10.0, 5.0, 0.0], vec![0.0, 0.5, 1.0])
+        );
+    }
+
+    #[test]
+    fn falls_back_to_independent_ticks() {
+        assert_eq!(
+            aligned_ticks((3.0, 3.0), (0.0, 1.0), 5),
+            (vec![3.0], ticks(0.0, 1.0, 5))
+        );
+        assert_eq!(
+            aligned_ticks((f64::NAN, 1.0), (0.0, 1.0), 5),
+            (vec![], ticks(0.0, 1.0, 5))
+        );
+        assert_eq!(aligned_ticks((0.0, 1.0), (0.0, 2.0), 0), (vec![], vec![]));
+    }
+}
@@ -0,0 +1,395 @@
+use crate::{ticks, MAX_TICKS};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// The steps in degrees [`angle_ticks`] chooses from, each dividing the next larger ones' turns.
+const ANGLE_STEPS: [f64; 8] = [1.0, 5.0, 15.0, 30.0, 45.0, 90.0, 180.0, 360.0];
+
+/// The steps [`radian_ticks`] chooses from, as fractions `(numerator, denominator)` of π.
+const RADIAN_STEPS: [(u32, u32); 6] = [(1, 12), (1, 6), (1, 4), (1, 2), (1, 1), (2, 1)];
+
+/// Nicely rounded angular steps in degrees, all of which divide a full turn.
+const POLAR_STEPS: [f64; 12] = [
+    1.0, 2.0, 5.0, 10.0, 15.0, 30.0, 45.0, 60.0, 90.0, 120.0, 180.0, 360.0,
+];
+
+/// Generates the ticks for a full polar grid as `(radius_ticks, angle_ticks_degrees)`.
+///
+/// The radii are nicely rounded ticks between `r_min` and `r_max`. The angles start at 0° and are
+/// evenly spaced by whichever divisor of 360° gives closest to `angle_count` spokes.
+pub fn polar_grid(
+    r_min: f64,
+    r_max: f64,
+    r_count: usize,
+    angle_count: usize,
+) -> (Vec<f64>, Vec<f64>) {
+    let radii = ticks(r_min, r_max, r_count);
+
+    if angle_count == 0 {
+        return (radii, vec![]);
+    }
+
+    let ideal = 360.0 / angle_count as f64;
+    let step = POLAR_STEPS
+        .iter()
+        .copied()
+        .min_by(|a, b| (a - ideal).abs().partial_cmp(&(b - ideal).abs()).unwrap())
+        .unwrap();
+    let spokes = Float::round(360.0 / step) as usize;
+    let angles = (0..spokes).map(|i| i as f64 * step).collect();
+
+    (radii, angles)
+}
+
+/// Like [`ticks`] but for a domain in degrees, with steps of 1°, 5°, 15°, 30°, 45°, 90° or 180°
+/// rather than steps like 20° or 50°, for compass plots, polar charts and rotation widgets.
+///
+/// The step is the one nearest the exact step on a log scale. Steps finer than a degree are
+/// chosen as by [`ticks`], and steps coarser than half a turn as by [`ticks`] in whole turns. The
+/// ticks are positions in the domain, so they keep counting past 360°; label them with
+/// [`wrap_degrees`]. A domain of exactly one turn leaves out its closing tick, which would
+/// coincide with the first.
+///
+/// ```
+/// use axis_ticks::{angle_ticks, wrap_degrees};
+///
+/// assert_eq!(angle_ticks(0.0, 360.0, 4), [0.0, 90.0, 180.0, 270.0]);
+/// assert_eq!(angle_ticks(0.0, 100.0, 5), [0.0, 15.0, 30.0, 45.0, 60.0, 75.0, 90.0]);
+///
+/// let labels: Vec<f64> = angle_ticks(330.0, 390.0, 4).into_iter().map(wrap_degrees).collect();
+/// assert_eq!(labels, [330.0, 345.0, 0.0, 15.0, 30.0]);
+/// ```
+pub fn angle_ticks<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Vec<T> {
+    if count == 0 || !start.is_finite() || !stop.is_finite() {
+        return vec![];
+    }
+    if start == stop {
+        return vec![start];
+    }
+
+    let turn = T::from_f64(360.0).unwrap();
+    let span = (stop - start).abs();
+    let exact = span / T::from_usize(count).unwrap();
+    let finest = T::from_f64(ANGLE_STEPS[0] * core::f64::consts::FRAC_1_SQRT_2).unwrap();
+    let coarsest = T::from_f64((180.0f64 * 360.0).sqrt()).unwrap();
+
+    let turns = || {
+        ticks(start / turn, stop / turn, count)
+            .into_iter()
+            .map(|tick| tick * turn)
+            .collect()
+    };
+
+    // The span of a domain near the limits of `T` can overflow to infinity, which is coarser than
+    // any step, so `ticks` takes it in whole turns.
+    let mut ticks = if exact < finest {
+        ticks(start, stop, count)
+    } else if exact > coarsest {
+        turns()
+    } else {
+        let steps = ANGLE_STEPS.map(|step| T::from_f64(step).unwrap());
+        steps[nearest_step(steps.iter().copied(), exact)..]
+            .iter()
+            .find_map(|&step| step_multiples(start, stop, step, |index| index * step))
+            .unwrap_or_else(turns)
+    };
+
+    if span == turn && ticks.len() > 1 && (ticks[ticks.len() - 1] - ticks[0]).abs() == turn {
+        ticks.pop();
+    }
+    ticks
+}
+
+/// Like [`ticks`] but for a domain in radians, with steps of π/12, π/6, π/4, π/2, π or 2π, so
+/// that the ticks are fractions of π such as π/4, π/2 and 3π/4. Label them with
+/// [`format_pi_fraction`].
+///
+/// Coarser steps are 1, 2 or 5 times a power of ten times π, as by [`ticks`] in units of π, and
+/// steps finer than π/12 are those of [`ticks`].
+///
+/// ```
+/// use axis_ticks::{format_pi_fraction, radian_ticks};
+/// use std::f64::consts::PI;
+///
+/// let labels: Vec<String> = radian_ticks(0.0, PI, 4)
+///     .into_iter()
+///     .map(|tick| format_pi_fraction(tick, 12))
+///     .collect();
+///
+/// assert_eq!(labels, ["0", "π/4", "π/2", "3π/4", "π"]);
+/// ```
+pub fn radian_ticks<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Vec<T> {
+    if count == 0 || !start.is_finite() || !stop.is_finite() {
+        return vec![];
+    }
+    if start == stop {
+        return vec![start];
+    }
+
+    let pi = T::PI();
+    // The exact step in units of π.
+    let exact = (stop - start).abs() / T::from_usize(count).unwrap() / pi;
+    // Halfway on a log scale from π/12 to π/24, and from 2π to the 5π that `ticks` would take.
+    let finest = T::from_f64(core::f64::consts::FRAC_1_SQRT_2 / 12.0).unwrap();
+    let coarsest = T::from_f64(10f64.sqrt()).unwrap();
+
+    let pis = || {
+        ticks(start / pi, stop / pi, count)
+            .into_iter()
+            .map(|tick| tick * pi)
+            .collect()
+    };
+
+    // An overflowing span, as in `angle_ticks`, is ticked in units of π.
+    if exact < finest {
+        ticks(start, stop, count)
+    } else if exact > coarsest {
+        pis()
+    } else {
+        let steps = RADIAN_STEPS.map(|(numerator, denominator)| {
+            (
+                T::from_u32(numerator).unwrap(),
+                T::from_u32(denominator).unwrap(),
+            )
+        });
+        let nearest = nearest_step(
+            steps
+                .iter()
+                .map(|&(numerator, denominator)| numerator / denominator),
+            exact,
+        );
+
+        steps[nearest..]
+            .iter()
+            .find_map(|&(numerator, denominator)| {
+                step_multiples(start, stop, pi * numerator / denominator, |index| {
+                    pi * (index * numerator) / denominator
+                })
+            })
+            .unwrap_or_else(pis)
+    }
+}
+
+/// Formats an angle in radians as a fraction of π with a denominator of at most
+/// `max_denominator`, such as `3π/4`, `-π/2` or `2π`, or as by `Display` if it is no such
+/// fraction.
+///
+/// ```
+/// use axis_ticks::format_pi_fraction;
+/// use std::f64::consts::PI;
+///
+/// assert_eq!(format_pi_fraction(-PI / 6.0, 12), "-π/6");
+/// assert_eq!(format_pi_fraction(1.0, 12), "1");
+/// ```
+pub fn format_pi_fraction(radians: f64, max_denominator: u32) -> String {
+    let turns = radians / core::f64::consts::PI;
+    let tolerance = 1e-9 * turns.abs().max(1.0);
+
+    let fraction = (1..=max_denominator).find_map(|denominator| {
+        let numerator = (turns * f64::from(denominator)).round();
+        if (numerator / f64::from(denominator) - turns).abs() <= tolerance {
+            Some((numerator, denominator))
+        } else {
+            None
+        }
+    });
+
+    match fraction {
+        Some((0.0, _)) => "0".to_string(),
+        Some((numerator, denominator)) => {
+            let sign = if numerator < 0.0 { "-" } else { "" };
+            let coefficient = match numerator.abs() {
+                1.0 => String::new(),
+                magnitude => format!("{}", magnitude),
+            };
+            let denominator = match denominator {
+                1 => String::new(),
+                denominator => format!("/{}", denominator),
+            };
+            format!("{}{}\u{3c0}{}", sign, coefficient, denominator)
+        }
+        None => format!("{}", radians),
+    }
+}
+
+/// The index of the step nearest `exact` on a log scale.
+pub(crate) fn nearest_step<T: Float>(steps: impl Iterator<Item = T>, exact: T) -> usize {
+    steps
+        .map(|step| (step / exact).ln().abs())
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+        .unwrap()
+        .0
+}
+
+/// The multiples of `step` within the domain, in the direction from `start` to `stop`, with the
+/// tick for each index given by `tick` so that it can be computed exactly, or `None` if there
+/// would be more than [`MAX_TICKS`] of them, so that the caller can move on to a coarser step.
+pub(crate) fn step_multiples<T: Float + FromPrimitive>(
+    start: T,
+    stop: T,
+    step: T,
+    tick: impl Fn(T) -> T,
+) -> Option<Vec<T>> {
+    let (lo, hi) = if stop < start {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+    let first = (lo / step).ceil();
+    let len = ((hi / step).floor() - first + T::one())
+        .to_usize()
+        .filter(|&len| len <= MAX_TICKS)?;
+    let mut ticks: Vec<T> = (0..len)
+        .map(|i| tick(first + T::from_usize(i).unwrap()))
+        .collect();
+    if stop < start {
+        ticks.reverse();
+    }
+    Some(ticks)
+}
+
+/// Wraps an angle in degrees into `[0, 360)`, such as `370°` to `10°` and `-90°` to `270°`, for
+/// labeling the ticks of [`angle_ticks`].
+pub fn wrap_degrees<T: Float + FromPrimitive>(degrees: T) -> T {
+    let turn = T::from_f64(360.0).unwrap();
+    let wrapped = degrees % turn;
+    let wrapped = if wrapped < T::zero() {
+        wrapped + turn
+    } else {
+        wrapped
+    };
+
+    // A tiny negative angle can round up to a whole turn, and `-0.0` should read as 0.
+    if wrapped >= turn || wrapped.is_zero() {
+        T::zero()
+    } else {
+        wrapped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polar_grid_ticks_radii_and_angles() {
+        assert_eq!(
+            polar_grid(0.0, 100.0, 5, 8),
+            (
+                vec![0.0, 20.0, 40.0, 60.0, 80.0, 100.0],
+                vec![0.0, 45.0, 90.0, 135.0, 180.0, 225.0, 270.0, 315.0]
+            )
+        );
+    }
+
+    #[test]
+    fn polar_grid_rounds_angle_count_to_a_divisor_of_360() {
+        assert_eq!(
+            polar_grid(0.0, 1.0, 1, 5).1,
+            [0.0, 60.0, 120.0, 180.0, 240.0, 300.0]
+        );
+        assert_eq!(polar_grid(0.0, 1.0, 1, 1).1, [0.0]);
+        assert!(polar_grid(0.0, 1.0, 1, 0).1.is_empty());
+        assert_eq!(polar_grid(0.0, 1.0, 1, 1000).1.len(), 360);
+    }
+
+    #[test]
+    fn angle_ticks_use_the_degree_ladder() {
+        assert_eq!(angle_ticks(0.0, 180.0, 4), [0.0, 45.0, 90.0, 135.0, 180.0]);
+        assert_eq!(angle_ticks(0.0, 360.0, 12).len(), 12);
+        assert_eq!(
+            angle_ticks(0.0, 90.0, 10),
+            [0.0, 15.0, 30.0, 45.0, 60.0, 75.0, 90.0]
+        );
+        assert_eq!(angle_ticks(0.0, 10.0, 10).len(), 11);
+        assert_eq!(angle_ticks(0.0, 1.0, 10), ticks(0.0, 1.0, 10));
+        assert_eq!(angle_ticks(90.0, -90.0, 2), [90.0, 0.0, -90.0]);
+        assert_eq!(
+            angle_ticks(0.0, 3600.0, 5),
+            [0.0, 720.0, 1440.0, 2160.0, 2880.0, 3600.0]
+        );
+    }
+
+    #[test]
+    fn angle_ticks_leave_out_the_closing_tick_of_a_turn() {
+        assert_eq!(angle_ticks(-180.0, 180.0, 4), [-180.0, -90.0, 0.0, 90.0]);
+        assert_eq!(angle_ticks(360.0, 0.0, 4), [360.0, 270.0, 180.0, 90.0]);
+        assert_eq!(angle_ticks(0.0, 720.0, 4).last(), Some(&720.0));
+    }
+
+    #[test]
+    fn angle_ticks_handle_degenerate_arguments() {
+        assert!(angle_ticks(0.0, 1e8, usize::MAX).len() <= MAX_TICKS);
+        assert!(angle_ticks(0.0, 1e8, 1_000_000).len() <= MAX_TICKS);
+        assert!(radian_ticks(0.0, 1e6, usize::MAX).len() <= MAX_TICKS);
+        assert!(radian_ticks(0.0, 1e5, 1_000_000).len() <= MAX_TICKS);
+        assert!(angle_ticks(0.0, 360.0, 0).is_empty());
+        assert!(angle_ticks(f64::NAN, 360.0, 4).is_empty());
+        assert_eq!(angle_ticks(45.0, 45.0, 4), [45.0]);
+        assert_eq!(angle_ticks(-f64::MAX, f64::MAX, 4).len(), 5);
+    }
+
+    #[test]
+    fn wrap_degrees_wraps_into_one_turn() {
+        assert_eq!(wrap_degrees(370.0), 10.0);
+        assert_eq!(wrap_degrees(-90.0), 270.0);
+        assert_eq!(wrap_degrees(720.0), 0.0);
+        assert_eq!(wrap_degrees(-0.0), 0.0);
+        assert_eq!(wrap_degrees(-1e-20), 0.0);
+        assert_eq!(wrap_degrees(359.5f32), 359.5);
+    }
+
+    #[test]
+    fn radian_ticks_are_fractions_of_pi() {
+        use core::f64::consts::PI;
+
+        let labels = |start: f64, stop: f64, count: usize| -> Vec<String> {
+            radian_ticks(start, stop, count)
+                .into_iter()
+                .map(|tick| format_pi_fraction(tick, 12))
+                .collect()
+        };
+
+        assert_eq!(labels(-PI, PI, 4), ["-π", "-π/2", "0", "π/2", "π"]);
+        assert_eq!(labels(0.0, 2.0 * PI, 2), ["0", "π", "2π"]);
+        assert_eq!(
+            labels(0.0, PI / 2.0, 6),
+            ["0", "π/12", "π/6", "π/4", "π/3", "5π/12", "π/2"]
+        );
+        assert_eq!(labels(0.0, 20.0 * PI, 2), ["0", "10π", "20π"]);
+        assert_eq!(radian_ticks(0.0, 0.1, 5), ticks(0.0, 0.1, 5));
+        assert_eq!(radian_ticks(PI, 0.0, 2)[0], PI);
+        assert_eq!(radian_ticks(0.0, PI / 4.0, 3)[3], PI / 4.0);
+        assert!(radian_ticks(0.0, PI, 0).is_empty());
+        assert_eq!(radian_ticks(1.0, 1.0, 4), [1.0]);
+        assert_eq!(radian_ticks(-f64::MAX, f64::MAX, 4).len(), 5);
+    }
+
+    #[test]
+    fn format_pi_fraction_reduces_fractions() {
+        use core::f64::consts::PI;
+
+        assert_eq!(format_pi_fraction(PI * 6.0 / 8.0, 12), "3\u{3c0}/4");
+        assert_eq!(format_pi_fraction(-2.0 * PI, 12), "-2\u{3c0}");
+        assert_eq!(format_pi_fraction(PI / 16.0, 12), format!("{}", PI / 16.0));
+        assert_eq!(format_pi_fraction(PI / 16.0, 16), "\u{3c0}/16");
+        assert_eq!(format_pi_fraction(-0.0, 12), "0");
+    }
+}
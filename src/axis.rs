@@ -0,0 +1,247 @@
+use crate::{
+    format::format_fixed, layout::fit_labels, nice, tick_decimals, tick_format, ticks, ticks_iter,
+    LinearScale, Orientation, Tick, TickKind, TickNotation,
+};
+use alloc::{string::String, vec::Vec};
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// Everything needed to render an axis, from [`describe_axis`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AxisDescription<T> {
+    /// The ticks over [`niced_domain`](AxisDescription::niced_domain).
+    pub ticks: Vec<T>,
+    /// The signed distance between consecutive ticks, or NaN if there are fewer than two.
+    pub step: T,
+    /// The decimal places needed to label the ticks.
+    pub decimals: usize,
+    /// The domain expanded outwards so that it starts and ends on a tick.
+    pub niced_domain: (T, T),
+    /// The ticks formatted with `decimals` decimal places.
+    pub labels: Vec<String>,
+}
+
+/// Nices the domain, generates its ticks and labels them with just enough decimal places.
+pub fn describe_axis<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> AxisDescription<T> {
+    let niced_domain = nice(start, stop, count);
+    let iter = ticks_iter(niced_domain.0, niced_domain.1, count);
+    let step = iter.step();
+    let ticks: Vec<T> = iter.collect();
+    let decimals = tick_decimals(niced_domain.0, niced_domain.1, count);
+    let labels = ticks
+        .iter()
+        .map(|tick| format_fixed(tick.to_f64().unwrap(), decimals))
+        .collect();
+
+    AxisDescription {
+        ticks,
+        step,
+        decimals,
+        niced_domain,
+        labels,
+    }
+}
+
+/// An axis mapping a domain of data values onto a range of pixels, which lays out its ticks with
+/// their labels and positions for any renderer.
+///
+/// The labels are formatted by [`tick_format`] in the axis's [`TickNotation`], fixed-point by
+/// default, with the precision the step needs.
+///
+/// ```
+/// use axis_ticks::{Axis, TickNotation};
+///
+/// let axis = Axis::new((0.0, 2e-3), (0.0, 400.0))
+///     .count(4)
+///     .notation(TickNotation::SiPrefix);
+///
+/// let layout = axis.layout();
+/// assert_eq!(layout[1].value, 5e-4);
+/// assert_eq!(layout[1].label, "0.5m");
+/// assert_eq!(layout[1].px, 100.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Axis {
+    scale: LinearScale<f64>,
+    orientation: Orientation,
+    count: usize,
+    notation: TickNotation,
+}
+
+/// A tick laid out by [`Axis::layout`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlacedTick {
+    /// The position of the tick in the domain.
+    pub value: f64,
+    /// The value formatted by the axis.
+    pub label: String,
+    /// The position of the tick in the pixel range.
+    pub px: f64,
+}
+
+impl Axis {
+    /// Creates a horizontal axis mapping `domain.0` to `range.0` and `domain.1` to `range.1`
+    /// pixels, with a count of 10 as in d3. Either may be reversed, as a y axis usually maps its
+    /// domain from the bottom of the plot up.
+    pub fn new(domain: (f64, f64), range: (f64, f64)) -> Self {
+        Axis {
+            scale: LinearScale::new(domain, range),
+            orientation: Orientation::Horizontal,
+            count: 10,
+            notation: TickNotation::Fixed,
+        }
+    }
+
+    /// Sets the direction of the axis, which decides how much room its labels take up in
+    /// [`layout_fitting`](Axis::layout_fitting).
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the approximate number of ticks, as for [`ticks`].
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Sets the notation the labels are written in.
+    pub fn notation(mut self, notation: TickNotation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// The scale from the domain to the pixel range.
+    pub fn scale(&self) -> LinearScale<f64> {
+        self.scale
+    }
+
+    /// Generates the ticks with their labels and pixel positions.
+    pub fn layout(&self) -> Vec<PlacedTick> {
+        self.placed(self.labeled(self.count))
+    }
+
+    /// Like [`layout`](Axis::layout) but lowers the count, or thins the ticks, until no two
+    /// labels are closer than `padding` pixels, measuring each label with `measure` as
+    /// [`labeled_ticks_fitting`](crate::labeled_ticks_fitting) does.
+    pub fn layout_fitting<F: Fn(&str) -> f32>(&self, padding: f32, measure: F) -> Vec<PlacedTick> {
+        let ticks = fit_labels(
+            self.count,
+            self.orientation,
+            padding,
+            measure,
+            |count| self.labeled(count),
+            |value| self.scale.map(value),
+        );
+        self.placed(ticks)
+    }
+
+    fn labeled(&self, count: usize) -> Vec<Tick<f64>> {
+        let (start, stop) = self.scale.domain();
+        let format = tick_format(start, stop, count, self.notation);
+        ticks(start, stop, count)
+            .into_iter()
+            .map(|value| Tick {
+                value,
+                label: format.format(value),
+                kind: TickKind::Major,
+            })
+            .collect()
+    }
+
+    fn placed(&self, ticks: Vec<Tick<f64>>) -> Vec<PlacedTick> {
+        ticks
+            .into_iter()
+            .map(|tick| PlacedTick {
+                value: tick.value,
+                px: self.scale.map(tick.value),
+                label: tick.label,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_a_niced_axis() {
+        let axis = describe_axis(0.13, 0.97, 10);
+
+        assert_eq!(
+            axis.ticks,
+            [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0]
+        );
+        assert!((axis.step - 0.1).abs() < 1e-12);
+        assert_eq!(axis.decimals, 1);
+        assert_eq!(axis.niced_domain, (0.1, 1.0));
+        assert_eq!(
+            axis.labels,
+            ["0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0"]
+        );
+    }
+
+    #[test]
+    fn describes_a_reversed_axis() {
+        let axis = describe_axis(97.0, 13.0, 5);
+
+        assert_eq!(axis.niced_domain, (100.0, 0.0));
+        assert_eq!(axis.ticks, [100.0, 80.0, 60.0, 40.0, 20.0, 0.0]);
+        assert_eq!(axis.step, -20.0);
+        assert_eq!(axis.labels, ["100", "80", "60", "40", "20", "0"]);
+    }
+
+    #[test]
+    fn lays_out_positioned_labeled_ticks() {
+        let layout = Axis::new((0.0, 1.0), (300.0, 0.0)).count(4).layout();
+
+        let placed: Vec<(f64, &str, f64)> = layout
+            .iter()
+            .map(|tick| (tick.value, tick.label.as_str(), tick.px))
+            .collect();
+        assert_eq!(
+            placed,
+            [
+                (0.0, "0.0", 300.0),
+                (0.2, "0.2", 240.0),
+                (0.4, "0.4", 180.0),
+                (0.6, "0.6", 120.0),
+                (0.8, "0.8", 60.0),
+                (1.0, "1.0", 0.0)
+            ]
+        );
+
+        let percent = Axis::new((0.0, 1.0), (0.0, 100.0))
+            .count(2)
+            .notation(TickNotation::Percent);
+        let labels: Vec<String> = percent
+            .layout()
+            .into_iter()
+            .map(|tick| tick.label)
+            .collect();
+        assert_eq!(labels, ["0%", "50%", "100%"]);
+        assert!(Axis::new((0.0, 1.0), (0.0, 100.0))
+            .count(0)
+            .layout()
+            .is_empty());
+    }
+
+    #[test]
+    fn lays_out_labels_that_fit() {
+        let measure = |label: &str| label.len() as f32 * 7.0;
+        let axis = Axis::new((0.0, 1e6), (0.0, 300.0));
+
+        assert_eq!(axis.layout().len(), 11);
+        assert_eq!(axis.layout_fitting(8.0, measure).len(), 6);
+
+        let vertical = axis.orientation(Orientation::Vertical { line_height: 12.0 });
+        assert_eq!(vertical.layout_fitting(4.0, measure).len(), 11);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::ticks;
+use alloc::vec::Vec;
+
+/// The ticks for an axis with a break between two disjoint ranges, from [`broken_axis_ticks`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrokenAxis {
+    /// Ticks within the lower range, ascending.
+    pub lower_ticks: Vec<f64>,
+    /// Ticks within the upper range, ascending.
+    pub upper_ticks: Vec<f64>,
+    /// The gap the break marker covers, from the top of the lower range to the bottom of the
+    /// upper range.
+    pub break_at: (f64, f64),
+}
+
+/// Generates separate ticks for the two ranges of a broken axis and locates the break between
+/// them.
+///
+/// Each range may be given in either order. Ticks are kept within their own range so none are
+/// drawn inside the gap.
+pub fn broken_axis_ticks(
+    lower: (f64, f64),
+    upper: (f64, f64),
+    lower_count: usize,
+    upper_count: usize,
+) -> BrokenAxis {
+    let lower = ascending(lower);
+    let upper = ascending(upper);
+
+    BrokenAxis {
+        lower_ticks: ticks_within(lower, lower_count),
+        upper_ticks: ticks_within(upper, upper_count),
+        break_at: (lower.1, upper.0),
+    }
+}
+
+fn ascending((a, b): (f64, f64)) -> (f64, f64) {
+    if b < a {
+        (b, a)
+    } else {
+        (a, b)
+    }
+}
+
+fn ticks_within((lo, hi): (f64, f64), count: usize) -> Vec<f64> {
+    ticks(lo, hi, count)
+        .into_iter()
+        .filter(|&tick| tick >= lo && tick <= hi)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticks_both_ranges_and_locates_the_break() {
+        assert_eq!(
+            broken_axis_ticks((0.0, 10.0), (1000.0, 1100.0), 5, 2),
+            BrokenAxis {
+                lower_ticks: vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0],
+                upper_ticks: vec![1000.0, 1050.0, 1100.0],
+                break_at: (10.0, 1000.0),
+            }
+        );
+    }
+
+    #[test]
+    fn keeps_ticks_out_of_the_gap() {
+        let axis = broken_axis_ticks((0.25, 0.125), (0.5, 0.625), 5, 5);
+
+        assert_eq!(axis.break_at, (0.25, 0.5));
+        assert!(axis.lower_ticks.iter().all(|t| (0.125..=0.25).contains(t)));
+        assert!(axis.upper_ticks.iter().all(|t| (0.5..=0.625).contains(t)));
+    }
+}
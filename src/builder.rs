@@ -0,0 +1,340 @@
+use crate::{close_ticks, nice, nice_with_multiples, ticks, Interval, TickRange, MAX_TICKS};
+use alloc::vec::Vec;
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// A configured tick generator, created with [`Ticks::builder`].
+///
+/// The configuration can be reused for other domains with [`Ticks::generate_for`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ticks<T> {
+    domain: (T, T),
+    count: usize,
+    include_zero: bool,
+    nice: bool,
+    closed: bool,
+    interval: Interval,
+    anchor: T,
+    multiples: Option<Vec<T>>,
+    max_ticks: usize,
+}
+
+/// Configures a [`Ticks`] generator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TicksBuilder<T> {
+    ticks: Ticks<T>,
+}
+
+impl<T: Float + FloatConst + FromPrimitive> Ticks<T> {
+    /// Starts configuring a generator with d3's defaults, a domain of `[0, 1]` and a count of 10.
+    pub fn builder() -> TicksBuilder<T> {
+        TicksBuilder {
+            ticks: Ticks {
+                domain: (T::zero(), T::one()),
+                count: 10,
+                include_zero: false,
+                nice: false,
+                closed: false,
+                interval: Interval::Closed,
+                anchor: T::zero(),
+                multiples: None,
+                max_ticks: MAX_TICKS,
+            },
+        }
+    }
+
+    /// Generates the ticks for the configured domain.
+    pub fn generate(&self) -> Vec<T> {
+        self.generate_for(self.domain.0, self.domain.1)
+    }
+
+    /// Generates the ticks for another domain with the same configuration.
+    pub fn generate_for(&self, start: T, stop: T) -> Vec<T> {
+        let (start, stop) = self.domain_for(start, stop);
+        let anchor = self.anchor;
+        let range = TickRange::with_multiples(
+            start - anchor,
+            stop - anchor,
+            self.count,
+            self.max_ticks,
+            self.multiples.as_deref(),
+        );
+        let mut ticks: Vec<T> = (0..range.len()).map(|i| range.get(i) + anchor).collect();
+        ticks.retain(|&tick| self.interval.admits(tick, start, stop));
+        if self.closed {
+            close_ticks(ticks, start, stop)
+        } else {
+            ticks
+        }
+    }
+
+    /// The domain that [`Ticks::generate_for`] ticks, after including zero and nicing.
+    pub fn domain_for(&self, start: T, stop: T) -> (T, T) {
+        let (start, stop) = if self.include_zero {
+            extend_to_zero(start, stop)
+        } else {
+            (start, stop)
+        };
+
+        if self.nice {
+            let (start, stop) = match &self.multiples {
+                Some(multiples) => nice_with_multiples(
+                    start - self.anchor,
+                    stop - self.anchor,
+                    self.count,
+                    multiples,
+                ),
+                None => nice(start - self.anchor, stop - self.anchor, self.count),
+            };
+            (start + self.anchor, stop + self.anchor)
+        } else {
+            (start, stop)
+        }
+    }
+}
+
+/// Like [`ticks`](crate::ticks) but extends the domain to include zero, as for bar charts and
+/// financial axes, so that a tick always lands exactly on zero.
+///
+/// ```
+/// use axis_ticks::ticks_including_zero;
+///
+/// assert_eq!(ticks_including_zero(37.0, 91.0, 5), [0.0, 20.0, 40.0, 60.0, 80.0]);
+/// ```
+pub fn ticks_including_zero<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Vec<T> {
+    let (start, stop) = extend_to_zero(start, stop);
+    ticks(start, stop, count)
+}
+
+/// Extends a finite domain to include zero, keeping its direction.
+fn extend_to_zero<T: Float>(start: T, stop: T) -> (T, T) {
+    if !start.is_finite() || !stop.is_finite() {
+        (start, stop)
+    } else if stop < start {
+        (start.max(T::zero()), stop.min(T::zero()))
+    } else {
+        (start.min(T::zero()), stop.max(T::zero()))
+    }
+}
+
+impl<T: Float + FloatConst + FromPrimitive> TicksBuilder<T> {
+    /// Sets the domain to tick, which may be reversed.
+    pub fn domain(mut self, start: T, stop: T) -> Self {
+        self.ticks.domain = (start, stop);
+        self
+    }
+
+    /// Sets the approximate number of intervals between ticks, as for [`ticks`](crate::ticks).
+    pub fn count(mut self, count: usize) -> Self {
+        self.ticks.count = count;
+        self
+    }
+
+    /// Extends the domain to include zero, e.g. for bar charts whose bars start at zero.
+    pub fn include_zero(mut self, include_zero: bool) -> Self {
+        self.ticks.include_zero = include_zero;
+        self
+    }
+
+    /// Expands the domain to start and end on a tick, as [`nice`](crate::nice).
+    pub fn nice(mut self, nice: bool) -> Self {
+        self.ticks.nice = nice;
+        self
+    }
+
+    /// Always includes the exact endpoints of the domain, as [`ticks_closed`](crate::ticks_closed).
+    /// The endpoints are added after the ticks are limited by [`TicksBuilder::max_ticks`].
+    pub fn closed(mut self, closed: bool) -> Self {
+        self.ticks.closed = closed;
+        self
+    }
+
+    /// Sets which ticks coinciding with the ends of the domain are emitted, as
+    /// [`ticks_interval`](crate::ticks_interval). Defaults to [`Interval::Closed`].
+    pub fn interval(mut self, interval: Interval) -> Self {
+        self.ticks.interval = interval;
+        self
+    }
+
+    /// Aligns the ticks to `anchor`, so they are `anchor + k * step`, as
+    /// [`ticks_anchored`](crate::ticks_anchored). Nicing also expands the domain to a tick
+    /// aligned to `anchor`. Defaults to zero.
+    pub fn anchor(mut self, anchor: T) -> Self {
+        self.ticks.anchor = anchor;
+        self
+    }
+
+    /// Chooses the step from `multiples` of powers of ten rather than d3's 1, 2 and 5, as
+    /// [`ticks_with_multiples`](crate::ticks_with_multiples). Nicing expands the domain to the
+    /// same steps. The multiples are copied, so they can be computed at run time.
+    pub fn multiples(mut self, multiples: &[T]) -> Self {
+        self.ticks.multiples = Some(multiples.to_vec());
+        self
+    }
+
+    /// Limits the number of ticks, falling back to a coarser step as [`ticks_with_limit`](crate::ticks_with_limit) does.
+    /// Defaults to [`MAX_TICKS`].
+    pub fn max_ticks(mut self, max_ticks: usize) -> Self {
+        self.ticks.max_ticks = max_ticks;
+        self
+    }
+
+    /// Finishes configuring the generator.
+    pub fn build(self) -> Ticks<T> {
+        self.ticks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_to_the_unit_domain() {
+        assert_eq!(
+            Ticks::<f64>::builder().build().generate(),
+            ticks(0.0, 1.0, 10)
+        );
+    }
+
+    #[test]
+    fn applies_the_configuration() {
+        let generator = Ticks::builder().domain(2.0, 9.0).count(5).build();
+        assert_eq!(generator.generate(), ticks(2.0, 9.0, 5));
+
+        let generator = Ticks::builder()
+            .domain(2.0, 9.0)
+            .count(5)
+            .include_zero(true)
+            .build();
+        assert_eq!(generator.generate(), [0.0, 2.0, 4.0, 6.0, 8.0]);
+
+        let generator = Ticks::builder()
+            .domain(-9.0, -2.0)
+            .include_zero(true)
+            .nice(true)
+            .count(5)
+            .build();
+        assert_eq!(generator.domain_for(-9.0, -2.0), (-10.0, 0.0));
+        assert_eq!(generator.generate(), [-10.0, -8.0, -6.0, -4.0, -2.0, 0.0]);
+    }
+
+    #[test]
+    fn limits_the_number_of_ticks() {
+        let generator = Ticks::<f64>::builder().count(1000).max_ticks(6).build();
+
+        assert_eq!(generator.generate(), [0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+    }
+
+    #[test]
+    fn closes_the_generated_domain() {
+        let generator = Ticks::builder().count(5).closed(true).build();
+        assert_eq!(
+            generator.generate_for(3.0, 97.0),
+            [3.0, 20.0, 40.0, 60.0, 80.0, 97.0]
+        );
+
+        let generator = Ticks::builder().count(5).closed(true).nice(true).build();
+        assert_eq!(
+            generator.generate_for(3.0, 97.0),
+            [0.0, 20.0, 40.0, 60.0, 80.0, 100.0]
+        );
+    }
+
+    #[test]
+    fn drops_ticks_on_excluded_endpoints() {
+        let generator = Ticks::builder()
+            .count(5)
+            .nice(true)
+            .interval(Interval::Open)
+            .build();
+
+        assert_eq!(generator.generate_for(3.0, 97.0), [20.0, 40.0, 60.0, 80.0]);
+    }
+
+    #[test]
+    fn ticks_including_zero_always_has_a_zero_tick() {
+        for &(start, stop) in &[(37.0, 91.0), (-91.0, -37.0), (91.0, 37.0), (0.3, 7.6)] {
+            let ticks = ticks_including_zero(start, stop, 7);
+            assert!(ticks.contains(&0.0), "{:?}", ticks);
+        }
+        assert_eq!(
+            ticks_including_zero(-91.0, -37.0, 5),
+            [-80.0, -60.0, -40.0, -20.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn does_not_include_zero_in_non_finite_domains() {
+        let generator = Ticks::<f64>::builder().include_zero(true).build();
+
+        assert!(generator.domain_for(f64::NAN, 5.0).0.is_nan());
+        assert!(generator.generate_for(f64::NAN, 5.0).is_empty());
+    }
+
+    #[test]
+    fn aligns_ticks_and_the_niced_domain_to_the_anchor() {
+        let generator = Ticks::builder()
+            .domain(0.0, 100.0)
+            .count(5)
+            .anchor(37.5)
+            .build();
+        assert_eq!(generator.generate(), [17.5, 37.5, 57.5, 77.5, 97.5]);
+
+        let generator = Ticks::builder()
+            .domain(0.0, 100.0)
+            .count(5)
+            .anchor(37.5)
+            .nice(true)
+            .build();
+        assert_eq!(generator.domain_for(0.0, 100.0), (-2.5, 117.5));
+        assert_eq!(generator.generate()[0], -2.5);
+    }
+
+    #[test]
+    fn uses_custom_multiples() {
+        let generator = Ticks::builder()
+            .count(4)
+            .multiples(&[1.0, 2.5, 5.0])
+            .build();
+        assert_eq!(generator.generate(), [0.0, 0.25, 0.5, 0.75, 1.0]);
+
+        let generator = Ticks::builder()
+            .count(4)
+            .nice(true)
+            .multiples(&[1.0, 2.5, 5.0])
+            .build();
+        assert_eq!(generator.domain_for(0.1, 9.1), (0.0, 10.0));
+        assert_eq!(generator.generate_for(0.1, 9.1), [0.0, 2.5, 5.0, 7.5, 10.0]);
+
+        let ladder: Vec<f64> = [4.0, 8.0].iter().map(|step| step / 4.0).collect();
+        let generator = Ticks::builder().count(4).multiples(&ladder).build();
+        drop(ladder);
+        assert_eq!(generator.generate_for(0.0, 4.0), [0.0, 1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn includes_zero_in_reversed_domains() {
+        let generator = Ticks::builder().include_zero(true).count(5).build();
+
+        assert_eq!(generator.domain_for(9.0, 2.0), (9.0, 0.0));
+        assert_eq!(generator.domain_for(-2.0, -9.0), (0.0, -9.0));
+    }
+
+    #[test]
+    fn reuses_the_configuration_for_other_domains() {
+        let generator = Ticks::builder().count(4).nice(true).build();
+
+        assert_eq!(generator.generate_for(0.3, 7.6), [0.0, 2.0, 4.0, 6.0, 8.0]);
+        assert_eq!(
+            generator.generate_for(13.0, 97.0),
+            [0.0, 20.0, 40.0, 60.0, 80.0, 100.0]
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{
+    format::{decimal_exponent, format_fixed},
+    tick_step, ticks, Tick, TickKind, MAX_TICKS,
+};
+use alloc::{format, string::String, vec, vec::Vec};
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+const IEC_UNITS: [&str; 9] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB", "ZiB", "YiB"];
+const SI_UNITS: [&str; 9] = ["B", "kB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB"];
+
+/// The units [`byte_ticks`] steps in and [`format_bytes`] writes sizes with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ByteUnits {
+    /// Powers of 1024 with IEC names, such as `KiB` and `MiB`, for memory and file sizes.
+    #[default]
+    Iec,
+    /// Powers of 1000 with SI names, such as `kB` and `MB`, as for disk capacities.
+    Si,
+}
+
+impl ByteUnits {
+    fn base(self) -> f64 {
+        match self {
+            ByteUnits::Iec => 1024.0,
+            ByteUnits::Si => 1000.0,
+        }
+    }
+
+    fn names(self) -> &'static [&'static str; 9] {
+        match self {
+            ByteUnits::Iec => &IEC_UNITS,
+            ByteUnits::Si => &SI_UNITS,
+        }
+    }
+
+    /// The index of the largest unit no larger than `bytes`.
+    fn unit_of(self, bytes: f64) -> usize {
+        let mut unit = 0;
+        let mut size = self.base();
+        while unit + 1 < IEC_UNITS.len() && bytes >= size {
+            unit += 1;
+            size *= self.base();
+        }
+        unit
+    }
+}
+
+/// Like [`ticks`] but for sizes in bytes: with [`ByteUnits::Iec`] the step is a power of two, so
+/// that ticks fall on sizes like 256 MiB, 512 MiB and 1 GiB rather than on decimal 1, 2 and 5
+/// steps. With [`ByteUnits::Si`] the ticks are those of [`ticks`].
+///
+/// The power of two is the one nearest the exact step on a log scale, and no less than a byte.
+/// Each tick is an exact multiple of the step.
+///
+/// ```
+/// use axis_ticks::{byte_ticks, ByteUnits};
+///
+/// let mib = 1024.0 * 1024.0;
+/// let ticks = byte_ticks(0.0, 1024.0 * mib, 4, ByteUnits::Iec);
+///
+/// assert_eq!(ticks, [0.0, 256.0 * mib, 512.0 * mib, 768.0 * mib, 1024.0 * mib]);
+/// ```
+pub fn byte_ticks<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+    units: ByteUnits,
+) -> Vec<T> {
+    if units == ByteUnits::Si {
+        return ticks(start, stop, count);
+    }
+    if count == 0 || !start.is_finite() || !stop.is_finite() {
+        return vec![];
+    }
+    if start == stop {
+        return vec![start];
+    }
+
+    let reverse = stop < start;
+    let (lo, hi) = if reverse {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+    let step = match binary_step(lo, hi, count) {
+        Some(step) => step,
+        None => return vec![],
+    };
+
+    let first = (lo / step).ceil();
+    let last = (hi / step).floor();
+    let len = (last - first + T::one()).to_usize().unwrap_or(0);
+    let mut ticks: Vec<T> = (0..len)
+        .map(|i| (first + T::from_usize(i).unwrap()) * step)
+        .collect();
+    if reverse {
+        ticks.reverse();
+    }
+
+    ticks
+}
+
+/// The power of two nearest the exact step over `[lo, hi]`, doubled until there are no more than
+/// [`MAX_TICKS`] ticks, or `None` if the span overflows.
+fn binary_step<T: Float + FromPrimitive>(lo: T, hi: T, count: usize) -> Option<T> {
+    let exact = (hi - lo) / T::from_usize(count)?;
+    if !exact.is_finite() {
+        return None;
+    }
+
+    let two = T::from_f64(2.0)?;
+    let mut step = two.powi(exact.log2().round().to_i32()?.max(0));
+    while ((hi / step).floor() - (lo / step).ceil() + T::one()) > T::from_usize(MAX_TICKS)? {
+        step = step * two;
+    }
+    if step.is_finite() {
+        Some(step)
+    } else {
+        None
+    }
+}
+
+/// Formats a size in bytes in the largest unit no larger than it, with `decimals` decimal
+/// places, such as `1.5 GiB`. Sizes below a kilobyte are written in bytes.
+///
+/// ```
+/// use axis_ticks::{format_bytes, ByteUnits};
+///
+/// assert_eq!(format_bytes(1536.0, ByteUnits::Iec, 1), "1.5 KiB");
+/// assert_eq!(format_bytes(2.5e9, ByteUnits::Si, 1), "2.5 GB");
+/// ```
+pub fn format_bytes(bytes: f64, units: ByteUnits, decimals: usize) -> String {
+    let unit = units.unit_of(bytes.abs());
+    format_in_unit(bytes, units, unit, decimals)
+}
+
+/// Like [`labeled_ticks`](crate::labeled_ticks) for [`byte_ticks`], labeling each tick in its
+/// own unit with the decimal places the step needs there, such as `512 MiB` and `1.5 GiB`.
+pub fn labeled_byte_ticks(start: f64, stop: f64, count: usize, units: ByteUnits) -> Vec<Tick<f64>> {
+    let ticks = byte_ticks(start, stop, count, units);
+    let step = match ticks.as_slice() {
+        [a, b, ..] => (b - a).abs(),
+        _ => tick_step(start, stop, count).abs(),
+    };
+
+    ticks
+        .into_iter()
+        .map(|value| {
+            let unit = units.unit_of(value.abs());
+            let size = units.base().powi(unit as i32);
+            let decimals = if !(step.is_finite() && step > 0.0) || step >= size {
+                0
+            } else {
+                match units {
+                    // The step is a power of two, so a fraction of the unit of 2^-k needs k
+                    // decimal places.
+                    ByteUnits::Iec => (size / step).log2().round() as usize,
+                    ByteUnits::Si => (3 * unit as i32 - decimal_exponent(step)).max(0) as usize,
+                }
+            };
+
+            Tick {
+                value,
+                label: format_in_unit(value, units, unit, decimals),
+                kind: TickKind::Major,
+            }
+        })
+        .collect()
+}
+
+fn format_in_unit(bytes: f64, units: ByteUnits, unit: usize, decimals: usize) -> String {
+    let value = bytes / units.base().powi(unit as i32);
+    format!("{} {}", format_fixed(value, decimals), units.names()[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIB: f64 = 1024.0 * 1024.0;
+    const GIB: f64 = 1024.0 * MIB;
+
+    fn labels(start: f64, stop: f64, count: usize, units: ByteUnits) -> Vec<String> {
+        labeled_byte_ticks(start, stop, count, units)
+            .into_iter()
+            .map(|tick| tick.label)
+            .collect()
+    }
+
+    #[test]
+    fn steps_in_powers_of_two() {
+        assert_eq!(
+            byte_ticks(0.0, 2.0 * GIB, 4, ByteUnits::Iec),
+            [0.0, 512.0 * MIB, GIB, 1.5 * GIB, 2.0 * GIB]
+        );
+        assert_eq!(
+            byte_ticks(100.0f32, 1000.0, 4, ByteUnits::Iec),
+            [256.0, 512.0, 768.0]
+        );
+        assert_eq!(
+            byte_ticks(1000.0, 0.0, 5, ByteUnits::Iec),
+            [768.0, 512.0, 256.0, 0.0]
+        );
+        assert_eq!(
+            byte_ticks(0.0, 3.0, 10, ByteUnits::Iec),
+            [0.0, 1.0, 2.0, 3.0]
+        );
+        assert_eq!(byte_ticks(0.0, 1e6, 4, ByteUnits::Si), ticks(0.0, 1e6, 4));
+    }
+
+    #[test]
+    fn handles_degenerate_arguments() {
+        assert!(byte_ticks(0.0, GIB, 0, ByteUnits::Iec).is_empty());
+        assert!(byte_ticks(f64::NAN, GIB, 4, ByteUnits::Iec).is_empty());
+        assert_eq!(byte_ticks(GIB, GIB, 4, ByteUnits::Iec), [GIB]);
+        assert!(byte_ticks(0.0, f64::MAX, usize::MAX, ByteUnits::Iec).len() <= MAX_TICKS);
+        assert!(byte_ticks(-f64::MAX, f64::MAX, 4, ByteUnits::Iec).is_empty());
+    }
+
+    #[test]
+    fn formats_sizes_in_units() {
+        assert_eq!(format_bytes(0.0, ByteUnits::Iec, 0), "0 B");
+        assert_eq!(format_bytes(1023.0, ByteUnits::Iec, 0), "1023 B");
+        assert_eq!(format_bytes(1024.0, ByteUnits::Iec, 0), "1 KiB");
+        assert_eq!(format_bytes(-1.5 * GIB, ByteUnits::Iec, 2), "-1.50 GiB");
+        assert_eq!(format_bytes(999.0, ByteUnits::Si, 0), "999 B");
+        assert_eq!(format_bytes(1e27, ByteUnits::Si, 0), "1000 YB");
+    }
+
+    #[test]
+    fn labels_ticks_in_their_own_units() {
+        assert_eq!(
+            labels(0.0, 2.0 * GIB, 4, ByteUnits::Iec),
+            ["0 B", "512 MiB", "1.0 GiB", "1.5 GiB", "2.0 GiB"]
+        );
+        assert_eq!(
+            labels(0.0, GIB, 4, ByteUnits::Iec),
+            ["0 B", "256 MiB", "512 MiB", "768 MiB", "1.00 GiB"]
+        );
+        assert_eq!(
+            labels(0.0, 2e6, 4, ByteUnits::Si),
+            ["0 B", "500 kB", "1.0 MB", "1.5 MB", "2.0 MB"]
+        );
+        assert!(labeled_byte_ticks(0.0, 1.0, 0, ByteUnits::Iec).is_empty());
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{data::extent, ticks};
+use num_complex::Complex;
+
+/// Generates ticks spanning the magnitudes of `data`, e.g. for plotting the amplitude of a signal.
+///
+/// Magnitudes that are not finite are skipped. Returns an empty `Vec` if no finite magnitudes
+/// remain.
+pub fn ticks_from_complex(data: &[Complex<f64>], count: usize) -> Vec<f64> {
+    match extent(data.iter().map(|z| z.norm())) {
+        Some((lo, hi)) => ticks(lo, hi, count),
+        None => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticks_the_magnitude_extent() {
+        let data = [
+            Complex::new(3.0, 4.0),
+            Complex::new(0.0, 1.0),
+            Complex::new(-6.0, 8.0),
+        ];
+
+        assert_eq!(
+            ticks_from_complex(&data, 10),
+            [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]
+        );
+    }
+
+    #[test]
+    fn skips_non_finite_magnitudes() {
+        let data = [
+            Complex::new(f64::NAN, 0.0),
+            Complex::new(0.0, 2.0),
+            Complex::new(f64::INFINITY, 1.0),
+            Complex::new(0.0, 4.0),
+        ];
+
+        assert_eq!(ticks_from_complex(&data, 2), [2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn returns_empty_vec_without_finite_data() {
+        assert!(ticks_from_complex(&[], 10).is_empty());
+        assert!(ticks_from_complex(&[Complex::new(f64::NAN, 0.0)], 10).is_empty());
+    }
+}
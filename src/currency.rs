@@ -0,0 +1,271 @@
+use crate::{
+    format::decimal_exponent, pow10, tick_step, ticks, FormatLocale, NumberFormat, Tick, TickKind,
+};
+use alloc::{format, string::String, vec::Vec};
+
+/// The abbreviations of thousands, millions, billions and trillions in compact amounts.
+const ABBREVIATIONS: [&str; 5] = ["", "k", "M", "B", "T"];
+
+/// ISO 4217 currencies whose minor unit is not a hundredth of the major unit.
+const MINOR_UNITS: [(&str, usize); 14] = [
+    ("BHD", 3),
+    ("CLP", 0),
+    ("IQD", 3),
+    ("ISK", 0),
+    ("JOD", 3),
+    ("JPY", 0),
+    ("KRW", 0),
+    ("KWD", 3),
+    ("LYD", 3),
+    ("OMR", 3),
+    ("PYG", 0),
+    ("TND", 3),
+    ("UGX", 0),
+    ("VND", 0),
+];
+
+/// A formatter for amounts of money, and for the ticks of a finance axis with
+/// [`tick_labels`](CurrencyFormat::tick_labels).
+///
+/// The digits are grouped, and the symbol placed before or after the amount, as the
+/// [`FormatLocale`] writes its own currency. Negative amounts are written with a hyphen-minus
+/// before the symbol, or in parentheses.
+///
+/// ```
+/// use axis_ticks::CurrencyFormat;
+///
+/// let dollars = CurrencyFormat::new("$");
+/// assert_eq!(dollars.format(-1234.5), "-$1,234.50");
+/// assert_eq!(dollars.clone().parentheses(true).format(-1234.5), "($1,234.50)");
+///
+/// let labels: Vec<String> = dollars
+///     .compact(true)
+///     .tick_labels(-500e3, 1.5e6, 4)
+///     .into_iter()
+///     .map(|tick| tick.label)
+///     .collect();
+/// assert_eq!(labels, ["-$500k", "$0", "$500k", "$1.0M", "$1.5M"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrencyFormat {
+    symbol: String,
+    code: bool,
+    minor_units: usize,
+    locale: FormatLocale,
+    parentheses: bool,
+    compact: bool,
+}
+
+impl CurrencyFormat {
+    /// Creates a formatter for amounts with `symbol`, such as `"$"` or `"€"`, with two decimal
+    /// places for cents in the `en-US` locale.
+    pub fn new(symbol: &str) -> Self {
+        CurrencyFormat {
+            symbol: symbol.into(),
+            code: false,
+            minor_units: 2,
+            locale: FormatLocale::default(),
+            parentheses: false,
+            compact: false,
+        }
+    }
+
+    /// Creates a formatter for amounts labeled with an ISO 4217 code such as `"USD"`, separated
+    /// from the number by a no-break space, with the decimal places of the currency's minor unit:
+    /// none for `JPY`, three for `KWD` and two for most others.
+    pub fn iso(code: &str) -> Self {
+        let minor_units = MINOR_UNITS
+            .iter()
+            .find(|&&(iso, _)| iso.eq_ignore_ascii_case(code))
+            .map_or(2, |&(_, units)| units);
+
+        CurrencyFormat {
+            code: true,
+            minor_units,
+            ..CurrencyFormat::new(code)
+        }
+    }
+
+    /// Sets the decimal places of the minor unit, the most any amount is written with.
+    pub fn minor_units(mut self, minor_units: usize) -> Self {
+        self.minor_units = minor_units;
+        self
+    }
+
+    /// Sets the locale whose decimal point, grouping and currency placement are used.
+    pub fn locale(mut self, locale: FormatLocale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Writes negative amounts in parentheses, as in accounting, rather than with a minus sign.
+    pub fn parentheses(mut self, parentheses: bool) -> Self {
+        self.parentheses = parentheses;
+        self
+    }
+
+    /// Abbreviates thousands, millions, billions and trillions, as `$1.2M`.
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Formats an amount with the decimal places of the minor unit, as `$1,234.50`, or with them
+    /// after the abbreviation's scale if compact, as `$1.23k`.
+    pub fn format(&self, value: f64) -> String {
+        let scale = self.scale(value);
+        self.format_scaled(value, scale, self.minor_units)
+    }
+
+    /// Like [`labeled_ticks`](crate::labeled_ticks) but labels each tick as an amount, with no
+    /// decimal places if the step needs none and those of the minor unit otherwise.
+    ///
+    /// If compact, each tick is abbreviated by its own scale, as `-$500k` and `$1.5M` on one
+    /// axis, with the decimal places the step needs at that scale.
+    pub fn tick_labels(&self, start: f64, stop: f64, count: usize) -> Vec<Tick<f64>> {
+        let step = tick_step(start, stop, count).abs();
+        let step_exponent = if step.is_finite() && step > 0.0 {
+            decimal_exponent(step)
+        } else {
+            0
+        };
+
+        ticks(start, stop, count)
+            .into_iter()
+            .map(|value| {
+                let scale = self.scale(value);
+                let decimals = match (scale - step_exponent).max(0) as usize {
+                    // Whole cents rather than tenths of a dollar.
+                    decimals if scale == 0 && decimals > 0 => self.minor_units,
+                    decimals => decimals,
+                };
+                Tick {
+                    value,
+                    label: self.format_scaled(value, scale, decimals),
+                    kind: TickKind::Major,
+                }
+            })
+            .collect()
+    }
+
+    /// The power of ten the abbreviation of `value` stands for, or 0 if not compact.
+    fn scale(&self, value: f64) -> i32 {
+        if !self.compact || value == 0.0 || !value.is_finite() {
+            return 0;
+        }
+        decimal_exponent(value.abs()).div_euclid(3).clamp(0, 4) * 3
+    }
+
+    /// Formats `value` divided by `10^scale` with at most `decimals` decimal places, and no more
+    /// than the minor unit has.
+    fn format_scaled(&self, value: f64, scale: i32, decimals: usize) -> String {
+        let decimals = decimals.min(scale as usize + self.minor_units);
+        let (prefix, suffix) = self.affixes();
+
+        let mut locale = self.locale.clone();
+        locale.minus = "-".into();
+        locale.currency = (
+            prefix,
+            format!("{}{}", ABBREVIATIONS[scale as usize / 3], suffix),
+        );
+        let sign = if self.parentheses { "(" } else { "-" };
+        let format: NumberFormat = locale.format(&format!("{}$,.{}f", sign, decimals)).unwrap();
+
+        format.format(value / pow10::<f64>(scale))
+    }
+
+    /// The text before and after the number, placing the symbol where the locale places its own.
+    fn affixes(&self) -> (String, String) {
+        let (before, after) = &self.locale.currency;
+        let code_space = if self.code { "\u{a0}" } else { "" };
+
+        if before.is_empty() && !after.is_empty() {
+            let space: String = after.chars().take_while(|c| c.is_whitespace()).collect();
+            let space = if space.is_empty() { code_space } else { &space };
+            (String::new(), format!("{}{}", space, self.symbol))
+        } else {
+            let space: String = before
+                .chars()
+                .rev()
+                .take_while(|c| c.is_whitespace())
+                .collect();
+            let space = if space.is_empty() { code_space } else { &space };
+            (format!("{}{}", self.symbol, space), String::new())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(format: &CurrencyFormat, start: f64, stop: f64, count: usize) -> Vec<String> {
+        format
+            .tick_labels(start, stop, count)
+            .into_iter()
+            .map(|tick| tick.label)
+            .collect()
+    }
+
+    #[test]
+    fn formats_amounts_with_the_minor_unit() {
+        assert_eq!(
+            CurrencyFormat::new("$").format(1234567.891),
+            "$1,234,567.89"
+        );
+        assert_eq!(CurrencyFormat::new("$").format(-0.001), "$0.00");
+        assert_eq!(CurrencyFormat::iso("JPY").format(1234.5), "JPY\u{a0}1,235");
+        assert_eq!(CurrencyFormat::iso("kwd").format(1.5), "kwd\u{a0}1.500");
+        assert_eq!(CurrencyFormat::new("$").minor_units(0).format(99.5), "$100");
+        assert_eq!(
+            CurrencyFormat::new("$").compact(true).format(1234.5),
+            "$1.23k"
+        );
+    }
+
+    #[test]
+    fn follows_the_locale() {
+        let euros = CurrencyFormat::new("\u{20ac}").locale(FormatLocale::de_de());
+
+        assert_eq!(euros.format(1234.5), "1.234,50\u{a0}\u{20ac}");
+        assert_eq!(
+            euros.clone().parentheses(true).format(-1234.5),
+            "(1.234,50\u{a0}\u{20ac})"
+        );
+        assert_eq!(
+            CurrencyFormat::iso("EUR")
+                .locale(FormatLocale::fr_fr())
+                .compact(true)
+                .format(2.5e6),
+            "2,50M\u{a0}EUR"
+        );
+        assert_eq!(
+            CurrencyFormat::new("\u{a3}")
+                .locale(FormatLocale::en_gb())
+                .format(5.0),
+            "\u{a3}5.00"
+        );
+    }
+
+    #[test]
+    fn labels_ticks_with_the_precision_of_the_step() {
+        let dollars = CurrencyFormat::new("$");
+
+        assert_eq!(
+            labels(&dollars, 0.0, 1.0, 4),
+            ["$0.00", "$0.20", "$0.40", "$0.60", "$0.80", "$1.00"]
+        );
+        assert_eq!(labels(&dollars, 0.0, 2000.0, 2), ["$0", "$1,000", "$2,000"]);
+        assert_eq!(labels(&dollars, 0.0, 0.01, 10)[1], "$0.00");
+        assert_eq!(
+            labels(
+                &dollars.clone().parentheses(true).compact(true),
+                -2e9,
+                0.0,
+                2
+            ),
+            ["($2B)", "($1B)", "$0"]
+        );
+        assert!(dollars.tick_labels(0.0, 1.0, 0).is_empty());
+    }
+}
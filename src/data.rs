@@ -0,0 +1,357 @@
+use crate::{pow10, ticks, MAX_TICKS};
+use alloc::{vec, vec::Vec};
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// The finite minimum and maximum of `values`, skipping NaN and infinities.
+pub(crate) fn extent<T: Float, I: IntoIterator<Item = T>>(values: I) -> Option<(T, T)> {
+    values
+        .into_iter()
+        .filter(|value| value.is_finite())
+        .fold(None, |extent, value| match extent {
+            None => Some((value, value)),
+            Some((lo, hi)) => Some((value.min(lo), value.max(hi))),
+        })
+}
+
+/// The extent of `data` without the fraction `clip` of its values at either end, such as `0.01` for
+/// the 1st to 99th percentiles, so that a few outliers do not set the domain. NaN and infinities
+/// are skipped, and `clip` is clamped to `[0, 0.5]`.
+///
+/// The percentiles are interpolated as by [`quantile_ticks`]. Returns `None` if no finite values
+/// remain.
+///
+/// ```
+/// use axis_ticks::robust_extent;
+///
+/// let mut data: Vec<f64> = (0..=100).map(f64::from).collect();
+/// data.push(1e9);
+///
+/// assert_eq!(robust_extent(&data, 0.0), Some((0.0, 1e9)));
+/// assert_eq!(robust_extent(&data, 0.01), Some((1.01, 99.99)));
+/// ```
+pub fn robust_extent<T: Float + FromPrimitive>(data: &[T], clip: T) -> Option<(T, T)> {
+    let sorted = sorted_finite(data);
+    if sorted.is_empty() {
+        return None;
+    }
+
+    let half = T::from_f64(0.5).unwrap();
+    let clip = if clip > T::zero() {
+        clip.min(half)
+    } else {
+        T::zero()
+    };
+    Some((
+        quantile_sorted(&sorted, clip),
+        quantile_sorted(&sorted, T::one() - clip),
+    ))
+}
+
+/// Like [`ticks_from_data`] but over the [`robust_extent`] of `data` for `clip`, widened by
+/// [`nice`](crate::nice), so that an outlier does not squeeze the rest of the data into a corner
+/// of the axis. Values outside the domain are left for the caller to clamp or mark.
+///
+/// ```
+/// use axis_ticks::ticks_from_data_robust;
+///
+/// let mut data: Vec<f64> = (0..=100).map(f64::from).collect();
+/// data.push(1e9);
+///
+/// assert_eq!(ticks_from_data_robust(&data, 5, 0.01), [0.0, 20.0, 40.0, 60.0, 80.0, 100.0]);
+/// ```
+pub fn ticks_from_data_robust<T: Float + FloatConst + FromPrimitive>(
+    data: &[T],
+    count: usize,
+    clip: T,
+) -> Vec<T> {
+    match robust_extent(data, clip) {
+        Some((lo, hi)) => {
+            let (lo, hi) = crate::nice(lo, hi, count);
+            ticks(lo, hi, count)
+        }
+        None => vec![],
+    }
+}
+
+/// Generates ticks over the extent of `data`, skipping NaN and infinities, for callers that start
+/// from the values to plot rather than a domain.
+///
+/// If `nice` is set the extent is first widened by [`nice`](crate::nice) so that the ticks start
+/// and end on its bounds. Returns an empty `Vec` if no finite values remain.
+///
+/// ```
+/// use axis_ticks::ticks_from_data;
+///
+/// let data = [0.7, f64::NAN, 3.2, 9.4, f64::NEG_INFINITY];
+///
+/// assert_eq!(ticks_from_data(&data, 5, false), [2.0, 4.0, 6.0, 8.0]);
+/// assert_eq!(ticks_from_data(&data, 5, true), [0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+/// ```
+pub fn ticks_from_data<T: Float + FloatConst + FromPrimitive>(
+    data: &[T],
+    count: usize,
+    nice: bool,
+) -> Vec<T> {
+    let (lo, hi) = match extent(data.iter().copied()) {
+        Some(extent) => extent,
+        None => return vec![],
+    };
+    let (lo, hi) = if nice {
+        crate::nice(lo, hi, count)
+    } else {
+        (lo, hi)
+    };
+    ticks(lo, hi, count)
+}
+
+/// Generates ticks over the extent of `data` that are denser where the data is denser, e.g. to
+/// add gridlines to the busy regions of a scatter plot.
+///
+/// The nicely rounded ticks for `base_count` are kept and each gap between them is subdivided in
+/// proportion to the share of the data that falls inside it, so the spacing is **not uniform**.
+/// The ticks are ascending. Non-finite data is skipped.
+///
+/// The subdivisions add up to `base_count` ticks in all, or fewer if there would be more than
+/// [`MAX_TICKS`] altogether.
+pub fn ticks_density_weighted(data: &[f64], base_count: usize) -> Vec<f64> {
+    let base_count = base_count.min(MAX_TICKS);
+    let (lo, hi) = match extent(data.iter().copied()) {
+        Some(extent) => extent,
+        None => return vec![],
+    };
+
+    let majors = ticks(lo, hi, base_count);
+    if majors.len() < 2 {
+        return majors;
+    }
+
+    let bins = majors.len() - 1;
+    let mut histogram = vec![0usize; bins];
+    let finite = data.iter().filter(|value| value.is_finite());
+    for &value in finite.clone() {
+        let bin = majors[1..]
+            .iter()
+            .position(|&edge| value < edge)
+            .unwrap_or(bins - 1);
+        histogram[bin] += 1;
+    }
+    let total = finite.count() as f64;
+
+    // Each bin's share of the budget is rounded cumulatively, so that the shares add up to the
+    // budget rather than overrunning it by up to half a tick per bin.
+    let budget = base_count.min(MAX_TICKS - majors.len()) as f64;
+    let share = |in_bins: usize| Float::round(budget * in_bins as f64 / total) as usize;
+    let mut weighted = Vec::new();
+    let mut in_bins = 0;
+    for (pair, &in_bin) in majors.windows(2).zip(&histogram) {
+        let (a, b) = (pair[0], pair[1]);
+        let parts = 1 + share(in_bins + in_bin) - share(in_bins);
+        in_bins += in_bin;
+        weighted.extend((0..parts).map(|k| a + (b - a) * k as f64 / parts as f64));
+    }
+    weighted.push(majors[bins]);
+
+    weighted
+}
+
+/// Generates ticks at the quantiles of `data` rather than evenly over its extent, splitting it
+/// into `count` groups of about equal size, as for the class breaks of a choropleth legend or the
+/// axis of a skewed distribution.
+///
+/// Each quantile is interpolated as by d3's `quantile` and then rounded to the coarsest power of
+/// ten that moves it by no more than a quarter of the gap to its nearest neighbour, so the ticks
+/// stay in order. The minimum is rounded down and the maximum up so that the ticks span the data.
+/// The ticks are ascending, with duplicates from tied data removed. Non-finite data is skipped.
+/// `count` is capped so that there are no more than [`MAX_TICKS`] ticks.
+///
+/// ```
+/// use axis_ticks::quantile_ticks;
+///
+/// let data = [1.0, 2.0, 3.0, 4.0, 10.0, 20.0, 50.0, 100.0, 1000.0];
+///
+/// assert_eq!(quantile_ticks(&data, 4), [1.0, 3.0, 10.0, 50.0, 1000.0]);
+/// ```
+pub fn quantile_ticks<T: Float + FloatConst + FromPrimitive>(data: &[T], count: usize) -> Vec<T> {
+    let sorted = sorted_finite(data);
+    if count == 0 || sorted.is_empty() {
+        return vec![];
+    }
+    let count = count.min(MAX_TICKS - 1);
+
+    let quantiles: Vec<T> = (0..=count)
+        .map(|i| {
+            let p = T::from_usize(i).unwrap() / T::from_usize(count).unwrap();
+            quantile_sorted(&sorted, p)
+        })
+        .collect();
+
+    let mut ticks: Vec<T> = quantiles
+        .iter()
+        .enumerate()
+        .map(|(i, &quantile)| {
+            let gap = [i.checked_sub(1), Some(i + 1)]
+                .iter()
+                .filter_map(|&j| quantiles.get(j?))
+                .map(|&neighbour| (neighbour - quantile).abs())
+                .filter(|&gap| gap > T::zero())
+                .fold(T::infinity(), T::min);
+            if !gap.is_finite() {
+                return quantile;
+            }
+
+            let power = (gap / T::from_f64(2.0).unwrap())
+                .log10()
+                .floor()
+                .to_i32()
+                .unwrap();
+            let round = match i {
+                0 => T::floor,
+                i if i == count => T::ceil,
+                _ => T::round,
+            };
+            if power < 0 {
+                round(quantile * pow10(-power)) / pow10(-power)
+            } else {
+                round(quantile / pow10(power)) * pow10(power)
+            }
+        })
+        .collect();
+    ticks.dedup();
+
+    ticks
+}
+
+/// The finite values of `data` in ascending order.
+pub(crate) fn sorted_finite<T: Float>(data: &[T]) -> Vec<T> {
+    let mut sorted: Vec<T> = data
+        .iter()
+        .copied()
+        .filter(|value| value.is_finite())
+        .collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    sorted
+}
+
+/// The `p`-quantile of non-empty `sorted` data, interpolated as by d3's `quantileSorted`.
+pub(crate) fn quantile_sorted<T: Float + FromPrimitive>(sorted: &[T], p: T) -> T {
+    let h = T::from_usize(sorted.len() - 1).unwrap() * p;
+    let below = h.floor().to_usize().unwrap();
+    let above = (below + 1).min(sorted.len() - 1);
+    sorted[below] + (sorted[above] - sorted[below]) * (h - h.floor())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extent_skips_non_finite_values() {
+        assert_eq!(
+            extent(vec![3.0, f64::NAN, -1.0, f64::INFINITY, 2.0]),
+            Some((-1.0, 3.0))
+        );
+        assert_eq!(extent(vec![f64::NAN]), None);
+        assert_eq!(extent(Vec::<f64>::new()), None);
+    }
+
+    #[test]
+    fn ticks_density_weighted_adds_ticks_near_clusters() {
+        let mut data = vec![0.0, 10.0];
+        data.extend((0..50).map(|i| 1.0 + i as f64 / 50.0));
+        data.extend((0..50).map(|i| 8.0 + i as f64 / 50.0));
+
+        let weighted = ticks_density_weighted(&data, 5);
+        let within = |lo: f64, hi: f64| weighted.iter().filter(|&&t| t > lo && t < hi).count();
+
+        assert!(weighted.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(weighted[0], 0.0);
+        assert_eq!(weighted[weighted.len() - 1], 10.0);
+        assert!(within(0.0, 2.0) > within(4.0, 6.0));
+        assert!(within(8.0, 10.0) > within(4.0, 6.0));
+    }
+
+    #[test]
+    fn ticks_density_weighted_handles_degenerate_data() {
+        assert!(ticks_density_weighted(&[], 5).is_empty());
+        assert!(ticks_density_weighted(&[f64::NAN], 5).is_empty());
+        assert_eq!(ticks_density_weighted(&[2.0, 2.0], 5), [2.0]);
+        assert!(ticks_density_weighted(&[0.0, 1.0], 1_000_000).len() <= MAX_TICKS);
+        assert!(ticks_density_weighted(&[0.0, 1.0], usize::MAX).len() <= MAX_TICKS);
+        assert_eq!(ticks_density_weighted(&[0.0, 0.5, 1.0], 4).len(), 6 + 4);
+    }
+
+    #[test]
+    fn quantile_ticks_split_data_into_equal_groups() {
+        let data: Vec<f64> = (1..=100).map(|i| (i * i) as f64).collect();
+
+        assert_eq!(
+            quantile_ticks(&data, 4),
+            [0.0, 700.0, 2600.0, 6000.0, 10000.0]
+        );
+        assert_eq!(quantile_ticks(&[0.12, 0.5, 0.87], 2), [0.1, 0.5, 0.9]);
+        assert_eq!(quantile_ticks(&[3.0f32, 1.0, 2.0], 2), [1.0, 2.0, 3.0]);
+        assert_eq!(
+            quantile_ticks(&[0.0, 1.0, 1.0, 1.0, 1.0, 1.0, 2.0], 4),
+            [0.0, 1.0, 2.0]
+        );
+    }
+
+    #[test]
+    fn quantile_ticks_handle_degenerate_data() {
+        assert!(quantile_ticks::<f64>(&[], 4).is_empty());
+        assert!(quantile_ticks(&[1.0, 2.0], 0).is_empty());
+        assert!(quantile_ticks(&[f64::NAN, f64::INFINITY], 4).is_empty());
+        assert_eq!(quantile_ticks(&[2.5, 2.5, f64::NAN], 4), [2.5]);
+
+        let data: Vec<f64> = (0..100_000).map(f64::from).collect();
+        assert!(quantile_ticks(&data, 1_000_000).len() <= MAX_TICKS);
+        assert!(quantile_ticks(&data, usize::MAX).len() <= MAX_TICKS);
+    }
+
+    #[test]
+    fn ticks_from_data_skips_non_finite_values() {
+        let data = [f64::NAN, 12.0, f64::INFINITY, 48.0];
+
+        assert_eq!(ticks_from_data(&data, 4, false), ticks(12.0, 48.0, 4));
+        assert_eq!(
+            ticks_from_data(&data, 4, true),
+            [10.0, 20.0, 30.0, 40.0, 50.0]
+        );
+        assert_eq!(
+            ticks_from_data(&[0.5f32, 0.1], 4, true),
+            [0.1, 0.2, 0.3, 0.4, 0.5]
+        );
+        assert_eq!(ticks_from_data(&[7.0, 7.0], 4, true), [7.0]);
+        assert!(ticks_from_data(&[f64::NAN], 4, true).is_empty());
+        assert!(ticks_from_data::<f64>(&[], 4, false).is_empty());
+    }
+
+    #[test]
+    fn robust_extent_clips_outliers() {
+        let mut data: Vec<f64> = (1..20).map(f64::from).collect();
+        data.extend([-1e6, f64::NAN, 1e6]);
+
+        assert_eq!(robust_extent(&data, 0.0), Some((-1e6, 1e6)));
+        assert_eq!(robust_extent(&data, 0.05), Some((1.0, 19.0)));
+        assert_eq!(robust_extent(&data, 0.9), Some((10.0, 10.0)));
+        assert_eq!(robust_extent(&data, -0.5), Some((-1e6, 1e6)));
+        assert_eq!(robust_extent(&data, f64::NAN), Some((-1e6, 1e6)));
+        assert_eq!(robust_extent::<f64>(&[f64::NAN], 0.01), None);
+    }
+
+    #[test]
+    fn ticks_from_data_robust_nices_the_clipped_extent() {
+        let mut data: Vec<f64> = (1..20).map(f64::from).collect();
+        data.extend([-1e6, 1e6]);
+
+        assert_eq!(
+            ticks_from_data_robust(&data, 4, 0.05),
+            [0.0, 5.0, 10.0, 15.0, 20.0]
+        );
+        assert_eq!(ticks_from_data_robust(&data, 4, 0.0), ticks(-1e6, 1e6, 4));
+        assert!(ticks_from_data_robust::<f64>(&[], 4, 0.1).is_empty());
+    }
+}
@@ -0,0 +1,252 @@
+use crate::time::{time_tick_interval, time_ticks, TimeInterval, TimeUnit};
+use crate::TickValue;
+use chrono::{DateTime, TimeZone, Utc};
+
+/// Generates ticks between two [`DateTime<Utc>`]s that land on natural calendar boundaries, like
+/// d3-time's `ticks()`.
+///
+/// The interval is chosen from seconds, minutes, hours, days, weeks, months and years as by
+/// [`time_tick_interval`](crate::time_tick_interval). This converts to milliseconds and uses
+/// [`time_ticks`](crate::time_ticks), so sub-millisecond precision is discarded.
+pub fn datetime_ticks(
+    start: DateTime<Utc>,
+    stop: DateTime<Utc>,
+    count: usize,
+) -> Vec<DateTime<Utc>> {
+    time_ticks(
+        start.timestamp_millis() as f64,
+        stop.timestamp_millis() as f64,
+        count,
+    )
+    .into_iter()
+    .filter_map(|ms| Utc.timestamp_millis_opt(ms as i64).single())
+    .collect()
+}
+
+/// Ticks on the linear millisecond axis of [`DateTime::timestamp_millis`], though
+/// [`ticks`](TickValue::ticks) lands on calendar boundaries as [`datetime_ticks`] does.
+impl TickValue for DateTime<Utc> {
+    fn position(&self) -> Option<f64> {
+        Some(self.timestamp_millis() as f64)
+    }
+
+    fn step_multiple(index: i128, mantissa: u32, exponent: i32) -> Option<Self> {
+        i64::step_multiple(index, mantissa, exponent)
+            .and_then(|ms| Utc.timestamp_millis_opt(ms).single())
+    }
+
+    fn ticks(start: &Self, stop: &Self, count: usize) -> Vec<Self> {
+        datetime_ticks(*start, *stop, count)
+    }
+}
+
+/// Generates ticks between two [`DateTime`]s in any time zone, such as a `chrono_tz::Tz`, that
+/// land on boundaries of local civil time.
+///
+/// Ticks of an hour or longer step in local time, so daily ticks fall on local midnight either
+/// side of a daylight saving transition. Local times skipped by a transition are dropped and
+/// repeated ones appear once, at their earliest instant. Shorter intervals step in UTC as
+/// [`datetime_ticks`] does.
+pub fn datetime_ticks_tz<Z: TimeZone>(
+    start: DateTime<Z>,
+    stop: DateTime<Z>,
+    count: usize,
+) -> Vec<DateTime<Z>> {
+    let zone = start.timezone();
+    let local_millis =
+        |t: &DateTime<Z>| Utc.from_utc_datetime(&t.naive_local()).timestamp_millis() as f64;
+    let (local_start, local_stop) = (local_millis(&start), local_millis(&stop));
+
+    let interval = match time_tick_interval(local_start, local_stop, count) {
+        Some(interval) => interval,
+        None => return vec![],
+    };
+    if let TimeUnit::Millisecond | TimeUnit::Second | TimeUnit::Minute = interval.unit {
+        return datetime_ticks(start.with_timezone(&Utc), stop.with_timezone(&Utc), count)
+            .into_iter()
+            .map(|tick| tick.with_timezone(&zone))
+            .collect();
+    }
+
+    let (lo, hi) = if stop < start {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+    time_ticks(local_start, local_stop, count)
+        .into_iter()
+        .filter_map(|ms| Utc.timestamp_millis_opt(ms as i64).single())
+        .filter_map(|local| zone.from_local_datetime(&local.naive_utc()).earliest())
+        .filter(|tick| *tick >= lo && *tick <= hi)
+        .collect()
+}
+
+/// Formats a tick produced by [`datetime_ticks`] with only as much detail as `interval` needs,
+/// e.g. `"14:30"` for minute ticks or `"2021"` for year ticks.
+///
+/// The interval for a domain can be found with
+/// [`time_tick_interval`](crate::time_tick_interval).
+pub fn format_datetime_tick(tick: &DateTime<Utc>, interval: TimeInterval) -> String {
+    let format = match interval.unit {
+        TimeUnit::Millisecond => "%H:%M:%S%.3f",
+        TimeUnit::Second => "%H:%M:%S",
+        TimeUnit::Minute | TimeUnit::Hour => "%H:%M",
+        TimeUnit::Day | TimeUnit::Week => "%b %-d",
+        TimeUnit::Month => "%b %Y",
+        TimeUnit::Year => "%Y",
+    };
+
+    tick.format(format).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time_tick_interval;
+
+    fn utc(ms: i64) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(ms).unwrap()
+    }
+
+    #[test]
+    fn returns_hourly_ticks_over_a_day() {
+        let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let stop = Utc.with_ymd_and_hms(2020, 1, 2, 0, 0, 0).unwrap();
+        let ticks = datetime_ticks(start, stop, 24);
+
+        assert_eq!(ticks.len(), 25);
+        assert_eq!(ticks[0], start);
+        assert_eq!(ticks[1], Utc.with_ymd_and_hms(2020, 1, 1, 1, 0, 0).unwrap());
+        assert_eq!(ticks[24], stop);
+    }
+
+    #[test]
+    fn tick_values_land_on_calendar_boundaries() {
+        let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let stop = Utc.with_ymd_and_hms(2020, 1, 2, 0, 0, 0).unwrap();
+
+        assert_eq!(
+            crate::tick_values(start, stop, 24),
+            datetime_ticks(start, stop, 24)
+        );
+        assert_eq!(DateTime::<Utc>::step_multiple(3, 5, 2), Some(utc(1500)));
+    }
+
+    #[test]
+    fn lands_ticks_on_week_and_month_boundaries() {
+        let format = |ticks: Vec<DateTime<Utc>>| -> Vec<String> {
+            ticks
+                .iter()
+                .map(|t| t.format("%a %Y-%m-%d %H:%M").to_string())
+                .collect()
+        };
+
+        let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let stop = Utc.with_ymd_and_hms(2020, 2, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            format(datetime_ticks(start, stop, 4)),
+            [
+                "Sun 2020-01-05 00:00",
+                "Sun 2020-01-12 00:00",
+                "Sun 2020-01-19 00:00",
+                "Sun 2020-01-26 00:00"
+            ]
+        );
+
+        let start = Utc.with_ymd_and_hms(2020, 1, 15, 7, 0, 0).unwrap();
+        let stop = Utc.with_ymd_and_hms(2020, 12, 15, 7, 0, 0).unwrap();
+        assert_eq!(
+            format(datetime_ticks(start, stop, 4)),
+            [
+                "Wed 2020-04-01 00:00",
+                "Wed 2020-07-01 00:00",
+                "Thu 2020-10-01 00:00"
+            ]
+        );
+    }
+
+    #[test]
+    fn lands_daily_ticks_on_local_midnight_across_dst() {
+        use chrono_tz::Europe::London;
+
+        let start = London.with_ymd_and_hms(2021, 3, 26, 12, 0, 0).unwrap();
+        let stop = London.with_ymd_and_hms(2021, 3, 30, 12, 0, 0).unwrap();
+        let ticks: Vec<String> = datetime_ticks_tz(start, stop, 4)
+            .iter()
+            .map(|t| t.format("%Y-%m-%d %H:%M %Z").to_string())
+            .collect();
+
+        assert_eq!(
+            ticks,
+            [
+                "2021-03-27 00:00 GMT",
+                "2021-03-28 00:00 GMT",
+                "2021-03-29 00:00 BST",
+                "2021-03-30 00:00 BST"
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_local_hours_removed_by_dst() {
+        use chrono_tz::America::New_York;
+
+        let start = New_York.with_ymd_and_hms(2021, 3, 14, 0, 0, 0).unwrap();
+        let stop = New_York.with_ymd_and_hms(2021, 3, 14, 5, 0, 0).unwrap();
+        let hours: Vec<String> = datetime_ticks_tz(start, stop, 4)
+            .iter()
+            .map(|t| t.format("%H:%M").to_string())
+            .collect();
+
+        assert_eq!(hours, ["00:00", "01:00", "03:00", "04:00", "05:00"]);
+    }
+
+    #[test]
+    fn formats_ticks_for_the_chosen_interval() {
+        let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let tick = Utc.with_ymd_and_hms(2020, 3, 1, 14, 30, 0).unwrap();
+        let cases = [
+            (start + chrono::Duration::milliseconds(500), "14:30:00.000"),
+            (start + chrono::Duration::minutes(1), "14:30:00"),
+            (start + chrono::Duration::hours(12), "14:30"),
+            (start + chrono::Duration::days(10), "Mar 1"),
+            (start + chrono::Duration::days(180), "Mar 2020"),
+            (start + chrono::Duration::days(3650), "2020"),
+        ];
+
+        for &(stop, expected) in &cases {
+            let interval = time_tick_interval(
+                start.timestamp_millis() as f64,
+                stop.timestamp_millis() as f64,
+                5,
+            )
+            .unwrap();
+            assert_eq!(format_datetime_tick(&tick, interval), expected);
+        }
+    }
+
+    #[test]
+    fn handles_spans_under_a_second_and_over_years() {
+        assert_eq!(
+            datetime_ticks(utc(0), utc(500), 5),
+            [utc(0), utc(100), utc(200), utc(300), utc(400), utc(500)]
+        );
+
+        let start = Utc.with_ymd_and_hms(2001, 6, 1, 0, 0, 0).unwrap();
+        let stop = Utc.with_ymd_and_hms(2006, 6, 1, 0, 0, 0).unwrap();
+        let years: Vec<_> = datetime_ticks(start, stop, 5)
+            .iter()
+            .map(|t| t.format("%Y-%m-%d").to_string())
+            .collect();
+        assert_eq!(
+            years,
+            [
+                "2002-01-01",
+                "2003-01-01",
+                "2004-01-01",
+                "2005-01-01",
+                "2006-01-01"
+            ]
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::ticks_with_multiples;
+use alloc::{vec, vec::Vec};
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// The multiples of powers of ten that decibel steps are chosen from, giving the conventional
+/// 1, 2, 3, 6, 10, 20 and 30 dB grids.
+const DECIBEL_MULTIPLES: [f64; 4] = [1.0, 2.0, 3.0, 6.0];
+
+/// What a linear quantity converted to decibels by [`decibel_ticks_linear`] measures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecibelScale {
+    /// A power, such as watts or a power spectral density, at `10 * log10(x)` dB.
+    Power,
+    /// A field or amplitude, such as volts or pressure, at `20 * log10(x)` dB.
+    Amplitude,
+}
+
+impl DecibelScale {
+    fn factor<T: FromPrimitive>(self) -> T {
+        let factor = match self {
+            DecibelScale::Power => 10.0,
+            DecibelScale::Amplitude => 20.0,
+        };
+        T::from_f64(factor).unwrap()
+    }
+
+    /// Converts a linear value to decibels, or to negative infinity for zero.
+    pub fn to_decibels<T: Float + FromPrimitive>(self, linear: T) -> T {
+        self.factor::<T>() * linear.log10()
+    }
+
+    /// Converts decibels to a linear value.
+    pub fn to_linear<T: Float + FromPrimitive>(self, decibels: T) -> T {
+        T::from_f64(10.0)
+            .unwrap()
+            .powf(decibels / self.factor::<T>())
+    }
+}
+
+/// Like [`ticks`](crate::ticks) but for a domain in decibels, with steps of 1, 2, 3 or 6 times a
+/// power of ten dB, as on audio meters and spectrum analyzers, rather than 1, 2 and 5.
+///
+/// ```
+/// use axis_ticks::decibel_ticks;
+///
+/// assert_eq!(decibel_ticks(-24.0, 0.0, 4), [-24.0, -18.0, -12.0, -6.0, 0.0]);
+/// assert_eq!(decibel_ticks(-90.0, 0.0, 3), [-90.0, -60.0, -30.0, 0.0]);
+/// ```
+pub fn decibel_ticks<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Vec<T> {
+    let multiples = DECIBEL_MULTIPLES.map(|multiple| T::from_f64(multiple).unwrap());
+    ticks_with_multiples(start, stop, count, &multiples)
+}
+
+/// Like [`decibel_ticks`] but for a domain of linear powers or amplitudes, which are converted to
+/// decibels first. The ticks are in decibels; convert them back with
+/// [`DecibelScale::to_linear`] to place them on a linear axis.
+///
+/// Returns an empty `Vec` if either end of the domain is not positive.
+///
+/// ```
+/// use axis_ticks::{decibel_ticks_linear, DecibelScale};
+///
+/// assert_eq!(
+///     decibel_ticks_linear(0.001, 1.0, 3, DecibelScale::Amplitude),
+///     [-60.0, -40.0, -20.0, 0.0]
+/// );
+/// ```
+pub fn decibel_ticks_linear<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+    scale: DecibelScale,
+) -> Vec<T> {
+    if start <= T::zero() || stop <= T::zero() {
+        return vec![];
+    }
+    decibel_ticks(scale.to_decibels(start), scale.to_decibels(stop), count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uses_the_decibel_ladder() {
+        assert_eq!(decibel_ticks(0.0, 12.0, 4), [0.0, 3.0, 6.0, 9.0, 12.0]);
+        assert_eq!(decibel_ticks(0.0, 24.0, 4), [0.0, 6.0, 12.0, 18.0, 24.0]);
+        assert_eq!(decibel_ticks(-100.0, 0.0, 5).len(), 6);
+        assert_eq!(decibel_ticks(0.0, 10.0, 10).len(), 11);
+        assert_eq!(decibel_ticks(3.0, -3.0, 2), [3.0, 0.0, -3.0]);
+        assert_eq!(decibel_ticks(0.0, 0.3f32, 3)[1], 0.1);
+    }
+
+    #[test]
+    fn converts_linear_domains() {
+        assert_eq!(
+            decibel_ticks_linear(1.0, 1e6, 3, DecibelScale::Power),
+            [0.0, 20.0, 40.0, 60.0]
+        );
+        assert!(decibel_ticks_linear(0.0, 1.0, 3, DecibelScale::Power).is_empty());
+        assert!(decibel_ticks_linear(-1.0, 1.0, 3, DecibelScale::Amplitude).is_empty());
+        assert_eq!(DecibelScale::Amplitude.to_decibels(10.0), 20.0);
+        assert_eq!(DecibelScale::Power.to_linear(30.0), 1000.0);
+        assert_eq!(DecibelScale::Power.to_decibels(0.0), f64::NEG_INFINITY);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{tick_values, TickValue};
+use core::convert::TryFrom;
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+
+/// Like [`ticks`](crate::ticks) but for [`Decimal`] values, so that ticks on prices and other
+/// decimal quantities are exact, e.g. `0.05` rather than `0.05000000000000001`.
+///
+/// The step and the range of ticks are chosen from the `f64` approximations of `start` and
+/// `stop`, exactly as [`ticks`](crate::ticks) chooses them, and each tick is then computed as an
+/// integer multiple of the step in decimal arithmetic. Ticks that would overflow `Decimal` or
+/// need more than its 28 decimal places are left out.
+///
+/// ```
+/// use axis_ticks::decimal_ticks;
+/// use rust_decimal::Decimal;
+///
+/// let ticks = decimal_ticks(Decimal::new(10, 2), Decimal::new(30, 2), 4);
+///
+/// assert_eq!(ticks.len(), 5);
+/// assert_eq!(ticks[1], Decimal::new(15, 2));
+/// assert_eq!(ticks[1].to_string(), "0.15");
+/// ```
+pub fn decimal_ticks(start: Decimal, stop: Decimal, count: usize) -> Vec<Decimal> {
+    tick_values(start, stop, count)
+}
+
+impl TickValue for Decimal {
+    fn position(&self) -> Option<f64> {
+        self.to_f64()
+    }
+
+    fn step_multiple(index: i128, mantissa: u32, exponent: i32) -> Option<Self> {
+        let multiple = Decimal::from_i128(index)?.checked_mul(Decimal::from(mantissa))?;
+        Some(scale(multiple, exponent)?.normalize())
+    }
+}
+
+/// `multiple * 10^exponent` for an integer `multiple`, or `None` if it is not representable.
+fn scale(multiple: Decimal, exponent: i32) -> Option<Decimal> {
+    if exponent < 0 {
+        let scale = u32::try_from(-exponent).ok()?;
+        Decimal::try_from_i128_with_scale(multiple.to_i128()?, scale).ok()
+    } else {
+        (0..exponent).try_fold(multiple, |value, _| value.checked_mul(Decimal::TEN))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ticks;
+    use core::str::FromStr;
+
+    fn decimals(values: &[&str]) -> Vec<Decimal> {
+        values
+            .iter()
+            .map(|value| Decimal::from_str(value).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn ticks_are_exact_decimals() {
+        let ticks = decimal_ticks(Decimal::ZERO, Decimal::ONE, 20);
+
+        assert_eq!(ticks.len(), 21);
+        assert_eq!(ticks[1].to_string(), "0.05");
+        assert_eq!(ticks[6].to_string(), "0.3");
+        assert_eq!(
+            decimal_ticks(Decimal::new(-125, 3), Decimal::new(25, 2), 10),
+            decimals(&["-0.15", "-0.1", "-0.05", "0", "0.05", "0.1", "0.15", "0.2", "0.25"])
+        );
+    }
+
+    #[test]
+    fn matches_float_ticks() {
+        for &(start, stop, count) in &[(0.0, 1.0, 10), (-37.0, 91.0, 10), (1e6, 1e9, 5)] {
+            let expected: Vec<Decimal> = ticks(start, stop, count)
+                .into_iter()
+                .map(|tick| Decimal::from_f64(tick).unwrap())
+                .collect();
+            let start = Decimal::from_f64(start).unwrap();
+            let stop = Decimal::from_f64(stop).unwrap();
+
+            assert_eq!(decimal_ticks(start, stop, count), expected);
+        }
+    }
+
+    #[test]
+    fn runs_in_the_direction_of_the_domain() {
+        assert_eq!(
+            decimal_ticks(Decimal::ONE, Decimal::ZERO, 2),
+            decimals(&["1", "0.5", "0"])
+        );
+    }
+
+    #[test]
+    fn handles_degenerate_arguments() {
+        assert_eq!(
+            decimal_ticks(Decimal::ONE, Decimal::ONE, 10),
+            [Decimal::ONE]
+        );
+        assert!(decimal_ticks(Decimal::ZERO, Decimal::ONE, 0).is_empty());
+        assert_eq!(
+            decimal_ticks(Decimal::MIN, Decimal::MAX, 2),
+            [Decimal::ZERO]
+        );
+        assert_eq!(
+            decimal_ticks(Decimal::MIN, Decimal::MAX, 4),
+            decimals(&[
+                "-50000000000000000000000000000",
+                "0",
+                "50000000000000000000000000000"
+            ])
+        );
+    }
+}
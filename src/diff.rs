@@ -0,0 +1,192 @@
+use crate::LinearScale;
+use alloc::vec::Vec;
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// How a tick set changed, from [`diff_ticks`], for animating an axis as d3's axes do on a
+/// transition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TickDiff<T> {
+    /// The new ticks with no old tick within the tolerance, which fade in.
+    pub entering: Vec<T>,
+    /// The ticks in both sets as `(old, new)`, which move from one to the other.
+    pub updating: Vec<(T, T)>,
+    /// The old ticks with no new tick within the tolerance, which fade out.
+    pub exiting: Vec<T>,
+}
+
+/// A tick part way through a transition, from [`TickDiff::animate`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnimatedTick<T> {
+    /// The value of the tick, new for an updating tick.
+    pub value: T,
+    /// The position of the tick in the range of the scales.
+    pub position: T,
+    /// From 0 for invisible to 1 for opaque.
+    pub opacity: T,
+}
+
+/// Matches the ticks of `old` with those of `new`, such as before and after a zoom, pairing
+/// ticks within `tolerance` of each other, and returns the unpaired ones as entering or exiting.
+///
+/// Each tick is paired at most once, in ascending order, so the tolerance should be less than
+/// half the step. The vectors of the diff are ascending, and NaN values are dropped.
+///
+/// ```
+/// use axis_ticks::{diff_ticks, ticks};
+///
+/// let diff = diff_ticks(&ticks(0.0, 10.0, 5), &ticks(4.0, 14.0, 5), 1e-9);
+///
+/// assert_eq!(diff.exiting, [0.0, 2.0]);
+/// assert_eq!(diff.updating, [(4.0, 4.0), (6.0, 6.0), (8.0, 8.0), (10.0, 10.0)]);
+/// assert_eq!(diff.entering, [12.0, 14.0]);
+/// ```
+pub fn diff_ticks<T: Float>(old: &[T], new: &[T], tolerance: T) -> TickDiff<T> {
+    let sorted = |ticks: &[T]| {
+        let mut sorted: Vec<T> = ticks
+            .iter()
+            .copied()
+            .filter(|tick| !tick.is_nan())
+            .collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        sorted
+    };
+    let (old, new) = (sorted(old), sorted(new));
+
+    let mut diff = TickDiff {
+        entering: Vec::new(),
+        updating: Vec::new(),
+        exiting: Vec::new(),
+    };
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        let (a, b) = (old[i], new[j]);
+        if (a - b).abs() <= tolerance {
+            diff.updating.push((a, b));
+            i += 1;
+            j += 1;
+        } else if a < b {
+            diff.exiting.push(a);
+            i += 1;
+        } else {
+            diff.entering.push(b);
+            j += 1;
+        }
+    }
+    diff.exiting.extend_from_slice(&old[i..]);
+    diff.entering.extend_from_slice(&new[j..]);
+
+    diff
+}
+
+impl<T: Float + FloatConst + FromPrimitive> TickDiff<T> {
+    /// The ticks at time `t` of a transition from the scale `from` to the scale `to`, with `t`
+    /// clamped to `[0, 1]`.
+    ///
+    /// As in d3, every tick moves from where `from` places it to where `to` places it: updating
+    /// ticks stay opaque, entering ticks fade in and exiting ticks fade out. The ticks are the
+    /// updating ones, then the entering ones, then the exiting ones.
+    ///
+    /// ```
+    /// use axis_ticks::{diff_ticks, LinearScale};
+    ///
+    /// let from = LinearScale::new((0.0, 10.0), (0.0, 100.0));
+    /// let to = LinearScale::new((0.0, 20.0), (0.0, 100.0));
+    /// let diff = diff_ticks(&[0.0, 5.0, 10.0], &[0.0, 10.0, 20.0], 1e-9);
+    ///
+    /// let halfway = diff.animate(&from, &to, 0.5);
+    /// assert_eq!(halfway[1].value, 10.0);
+    /// assert_eq!(halfway[1].position, 75.0);
+    /// assert_eq!(halfway[2].opacity, 0.5);
+    /// ```
+    pub fn animate(
+        &self,
+        from: &LinearScale<T>,
+        to: &LinearScale<T>,
+        t: T,
+    ) -> Vec<AnimatedTick<T>> {
+        let t = t.max(T::zero()).min(T::one());
+        let lerp = |a: T, b: T| a + (b - a) * t;
+
+        let updating = self.updating.iter().map(|&(old, new)| AnimatedTick {
+            value: new,
+            position: lerp(from.map(old), to.map(new)),
+            opacity: T::one(),
+        });
+        let entering = self.entering.iter().map(|&value| AnimatedTick {
+            value,
+            position: lerp(from.map(value), to.map(value)),
+            opacity: t,
+        });
+        let exiting = self.exiting.iter().map(|&value| AnimatedTick {
+            value,
+            position: lerp(from.map(value), to.map(value)),
+            opacity: T::one() - t,
+        });
+
+        updating.chain(entering).chain(exiting).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_ticks_within_the_tolerance() {
+        let diff = diff_ticks(
+            &[0.0, 0.1, 0.2, 0.3],
+            &[0.30000000000000004, 0.2, 0.15],
+            1e-12,
+        );
+
+        assert_eq!(diff.updating, [(0.2, 0.2), (0.3, 0.30000000000000004)]);
+        assert_eq!(diff.entering, [0.15]);
+        assert_eq!(diff.exiting, [0.0, 0.1]);
+
+        let diff = diff_ticks(&[1.0, f64::NAN], &[], 0.0);
+        assert_eq!(diff.exiting, [1.0]);
+        assert!(diff.updating.is_empty() && diff.entering.is_empty());
+    }
+
+    #[test]
+    fn animates_between_scales() {
+        let from = LinearScale::new((0.0, 4.0), (0.0, 400.0));
+        let to = LinearScale::new((2.0, 6.0), (0.0, 400.0));
+        let diff = diff_ticks(&[0.0, 2.0, 4.0], &[2.0, 4.0, 6.0], 0.0);
+
+        let start = diff.animate(&from, &to, -1.0);
+        assert_eq!(
+            start,
+            [
+                AnimatedTick {
+                    value: 2.0,
+                    position: 200.0,
+                    opacity: 1.0
+                },
+                AnimatedTick {
+                    value: 4.0,
+                    position: 400.0,
+                    opacity: 1.0
+                },
+                AnimatedTick {
+                    value: 6.0,
+                    position: 600.0,
+                    opacity: 0.0
+                },
+                AnimatedTick {
+                    value: 0.0,
+                    position: 0.0,
+                    opacity: 1.0
+                },
+            ]
+        );
+
+        let end = diff.animate(&from, &to, 1.0);
+        let positions: Vec<f64> = end.iter().map(|tick| tick.position).collect();
+        assert_eq!(positions, [0.0, 200.0, 400.0, -200.0]);
+        assert_eq!(end[3].opacity, 0.0);
+    }
+}
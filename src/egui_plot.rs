@@ -0,0 +1,122 @@
+use crate::{
+    minor::{default_subdivisions, range_minor_ticks},
+    TickRange,
+};
+use ::egui_plot::{GridInput, GridMark};
+use alloc::vec::Vec;
+
+/// Generates the grid marks of an `egui_plot` axis: the major ticks of [`ticks`](crate::ticks)
+/// followed by the [`minor_ticks`](crate::minor_ticks) between them, divided into `subdivisions`
+/// parts or the conventional number for the step if `None`.
+///
+/// Each mark's `step_size` is the distance between the marks of its kind, the major step or the
+/// minor step, which `egui_plot` uses to paint the majors more prominently. Returns an empty `Vec`
+/// if there is no step, and only the majors if `subdivisions` is less than 2.
+///
+/// ```
+/// use axis_ticks::grid_marks;
+///
+/// let marks = grid_marks(0.0, 2.0, 2, Some(2));
+/// let marks: Vec<(f64, f64)> = marks.iter().map(|mark| (mark.value, mark.step_size)).collect();
+///
+/// assert_eq!(marks, [(0.0, 1.0), (1.0, 1.0), (2.0, 1.0), (0.5, 0.5), (1.5, 0.5)]);
+/// ```
+pub fn grid_marks(
+    start: f64,
+    stop: f64,
+    count: usize,
+    subdivisions: Option<usize>,
+) -> Vec<GridMark> {
+    let (lo, hi) = if stop < start {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+    let range = TickRange::new(lo, hi, count);
+    let (step, major) = match (range.step, range.step()) {
+        (Some(step), Some(major)) => (step, major),
+        _ => return Vec::new(),
+    };
+
+    let mut marks: Vec<GridMark> = (0..range.len())
+        .map(|i| GridMark {
+            value: range.get(i),
+            step_size: major,
+        })
+        .collect();
+
+    let subdivisions = subdivisions.unwrap_or_else(|| default_subdivisions(step));
+    let minor = major / subdivisions as f64;
+    marks.extend(
+        range_minor_ticks(&range, lo, hi, Some(subdivisions))
+            .into_iter()
+            .map(|value| GridMark {
+                value,
+                step_size: minor,
+            }),
+    );
+
+    marks
+}
+
+/// A grid spacer for `Plot::x_grid_spacer` or `Plot::y_grid_spacer` that places the grid of
+/// [`grid_marks`] with the conventional subdivisions over the visible bounds.
+///
+/// Minor marks closer together than the plot's `base_step_size`, the finest step it can tell
+/// apart on screen, are left out.
+///
+/// ```
+/// use egui_plot::Plot;
+///
+/// let plot = Plot::new("plot").x_grid_spacer(axis_ticks::grid_spacer(8));
+/// ```
+pub fn grid_spacer(count: usize) -> impl Fn(GridInput) -> Vec<GridMark> {
+    move |input: GridInput| {
+        let (start, stop) = input.bounds;
+        let mut marks = grid_marks(start, stop, count, None);
+        let major = marks.first().map_or(0.0, |mark| mark.step_size);
+        marks.retain(|mark| mark.step_size == major || mark.step_size >= input.base_step_size);
+        marks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(marks: &[GridMark], step_size: f64) -> Vec<f64> {
+        marks
+            .iter()
+            .filter(|mark| mark.step_size == step_size)
+            .map(|mark| mark.value)
+            .collect()
+    }
+
+    #[test]
+    fn marks_majors_and_minors_by_step_size() {
+        let marks = grid_marks(0.0, 1.0, 5, None);
+
+        assert_eq!(values(&marks, 0.2), [0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+        assert_eq!(values(&marks, 0.05).len(), 15);
+        assert_eq!(marks.len(), 21);
+        assert_eq!(
+            grid_marks(10.0, 0.0, 2, None),
+            grid_marks(0.0, 10.0, 2, None)
+        );
+        assert_eq!(grid_marks(0.0, 1.0, 5, Some(1)).len(), 6);
+        assert!(grid_marks(0.0, 1.0, 0, None).is_empty());
+        assert!(grid_marks(f64::NAN, 1.0, 5, None).is_empty());
+    }
+
+    #[test]
+    fn spacer_drops_minors_finer_than_the_base_step() {
+        let spacer = grid_spacer(5);
+        let input = |base_step_size| GridInput {
+            bounds: (0.0, 1.0),
+            base_step_size,
+        };
+
+        assert_eq!(spacer(input(0.01)).len(), 21);
+        assert_eq!(spacer(input(0.1)).len(), 6);
+    }
+}
@@ -0,0 +1,32 @@
+use core::fmt;
+
+/// The reason [`try_ticks`](crate::try_ticks) or
+/// [`try_ticks_with_limit`](crate::try_ticks_with_limit) could not generate ticks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickError {
+    /// `start` or `stop` is NaN or infinite.
+    NonFiniteDomain,
+    /// `count` is zero, so no ticks were asked for.
+    ZeroCount,
+    /// `count` cannot be represented in the float type.
+    ConversionFailure,
+    /// `count` asks for `len` ticks, more than the limit given to
+    /// [`try_ticks_with_limit`](crate::try_ticks_with_limit).
+    TooManyTicks { len: usize },
+}
+
+impl fmt::Display for TickError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TickError::NonFiniteDomain => write!(f, "the domain is not finite"),
+            TickError::ZeroCount => write!(f, "the tick count is zero"),
+            TickError::ConversionFailure => {
+                write!(f, "the tick count cannot be converted to the float type")
+            }
+            TickError::TooManyTicks { len } => write!(f, "{} ticks exceeds the limit", len),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TickError {}
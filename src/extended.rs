@@ -0,0 +1,232 @@
+use crate::{lit, multiple, MAX_TICKS};
+use alloc::{vec, vec::Vec};
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// The nice step multiples, most preferred first, from Talbot, Lin and Hanrahan.
+const Q: [f64; 6] = [1.0, 5.0, 2.0, 2.5, 4.0, 3.0];
+
+/// The weights of simplicity, coverage, density and legibility in a labeling's score.
+const WEIGHTS: [f64; 4] = [0.25, 0.2, 0.5, 0.05];
+
+/// Generates ticks with the extended labeling algorithm of Talbot, Lin and Hanrahan, an
+/// alternative to the 1, 2, 5 steps of [`ticks`](crate::ticks).
+///
+/// Candidate labelings are scored by the simplicity of their step, how well they cover the
+/// domain, how close their density is to `count + 1` ticks and their legibility, and the best is
+/// returned. The ticks may extend slightly beyond the domain and run in the direction from
+/// `start` to `stop`. Returns an empty `Vec` if `count` is zero or the domain is not finite.
+///
+/// ```
+/// use axis_ticks::extended_ticks;
+///
+/// assert_eq!(extended_ticks(8.1, 14.1, 3), [8.0, 10.0, 12.0, 14.0]);
+/// ```
+pub fn extended_ticks<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Vec<T> {
+    if count == 0 || !start.is_finite() || !stop.is_finite() {
+        return vec![];
+    }
+    if start == stop {
+        return vec![start];
+    }
+
+    let (lo, hi) = if stop < start {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+    let mut ticks = match best_labeling(lo, hi, count.saturating_add(1).min(MAX_TICKS)) {
+        Some(labeling) => labeling.ticks(),
+        None => return vec![],
+    };
+    if stop < start {
+        ticks.reverse();
+    }
+
+    ticks
+}
+
+/// A candidate labeling of `len` ticks, the first at `first * q * 10^exponent` and each `j * q *
+/// 10^exponent` apart.
+#[derive(Debug, Clone, Copy)]
+struct Labeling<T> {
+    first: T,
+    j: T,
+    q: T,
+    exponent: i32,
+    len: usize,
+}
+
+impl<T: Float + FromPrimitive> Labeling<T> {
+    fn ticks(&self) -> Vec<T> {
+        // Keep the mantissa an integer so that `multiple` is exact.
+        let (mantissa, exponent) = if self.q.fract().is_zero() {
+            (self.q, self.exponent)
+        } else {
+            (self.q * lit(10.0), self.exponent - 1)
+        };
+
+        (0..self.len)
+            .map(|i| multiple((mantissa, exponent), self.first + self.j * lit(i as f64)))
+            .collect()
+    }
+}
+
+/// The highest scoring labeling of `[lo, hi]` with about `m` ticks, searched in order of
+/// decreasing best possible score so that the search can stop early.
+fn best_labeling<T: Float + FromPrimitive>(lo: T, hi: T, m: usize) -> Option<Labeling<T>> {
+    let w: [T; 4] = [
+        lit(WEIGHTS[0]),
+        lit(WEIGHTS[1]),
+        lit(WEIGHTS[2]),
+        lit(WEIGHTS[3]),
+    ];
+    let q: Vec<T> = Q.iter().map(|&q| lit(q)).collect();
+    let m = lit::<T>(m as f64);
+
+    let mut best_score = lit::<T>(-2.0);
+    let mut best = None;
+
+    for j in 1..=MAX_TICKS {
+        let j = lit::<T>(j as f64);
+        for (index, &q) in q.iter().enumerate() {
+            let sm = simplicity_max(index, j);
+            if w[0] * sm + w[1] + w[2] + w[3] < best_score {
+                return best;
+            }
+
+            for k in 2..=MAX_TICKS {
+                let k = lit::<T>(k as f64);
+                let dm = density_max(k, m);
+                if w[0] * sm + w[1] + w[2] * dm + w[3] < best_score {
+                    break;
+                }
+
+                let delta = (hi - lo) / (k + T::one()) / j / q;
+                let mut z = delta.log10().ceil().to_i32()?;
+                loop {
+                    let step = j * q * lit::<T>(10.0).powi(z);
+                    if !step.is_finite() {
+                        break;
+                    }
+                    let cm = coverage_max(lo, hi, step * (k - T::one()));
+                    if w[0] * sm + w[1] * cm + w[2] * dm + w[3] < best_score {
+                        break;
+                    }
+
+                    let min_start = (hi / step).floor() * j - (k - T::one()) * j;
+                    let max_start = (lo / step).ceil() * j;
+                    let starts = (max_start - min_start).to_usize().unwrap_or(0);
+                    for offset in 0..=starts.min(MAX_TICKS) {
+                        let first = min_start + lit(offset as f64);
+                        let label_lo = first * (step / j);
+                        let label_hi = label_lo + step * (k - T::one());
+                        let score = w[0] * simplicity(index, j, label_lo, label_hi, step)
+                            + w[1] * coverage(lo, hi, label_lo, label_hi)
+                            + w[2] * density(k, m, lo, hi, label_lo, label_hi)
+                            + w[3];
+                        if score > best_score {
+                            best_score = score;
+                            best = Some(Labeling {
+                                first,
+                                j,
+                                q,
+                                exponent: z,
+                                len: k.to_usize()?,
+                            });
+                        }
+                    }
+                    z += 1;
+                }
+            }
+        }
+    }
+
+    best
+}
+
+/// Rewards preferred multiples, small skips and labelings that include zero.
+fn simplicity<T: Float + FromPrimitive>(index: usize, j: T, lo: T, hi: T, step: T) -> T {
+    let eps = T::epsilon() * lit(100.0);
+    let remainder = lo % step;
+    let has_zero = (remainder.abs() < eps || (step - remainder).abs() < eps)
+        && lo <= T::zero()
+        && hi >= T::zero();
+    let v = if has_zero { T::one() } else { T::zero() };
+
+    T::one() - lit::<T>(index as f64) / lit((Q.len() - 1) as f64) - j + v
+}
+
+fn simplicity_max<T: Float + FromPrimitive>(index: usize, j: T) -> T {
+    lit::<T>(2.0) - lit::<T>(index as f64) / lit((Q.len() - 1) as f64) - j
+}
+
+/// Penalises labelings that extend past the data or leave it uncovered.
+fn coverage<T: Float + FromPrimitive>(lo: T, hi: T, label_lo: T, label_hi: T) -> T {
+    let tenth = (hi - lo) * lit(0.1);
+    T::one()
+        - lit::<T>(0.5) * (((hi - label_hi) / tenth).powi(2) + ((lo - label_lo) / tenth).powi(2))
+}
+
+fn coverage_max<T: Float + FromPrimitive>(lo: T, hi: T, span: T) -> T {
+    let range = hi - lo;
+    if span > range {
+        let half = (span - range) / lit(2.0);
+        T::one() - (half / (range * lit(0.1))).powi(2)
+    } else {
+        T::one()
+    }
+}
+
+/// Rewards labelings with close to `m` ticks over the data.
+fn density<T: Float + FromPrimitive>(k: T, m: T, lo: T, hi: T, label_lo: T, label_hi: T) -> T {
+    let r = (k - T::one()) / (label_hi - label_lo);
+    let rt = (m - T::one()) / (label_hi.max(hi) - lo.min(label_lo));
+    lit::<T>(2.0) - (r / rt).max(rt / r)
+}
+
+fn density_max<T: Float + FromPrimitive>(k: T, m: T) -> T {
+    if k >= m {
+        lit::<T>(2.0) - (k - T::one()) / (m - T::one())
+    } else {
+        T::one()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_the_reference_implementation() {
+        assert_eq!(extended_ticks(8.1, 14.1, 3), [8.0, 10.0, 12.0, 14.0]);
+        assert_eq!(
+            extended_ticks(0.0, 100.0, 4),
+            [0.0, 25.0, 50.0, 75.0, 100.0]
+        );
+        assert_eq!(extended_ticks(-0.125, 0.25, 4), [-0.1, 0.0, 0.1, 0.2, 0.3]);
+    }
+
+    #[test]
+    fn runs_in_the_direction_of_the_domain() {
+        assert_eq!(
+            extended_ticks(100.0, 0.0, 4),
+            [100.0, 75.0, 50.0, 25.0, 0.0]
+        );
+        assert_eq!(extended_ticks(8.1f32, 14.1, 3), [8.0, 10.0, 12.0, 14.0]);
+    }
+
+    #[test]
+    fn handles_degenerate_domains() {
+        assert_eq!(extended_ticks(1.0, 1.0, 5), [1.0]);
+        assert!(extended_ticks(0.0, 1.0, 0).is_empty());
+        assert!(extended_ticks(f64::NAN, 1.0, 5).is_empty());
+        assert_eq!(extended_ticks(-1e300, 1e300, 4).len(), 5);
+    }
+}
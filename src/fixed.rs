@@ -0,0 +1,259 @@
+use crate::MAX_TICKS;
+use alloc::{vec, vec::Vec};
+use core::convert::{TryFrom, TryInto};
+use fixed::traits::Fixed;
+
+/// The significant digits of the exact step that choose its mantissa.
+const DIGITS: u32 = 12;
+
+/// Like [`ticks`](crate::ticks) but for fixed-point numbers from the `fixed` crate, such as
+/// `I16F16`, using only integer arithmetic so that it suits targets without a floating point unit.
+///
+/// The step is chosen as [`ticks`](crate::ticks) chooses it, with the power of ten found by long
+/// division rather than a logarithm. Each tick is the fixed-point value nearest its exact decimal
+/// position, so a step finer than the type's resolution gives ticks that are rounded to the same
+/// value only once. Ticks beyond the range of the type are left out, and a domain too extreme for
+/// 128-bit intermediate arithmetic returns an empty `Vec`. At most [`MAX_TICKS`] ticks are
+/// returned.
+///
+/// ```
+/// use axis_ticks::fixed_ticks;
+/// use fixed::types::I16F16;
+///
+/// let ticks = fixed_ticks(I16F16::from_num(0), I16F16::from_num(10), 5);
+///
+/// assert_eq!(ticks, [0, 2, 4, 6, 8, 10].map(I16F16::from_num));
+/// ```
+pub fn fixed_ticks<F: Fixed>(start: F, stop: F, count: usize) -> Vec<F> {
+    if count == 0 {
+        return vec![];
+    }
+    if start == stop {
+        return vec![start];
+    }
+
+    let (start_bits, stop_bits): (i128, i128) =
+        match (start.to_bits().try_into(), stop.to_bits().try_into()) {
+            (Ok(start), Ok(stop)) => (start, stop),
+            _ => return vec![],
+        };
+    let reverse = stop_bits < start_bits;
+    let (lo, hi) = if reverse {
+        (stop_bits, start_bits)
+    } else {
+        (start_bits, stop_bits)
+    };
+
+    let mut ticks: Vec<F> = fixed_bits_ticks(lo, hi, count, F::FRAC_NBITS)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|bits| Some(F::from_bits(F::Bits::try_from(bits).ok()?)))
+        .collect();
+    ticks.dedup();
+    if reverse {
+        ticks.reverse();
+    }
+
+    ticks
+}
+
+/// The ticks over `[lo, hi]` as the bits of fixed-point numbers with `frac_bits` fractional bits,
+/// or `None` if the arithmetic would overflow.
+fn fixed_bits_ticks(lo: i128, hi: i128, count: usize, frac_bits: u32) -> Option<Vec<i128>> {
+    let one = 1u128.checked_shl(frac_bits)?;
+    let span = lo.abs_diff(hi);
+
+    // Counts above the limit would fall back to a coarser step anyway.
+    let mut step = fixed_step(span, count.min(MAX_TICKS), one)?;
+    let mut bounds = fixed_bounds(lo, hi, step)?;
+    if bounds.1 > MAX_TICKS as i128 {
+        // Try the finest grid that might fit first, as `TickRange` does.
+        for &coarse_count in &[MAX_TICKS - 1, MAX_TICKS / 2] {
+            step = fixed_step(span, coarse_count, one)?;
+            bounds = fixed_bounds(lo, hi, step)?;
+            if bounds.1 <= MAX_TICKS as i128 {
+                break;
+            }
+        }
+    }
+
+    let (first, len) = bounds;
+    let (numerator, denominator, _) = step;
+    (first..first + len.min(MAX_TICKS as i128))
+        .map(|i| {
+            // The nearest bits to `i * numerator / denominator`, rounding halves up.
+            let twice = i.checked_mul(numerator)?.checked_mul(2)?;
+            Some(
+                twice
+                    .checked_add(denominator)?
+                    .div_euclid(denominator.checked_mul(2)?),
+            )
+        })
+        .collect()
+}
+
+/// The step for `count` intervals over `span` bits, as the bits `numerator / denominator` and
+/// whether the bounds round outwards, where `one` is the bits of 1.
+fn fixed_step(span: u128, count: usize, one: u128) -> Option<(i128, i128, bool)> {
+    let (power, digits) = leading_digits(span, (count as u128).checked_mul(one)?);
+
+    // As `tick_increment`, comparing the squares of the exact step's leading digits.
+    let reaches = |threshold: u128| digits * digits >= threshold * 10u128.pow(2 * (DIGITS - 1));
+    let mantissa = if reaches(50) {
+        10
+    } else if reaches(10) {
+        5
+    } else if reaches(2) {
+        2
+    } else {
+        1
+    };
+
+    let scale = 10u128.checked_pow(power.unsigned_abs())?;
+    let (numerator, denominator) = if power >= 0 {
+        (mantissa * scale, 1)
+    } else {
+        (mantissa, scale)
+    };
+    let numerator = i128::try_from(numerator.checked_mul(one)?).ok()?;
+    let denominator = i128::try_from(denominator).ok()?;
+
+    // A fractional step is an inverse increment to `ticks`, whose bounds then round outwards.
+    Some((numerator, denominator, power < 0))
+}
+
+/// The index of the first tick and the number of ticks for `step` over `[lo, hi]`.
+fn fixed_bounds(lo: i128, hi: i128, step: (i128, i128, bool)) -> Option<(i128, i128)> {
+    let (numerator, denominator, outwards) = step;
+    let index = |bits: i128| -> Option<(i128, i128)> {
+        let scaled = bits.checked_mul(denominator)?;
+        let floor = scaled.div_euclid(numerator);
+        let ceil = if scaled.rem_euclid(numerator) == 0 {
+            floor
+        } else {
+            floor + 1
+        };
+        Some((floor, ceil))
+    };
+
+    let (lo_floor, lo_ceil) = index(lo)?;
+    let (hi_floor, hi_ceil) = index(hi)?;
+    let (first, last) = if outwards {
+        (lo_floor, hi_ceil)
+    } else {
+        (lo_ceil, hi_floor)
+    };
+
+    Some((first, (last - first + 1).max(0)))
+}
+
+/// The power of ten of `numerator / denominator` and its first [`DIGITS`] significant digits, by
+/// long division.
+///
+/// `numerator` must be positive and `denominator` no more than a tenth of `u128::MAX`.
+fn leading_digits(numerator: u128, denominator: u128) -> (i32, u128) {
+    let mut digits = numerator / denominator;
+    let mut remainder = numerator % denominator;
+    let mut power = -1;
+    let mut significant = 0;
+
+    if digits > 0 {
+        while let Some(scale) = 10u128.checked_pow(significant) {
+            if scale > digits {
+                break;
+            }
+            significant += 1;
+        }
+        power = significant as i32 - 1;
+        if significant > DIGITS {
+            return (power, digits / 10u128.pow(significant - DIGITS));
+        }
+    }
+
+    while significant < DIGITS {
+        remainder *= 10;
+        digits = digits * 10 + remainder / denominator;
+        remainder %= denominator;
+        if digits > 0 {
+            significant += 1;
+        } else {
+            power -= 1;
+        }
+    }
+
+    (power, digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ticks;
+    use fixed::types::{I16F16, I32F32, I8F8, U16F16};
+
+    #[test]
+    fn matches_float_ticks() {
+        for &(start, stop, count) in &[
+            (0.0, 10.0, 5),
+            (-37.0, 91.0, 10),
+            (1.5, 9.5, 10),
+            (0.0, 1.0, 4),
+            (-0.125, 0.25, 10),
+            (100.0, -100.0, 4),
+        ] {
+            let expected: Vec<I16F16> = ticks(start, stop, count)
+                .into_iter()
+                .map(I16F16::from_num)
+                .collect();
+
+            assert_eq!(
+                fixed_ticks(I16F16::from_num(start), I16F16::from_num(stop), count),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn rounds_decimal_ticks_to_the_nearest_value() {
+        let ticks = fixed_ticks(I32F32::from_num(0), I32F32::from_num(1), 10);
+
+        assert_eq!(ticks.len(), 11);
+        assert_eq!(ticks[1], I32F32::from_num(0.1));
+        assert_eq!(ticks[3], I32F32::from_num(0.3));
+    }
+
+    #[test]
+    fn skips_ticks_beyond_the_type() {
+        assert_eq!(
+            fixed_ticks(I8F8::from_num(-127.5), I8F8::MAX, 4),
+            [-100, -50, 0, 50, 100].map(I8F8::from_num)
+        );
+        assert_eq!(
+            fixed_ticks(I8F8::from_num(127.05), I8F8::from_num(127.95), 10),
+            (0..10)
+                .map(|i| I8F8::from_num(127.0 + i as f64 / 10.0))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            fixed_ticks(U16F16::from_num(0.95), U16F16::ZERO, 10),
+            [1.0, 0.9, 0.8, 0.7, 0.6, 0.5, 0.4, 0.3, 0.2, 0.1, 0.0].map(U16F16::from_num)
+        );
+    }
+
+    #[test]
+    fn merges_steps_below_the_resolution() {
+        let ticks = fixed_ticks(I8F8::ZERO, I8F8::DELTA * 2, 10);
+
+        assert_eq!(ticks, [I8F8::ZERO, I8F8::DELTA, I8F8::DELTA * 2]);
+    }
+
+    #[test]
+    fn handles_degenerate_arguments() {
+        assert_eq!(fixed_ticks(I16F16::ONE, I16F16::ONE, 10), [I16F16::ONE]);
+        assert!(fixed_ticks(I16F16::ZERO, I16F16::ONE, 0).is_empty());
+        assert_eq!(
+            fixed_ticks(I16F16::MIN, I16F16::MAX, usize::MAX).len(),
+            fixed_ticks(I16F16::MIN, I16F16::MAX, MAX_TICKS - 1).len()
+        );
+        assert!(fixed_ticks(I16F16::MIN, I16F16::MAX, usize::MAX).len() <= MAX_TICKS);
+    }
+}
@@ -0,0 +1,473 @@
+use crate::{percent::percent_decimals, pow10, tick_decimals, TickRange};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// The SI prefixes from 10^-24 to 10^24 in steps of 10^3, as d3-format writes them.
+pub(crate) const SI_PREFIXES: [&str; 17] = [
+    "y", "z", "a", "f", "p", "n", "\u{b5}", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+];
+
+/// How the integer digits of a formatted number are grouped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupingStyle {
+    /// Groups of three digits, e.g. `1,234,567`.
+    Thousands,
+    /// A group of three digits followed by groups of two, as in Indian numbering, e.g.
+    /// `12,34,567`.
+    Indian,
+}
+
+impl GroupingStyle {
+    /// The sizes of the rightmost group and of every group after it.
+    fn group_sizes(self) -> (usize, usize) {
+        match self {
+            GroupingStyle::Thousands => (3, 3),
+            GroupingStyle::Indian => (3, 2),
+        }
+    }
+}
+
+/// Formats `value` with `decimals` decimal places and its integer digits grouped with commas.
+///
+/// Values that are not finite are formatted as by `Display`.
+pub fn format_grouped(value: f64, decimals: usize, style: GroupingStyle) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+
+    let formatted = format!("{:.*}", decimals, value.abs());
+    let (integer, fraction) = match formatted.find('.') {
+        Some(point) => formatted.split_at(point),
+        None => (formatted.as_str(), ""),
+    };
+
+    let negative = value < 0.0 && formatted.bytes().any(|b| b.is_ascii_digit() && b != b'0');
+    let sign = if negative { "-" } else { "" };
+
+    format!("{}{}{}", sign, group_digits(integer, style), fraction)
+}
+
+/// Formats ticks with `decimals` decimal places, padded on the left to a common width so they
+/// line up in monospaced output.
+pub fn format_ticks_aligned(ticks: &[f64], decimals: usize) -> Vec<String> {
+    let labels: Vec<String> = ticks
+        .iter()
+        .map(|&tick| format_fixed(tick, decimals))
+        .collect();
+    let width = labels.iter().map(|label| label.len()).max().unwrap_or(0);
+
+    labels
+        .into_iter()
+        .map(|label| format!("{:>width$}", label, width = width))
+        .collect()
+}
+
+/// How [`tick_format`] writes tick values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TickNotation {
+    /// Fixed-point notation, like d3-format's `f` type, e.g. `0.25`.
+    #[default]
+    Fixed,
+    /// Fixed-point notation scaled by one SI prefix shared by every tick, like d3-format's `s`
+    /// type in `tickFormat`, e.g. `0.5k` and `1.0k`.
+    SiPrefix,
+    /// Engineering notation, with each tick scaled by its own SI prefix so that its exponent is a
+    /// multiple of 3, e.g. `4.7k`, `470k` and `4.7M`.
+    Engineering,
+    /// Fractions written as percentages in fixed-point notation, like d3-format's `%` type, e.g.
+    /// `25%` for `0.25`. The decimal places are those of the ticks of
+    /// [`percent_ticks`](crate::percent_ticks).
+    Percent,
+    /// Scientific notation with each tick's own exponent, e.g. `1.5×10⁻⁶`.
+    Scientific,
+    /// Fixed-point notation with one power of ten factored out of every tick, e.g. `0`, `2`, `4`
+    /// and `6` with the factor `×10⁻⁶` of [`TickFormat::factor_label`] shown once for the axis.
+    SharedExponent,
+}
+
+/// A formatter for the ticks of a domain, from [`tick_format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TickFormat {
+    notation: TickNotation,
+    decimals: usize,
+    /// The power of ten shared by the ticks: of the larger magnitude of the domain's ends, or of
+    /// its SI prefix, a multiple of 3, for the prefixed notations.
+    exponent: i32,
+    /// The power of ten of the step, which the decimal places of each tick are found from.
+    step_exponent: i32,
+}
+
+impl TickFormat {
+    /// Formats a tick, such as one of [`ticks`](crate::ticks) for the same domain.
+    pub fn format(&self, value: f64) -> String {
+        let scaled =
+            |exponent: i32, decimals: usize| format_fixed(value / pow10::<f64>(exponent), decimals);
+
+        match self.notation {
+            TickNotation::Fixed => format_fixed(value, self.decimals),
+            TickNotation::Percent => format!("{}%", format_fixed(value * 100.0, self.decimals)),
+            TickNotation::SiPrefix => {
+                format!("{}{}", scaled(self.exponent, self.decimals), self.prefix())
+            }
+            TickNotation::SharedExponent => scaled(self.exponent, self.decimals),
+            // Zero has no exponent of its own, so it is written without decimal places.
+            TickNotation::Engineering | TickNotation::Scientific
+                if value == 0.0 || !value.is_finite() =>
+            {
+                format_fixed(value, 0)
+            }
+            TickNotation::Engineering => {
+                let exponent = prefix_exponent(value.abs());
+                format!(
+                    "{}{}",
+                    scaled(exponent, self.decimals_at(exponent)),
+                    SI_PREFIXES[(exponent / 3 + 8) as usize]
+                )
+            }
+            TickNotation::Scientific => {
+                let exponent = decimal_exponent(value.abs());
+                format!(
+                    "{}\u{d7}10{}",
+                    scaled(exponent, self.decimals_at(exponent)),
+                    superscript(exponent)
+                )
+            }
+        }
+    }
+
+    /// The decimal places every tick is formatted with.
+    ///
+    /// With [`TickNotation::Engineering`] and [`TickNotation::Scientific`] these are the decimal
+    /// places of the ticks of the larger magnitude of the domain's ends; ticks with smaller
+    /// exponents may need fewer.
+    pub fn decimals(&self) -> usize {
+        self.decimals
+    }
+
+    /// The SI prefix every tick is formatted with, or `""` for the notations without prefixes and
+    /// for ticks that need none. With [`TickNotation::Engineering`] this is the prefix of the
+    /// larger magnitude of the domain's ends.
+    pub fn prefix(&self) -> &'static str {
+        match self.notation {
+            TickNotation::SiPrefix | TickNotation::Engineering => {
+                SI_PREFIXES[(self.exponent / 3 + 8) as usize]
+            }
+            _ => "",
+        }
+    }
+
+    /// The power of ten factored out of every tick with [`TickNotation::SharedExponent`], or
+    /// `None` for the other notations.
+    pub fn exponent(&self) -> Option<i32> {
+        match self.notation {
+            TickNotation::SharedExponent => Some(self.exponent),
+            _ => None,
+        }
+    }
+
+    /// The factor of [`exponent`](TickFormat::exponent) to show once for the axis, such as
+    /// `×10⁻⁶`, or `""` if there is none.
+    pub fn factor_label(&self) -> String {
+        match self.exponent() {
+            Some(exponent) if exponent != 0 => format!("\u{d7}10{}", superscript(exponent)),
+            _ => String::new(),
+        }
+    }
+
+    /// The decimal places the step needs for a tick scaled by `10^exponent`.
+    fn decimals_at(&self, exponent: i32) -> usize {
+        (exponent - self.step_exponent).max(0) as usize
+    }
+}
+
+/// A port of d3-scale's `tickFormat(start, stop, count, specifier)`, which formats the ticks of
+/// [`ticks`](crate::ticks) for the same arguments with the fewest decimal places that tell them
+/// apart.
+///
+/// With [`TickNotation::SiPrefix`] the prefix is chosen from the larger magnitude of `start` and
+/// `stop`, and the decimal places from the step relative to it, so that every tick shares the
+/// prefix; [`TickNotation::SharedExponent`] likewise shares its power of ten. With
+/// [`TickNotation::Engineering`] and [`TickNotation::Scientific`] each tick gets its own prefix
+/// or exponent instead, and the decimal places the step needs relative to it. Unlike d3's
+/// default, integer digits are not grouped; see [`format_grouped`].
+///
+/// ```
+/// use axis_ticks::{tick_format, TickNotation};
+///
+/// let format = tick_format(0.0, 2500.0, 5, TickNotation::SiPrefix);
+///
+/// assert_eq!(format.format(500.0), "0.5k");
+/// assert_eq!(tick_format(0.0, 1.0, 10, TickNotation::Fixed).format(0.3), "0.3");
+///
+/// let format = tick_format(0.0, 6e-6, 3, TickNotation::SharedExponent);
+/// assert_eq!(format.format(4e-6), "4");
+/// assert_eq!(format.factor_label(), "×10⁻⁶");
+/// ```
+pub fn tick_format(start: f64, stop: f64, count: usize, notation: TickNotation) -> TickFormat {
+    let magnitude = start.abs().max(stop.abs());
+    let exponent = match notation {
+        TickNotation::SiPrefix | TickNotation::Engineering => prefix_exponent(magnitude),
+        _ => decimal_exponent(magnitude),
+    };
+    let step_exponent = TickRange::new(start, stop, count)
+        .power()
+        .unwrap_or(exponent);
+
+    let decimals = match notation {
+        TickNotation::Fixed => tick_decimals(start, stop, count),
+        TickNotation::Percent => percent_decimals(start, stop, count),
+        _ => (exponent - step_exponent).max(0) as usize,
+    };
+
+    TickFormat {
+        notation,
+        decimals,
+        exponent,
+        step_exponent,
+    }
+}
+
+/// `exponent` in superscript digits, such as `⁻⁶`.
+fn superscript(exponent: i32) -> String {
+    const DIGITS: [char; 10] = [
+        '\u{2070}', '\u{b9}', '\u{b2}', '\u{b3}', '\u{2074}', '\u{2075}', '\u{2076}', '\u{2077}',
+        '\u{2078}', '\u{2079}',
+    ];
+
+    let sign = if exponent < 0 { "\u{207b}" } else { "" };
+    let digits: String = exponent
+        .unsigned_abs()
+        .to_string()
+        .bytes()
+        .map(|digit| DIGITS[usize::from(digit - b'0')])
+        .collect();
+    format!("{}{}", sign, digits)
+}
+
+/// The power of ten of the SI prefix for `magnitude`, a multiple of 3.
+fn prefix_exponent(magnitude: f64) -> i32 {
+    decimal_exponent(magnitude).div_euclid(3).clamp(-8, 8) * 3
+}
+
+/// The exponent of `value` in scientific notation, or 0 for zero and values that are not finite.
+pub(crate) fn decimal_exponent(value: f64) -> i32 {
+    if value == 0.0 || !value.is_finite() {
+        return 0;
+    }
+
+    let formatted = format!("{:e}", value);
+    formatted
+        .split_once('e')
+        .and_then(|(_, exponent)| exponent.parse().ok())
+        .unwrap_or(0)
+}
+
+/// Formats `value` with `decimals` decimal places, without the sign of values that round to zero.
+pub(crate) fn format_fixed(value: f64, decimals: usize) -> String {
+    let formatted = format!("{:.*}", decimals, value);
+    match formatted.strip_prefix('-') {
+        Some(unsigned) if !unsigned.bytes().any(|b| (b'1'..=b'9').contains(&b)) => {
+            unsigned.to_string()
+        }
+        _ => formatted,
+    }
+}
+
+/// Inserts commas between the groups of a string of digits.
+pub(crate) fn group_digits(digits: &str, style: GroupingStyle) -> String {
+    let (first, rest) = style.group_sizes();
+
+    let mut groups = Vec::new();
+    let mut end = digits.len();
+    let mut size = first;
+    while end > size {
+        groups.push(&digits[end - size..end]);
+        end -= size;
+        size = rest;
+    }
+    groups.push(&digits[..end]);
+
+    groups.reverse();
+    groups.join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_grouped_groups_thousands() {
+        assert_eq!(
+            format_grouped(1234567.0, 0, GroupingStyle::Thousands),
+            "1,234,567"
+        );
+        assert_eq!(
+            format_grouped(-1234.5, 2, GroupingStyle::Thousands),
+            "-1,234.50"
+        );
+        assert_eq!(format_grouped(123.0, 0, GroupingStyle::Thousands), "123");
+        assert_eq!(format_grouped(0.0, 1, GroupingStyle::Thousands), "0.0");
+    }
+
+    #[test]
+    fn format_grouped_groups_indian_numbering() {
+        assert_eq!(
+            format_grouped(1234567.0, 0, GroupingStyle::Indian),
+            "12,34,567"
+        );
+        assert_eq!(
+            format_grouped(100000.0, 0, GroupingStyle::Indian),
+            "1,00,000"
+        );
+        assert_eq!(
+            format_grouped(-1234.25, 1, GroupingStyle::Indian),
+            "-1,234.2"
+        );
+    }
+
+    #[test]
+    fn format_grouped_does_not_sign_zero() {
+        assert_eq!(format_grouped(-0.001, 1, GroupingStyle::Thousands), "0.0");
+        assert_eq!(format_grouped(f64::NAN, 1, GroupingStyle::Thousands), "NaN");
+    }
+
+    #[test]
+    fn format_ticks_aligned_pads_to_a_common_width() {
+        assert_eq!(
+            format_ticks_aligned(&[-5.0, 0.0, 5.0, 10.0, 15.0], 1),
+            ["-5.0", " 0.0", " 5.0", "10.0", "15.0"]
+        );
+        assert_eq!(format_ticks_aligned(&[-10.0, 5.0], 0), ["-10", "  5"]);
+        assert_eq!(format_ticks_aligned(&[-0.01, 0.5], 1), ["0.0", "0.5"]);
+        assert_eq!(format_ticks_aligned(&[], 1), Vec::<String>::new());
+    }
+
+    #[test]
+    fn tick_format_infers_the_decimals_from_the_step() {
+        let format = tick_format(0.0, 1.0, 10, TickNotation::Fixed);
+
+        assert_eq!(format.decimals(), 1);
+        assert_eq!(format.format(0.30000000000000004), "0.3");
+        assert_eq!(
+            tick_format(-0.15, 0.25, 10, TickNotation::default()).format(-0.1),
+            "-0.10"
+        );
+        assert_eq!(
+            tick_format(0.0, 1000.0, 5, TickNotation::Fixed).format(200.0),
+            "200"
+        );
+    }
+
+    #[test]
+    fn tick_format_follows_the_capped_step() {
+        for &count in &[1_000_000, usize::MAX] {
+            let format = tick_format(0.0, 1.0, count, TickNotation::SiPrefix);
+            assert_eq!(format.format(0.0002), "0.0002");
+        }
+    }
+
+    #[test]
+    fn tick_format_shares_one_si_prefix() {
+        let format = tick_format(0.0, 1e6, 5, TickNotation::SiPrefix);
+        let labels: Vec<String> = [0.0, 2e5, 1e6].iter().map(|&v| format.format(v)).collect();
+
+        assert_eq!(labels, ["0.0M", "0.2M", "1.0M"]);
+        assert_eq!(format.prefix(), "M");
+        assert_eq!(
+            tick_format(0.0, 5e-6, 5, TickNotation::SiPrefix).format(2e-6),
+            "2\u{b5}"
+        );
+        assert_eq!(
+            tick_format(0.0, 50.0, 5, TickNotation::SiPrefix).format(10.0),
+            "10"
+        );
+        assert_eq!(
+            tick_format(0.0, 1e30, 2, TickNotation::SiPrefix).format(5e29),
+            "500000Y"
+        );
+    }
+
+    #[test]
+    fn tick_format_writes_engineering_notation() {
+        let format = tick_format(0.0, 5e6, 10, TickNotation::Engineering);
+        let labels: Vec<String> = [0.0, 5e5, 1e6, 4.5e6, -1.5e6]
+            .iter()
+            .map(|&v| format.format(v))
+            .collect();
+
+        assert_eq!(labels, ["0", "500k", "1.0M", "4.5M", "-1.5M"]);
+        assert_eq!((format.prefix(), format.decimals()), ("M", 1));
+        let format = tick_format(4000.0, 5000.0, 10, TickNotation::Engineering);
+        assert_eq!(format.format(4700.0), "4.7k");
+        let format = tick_format(0.0, 1e6, 10, TickNotation::Engineering);
+        assert_eq!(format.format(4.7e5), "470k");
+        assert_eq!(
+            tick_format(0.0, 0.005, 5, TickNotation::Engineering).format(4e-4),
+            "400\u{b5}"
+        );
+        assert_eq!(
+            tick_format(1.0, 1.0, 5, TickNotation::Engineering).format(1.0),
+            "1"
+        );
+    }
+
+    #[test]
+    fn tick_format_writes_percentages() {
+        let format = tick_format(0.0, 1.0, 4, TickNotation::Percent);
+
+        assert_eq!(format.format(0.25), "25%");
+        assert_eq!(format.format(0.07), "7%");
+        assert_eq!(format.prefix(), "");
+        assert_eq!(
+            tick_format(0.0, 0.1, 4, TickNotation::Percent).format(0.025),
+            "2.5%"
+        );
+        assert_eq!(
+            tick_format(0.0, -0.001, 10, TickNotation::Percent).format(-0.0003),
+            "-0.03%"
+        );
+    }
+
+    #[test]
+    fn tick_format_writes_scientific_notation() {
+        let format = tick_format(0.0, 2e-6, 4, TickNotation::Scientific);
+
+        assert_eq!(format.format(1.5e-6), "1.5\u{d7}10\u{207b}\u{2076}");
+        assert_eq!(format.format(0.0), "0");
+        assert_eq!(format.format(5e-7), "5\u{d7}10\u{207b}\u{2077}");
+        assert_eq!(format.exponent(), None);
+        assert_eq!(
+            tick_format(0.0, 3e12, 3, TickNotation::Scientific).format(-2e12),
+            "-2\u{d7}10\u{b9}\u{b2}"
+        );
+    }
+
+    #[test]
+    fn tick_format_factors_out_a_shared_exponent() {
+        let format = tick_format(0.0, 6e-6, 3, TickNotation::SharedExponent);
+        let labels: Vec<String> = [0.0, 2e-6, 4e-6, 6e-6]
+            .iter()
+            .map(|&v| format.format(v))
+            .collect();
+
+        assert_eq!(labels, ["0", "2", "4", "6"]);
+        assert_eq!(format.exponent(), Some(-6));
+        assert_eq!(format.factor_label(), "\u{d7}10\u{207b}\u{2076}");
+        let format = tick_format(0.0, 1e5, 5, TickNotation::SharedExponent);
+        assert_eq!(
+            (format.format(2e4), format.factor_label()),
+            ("0.2".into(), "\u{d7}10\u{2075}".into())
+        );
+        assert_eq!(
+            tick_format(0.0, 5.0, 5, TickNotation::SharedExponent).factor_label(),
+            ""
+        );
+        assert_eq!(
+            tick_format(0.0, 5.0, 5, TickNotation::Fixed).factor_label(),
+            ""
+        );
+    }
+}
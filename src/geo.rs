@@ -0,0 +1,231 @@
+use crate::{
+    angle::{nearest_step, step_multiples},
+    angle_ticks,
+    format::decimal_exponent,
+    ticks, wrap_degrees, Tick, TickKind,
+};
+use alloc::{format, string::String, vec, vec::Vec};
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// The steps in arcseconds [`geo_ticks`] chooses from: 1″, 5″, 10″ and 30″, the same in
+/// arcminutes, and 1°, 5°, 10° and 30°.
+const GEO_STEPS: [f64; 12] = [
+    1.0, 5.0, 10.0, 30.0, 60.0, 300.0, 600.0, 1800.0, 3600.0, 18000.0, 36000.0, 108000.0,
+];
+
+/// Which coordinate a [`geo_ticks`] axis shows, deciding the hemisphere suffixes of its labels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeoAxis {
+    /// Degrees north (`N`) or south (`S`) of the equator.
+    Latitude,
+    /// Degrees east (`E`) or west (`W`) of the prime meridian, wrapped into ±180°.
+    Longitude,
+}
+
+/// Like [`ticks`] but for a domain in decimal degrees of latitude or longitude, with
+/// degree-minute-second steps of 1″, 5″, 10″ or 30″, 1′, 5′, 10′ or 30′, or 1°, 5°, 10° or 30°,
+/// for map axes and graticules. Label them with [`format_dms`] or use [`labeled_geo_ticks`].
+///
+/// The step is the one nearest the exact step on a log scale. Steps finer than an arcsecond are
+/// those of [`ticks`] in arcseconds, and steps coarser than 30° those of
+/// [`angle_ticks`](crate::angle_ticks).
+///
+/// ```
+/// use axis_ticks::geo_ticks;
+///
+/// assert_eq!(geo_ticks(0.0, 10.0, 3), [0.0, 5.0, 10.0]);
+/// assert_eq!(geo_ticks(0.0, 1.0, 2), [0.0, 0.5, 1.0]);
+/// ```
+pub fn geo_ticks<T: Float + FloatConst + FromPrimitive>(start: T, stop: T, count: usize) -> Vec<T> {
+    if count == 0 || !start.is_finite() || !stop.is_finite() {
+        return vec![];
+    }
+    if start == stop {
+        return vec![start];
+    }
+
+    let arcseconds = T::from_f64(3600.0).unwrap();
+    let exact = (stop - start).abs() / T::from_usize(count).unwrap() * arcseconds;
+    // Halfway on a log scale from 1″ to the 0.5″ of `ticks`, and from 30° to the 45° of
+    // `angle_ticks`.
+    let finest = T::from_f64(core::f64::consts::FRAC_1_SQRT_2).unwrap();
+    let coarsest = T::from_f64((108000.0f64 * 162000.0).sqrt()).unwrap();
+
+    // An overflowing span, as in `angle_ticks`, is left to `angle_ticks`.
+    if exact < finest {
+        ticks(start * arcseconds, stop * arcseconds, count)
+            .into_iter()
+            .map(|tick| tick / arcseconds)
+            .collect()
+    } else if exact > coarsest {
+        angle_ticks(start, stop, count)
+    } else {
+        let steps = GEO_STEPS.map(|step| T::from_f64(step).unwrap());
+        steps[nearest_step(steps.iter().copied(), exact)..]
+            .iter()
+            .find_map(|&step| {
+                step_multiples(start, stop, step / arcseconds, |index| {
+                    index * step / arcseconds
+                })
+            })
+            .unwrap_or_else(|| angle_ticks(start, stop, count))
+    }
+}
+
+/// Formats decimal degrees as degrees, minutes and seconds with a hemisphere suffix, such as
+/// `45°30′N` or `122°25′12.5″W`, rounding the seconds to `decimals` decimal places.
+///
+/// Minutes and seconds are left out when they and the smaller parts are zero, and the equator,
+/// the prime meridian and the antimeridian have no suffix. Longitudes are wrapped into ±180°.
+///
+/// ```
+/// use axis_ticks::{format_dms, GeoAxis};
+///
+/// assert_eq!(format_dms(-33.8575, GeoAxis::Latitude, 0), "33°51′27″S");
+/// assert_eq!(format_dms(190.0, GeoAxis::Longitude, 0), "170°W");
+/// ```
+pub fn format_dms(degrees: f64, axis: GeoAxis, decimals: usize) -> String {
+    if !degrees.is_finite() {
+        return format!("{}", degrees);
+    }
+
+    let degrees = match axis {
+        GeoAxis::Latitude => degrees,
+        GeoAxis::Longitude => wrap_degrees(degrees + 180.0) - 180.0,
+    };
+    let scale = 10f64.powi(decimals as i32);
+    let arcseconds = (degrees.abs() * 3600.0 * scale).round() / scale;
+
+    let whole_degrees = (arcseconds / 3600.0).floor();
+    let minutes = ((arcseconds - whole_degrees * 3600.0) / 60.0).floor();
+    let seconds = arcseconds - whole_degrees * 3600.0 - minutes * 60.0;
+
+    let mut label = format!("{}\u{b0}", whole_degrees);
+    if minutes != 0.0 || seconds != 0.0 {
+        label += &format!("{}\u{2032}", minutes);
+    }
+    if seconds != 0.0 {
+        label += &format!("{:.*}\u{2033}", decimals, seconds);
+    }
+
+    let hemisphere = match axis {
+        _ if arcseconds == 0.0 => "",
+        GeoAxis::Longitude if arcseconds == 180.0 * 3600.0 => "",
+        GeoAxis::Latitude if degrees > 0.0 => "N",
+        GeoAxis::Latitude => "S",
+        GeoAxis::Longitude if degrees > 0.0 => "E",
+        GeoAxis::Longitude => "W",
+    };
+    label + hemisphere
+}
+
+/// Like [`labeled_ticks`](crate::labeled_ticks) for [`geo_ticks`], labeling each tick as by
+/// [`format_dms`] with the decimal places of seconds the step needs.
+///
+/// ```
+/// use axis_ticks::{labeled_geo_ticks, GeoAxis};
+///
+/// let labels: Vec<String> = labeled_geo_ticks(-1.0, 0.0, 2, GeoAxis::Longitude)
+///     .into_iter()
+///     .map(|tick| tick.label)
+///     .collect();
+///
+/// assert_eq!(labels, ["1°W", "0°30′W", "0°"]);
+/// ```
+pub fn labeled_geo_ticks(start: f64, stop: f64, count: usize, axis: GeoAxis) -> Vec<Tick<f64>> {
+    let ticks = geo_ticks(start, stop, count);
+    let step = match ticks.as_slice() {
+        [a, b, ..] => (b - a).abs() * 3600.0,
+        _ => 0.0,
+    };
+    let decimals = if step > 0.0 && step < 1.0 {
+        (-decimal_exponent(step)).max(0) as usize
+    } else {
+        0
+    };
+
+    ticks
+        .into_iter()
+        .map(|value| Tick {
+            value,
+            label: format_dms(value, axis, decimals),
+            kind: TickKind::Major,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MAX_TICKS;
+
+    fn labels(start: f64, stop: f64, count: usize, axis: GeoAxis) -> Vec<String> {
+        labeled_geo_ticks(start, stop, count, axis)
+            .into_iter()
+            .map(|tick| tick.label)
+            .collect()
+    }
+
+    #[test]
+    fn steps_on_the_dms_ladder() {
+        assert_eq!(geo_ticks(0.0, 50.0, 5), [0.0, 10.0, 20.0, 30.0, 40.0, 50.0]);
+        assert_eq!(
+            geo_ticks(-90.0, 90.0, 6),
+            [-90.0, -60.0, -30.0, 0.0, 30.0, 60.0, 90.0]
+        );
+        assert_eq!(geo_ticks(-180.0, 180.0, 4), [-180.0, -90.0, 0.0, 90.0]);
+        assert_eq!(geo_ticks(1.0, 0.0, 2), [1.0, 0.5, 0.0]);
+        assert_eq!(geo_ticks(0.0, 0.01, 4)[1], 10.0 / 3600.0);
+        assert_eq!(
+            geo_ticks(0.0, 1.0 / 3600.0, 2),
+            [0.0, 0.5 / 3600.0, 1.0 / 3600.0]
+        );
+        assert!(geo_ticks(0.0, 1.0, 0).is_empty());
+        assert!(geo_ticks(f64::NAN, 1.0, 4).is_empty());
+        assert_eq!(geo_ticks(45.0, 45.0, 4), [45.0]);
+        assert_eq!(geo_ticks(-f64::MAX, f64::MAX, 4).len(), 5);
+        assert!(geo_ticks(-180.0, 180.0, usize::MAX).len() <= MAX_TICKS);
+        assert!(geo_ticks(-180.0, 180.0, 1_000_000).len() <= MAX_TICKS);
+    }
+
+    #[test]
+    fn formats_degrees_minutes_and_seconds() {
+        assert_eq!(format_dms(45.5, GeoAxis::Latitude, 0), "45°30′N");
+        assert_eq!(format_dms(0.0, GeoAxis::Latitude, 0), "0°");
+        assert_eq!(format_dms(-0.0001, GeoAxis::Latitude, 0), "0°");
+        assert_eq!(
+            format_dms(-122.42014, GeoAxis::Longitude, 1),
+            "122°25′12.5″W"
+        );
+        assert_eq!(format_dms(180.0, GeoAxis::Longitude, 0), "180°");
+        assert_eq!(format_dms(-180.0, GeoAxis::Longitude, 0), "180°");
+        assert_eq!(format_dms(59.99999, GeoAxis::Longitude, 0), "60°E");
+        assert_eq!(
+            format_dms(10.0 + 1.0 / 60.0, GeoAxis::Latitude, 2),
+            "10°1′N"
+        );
+    }
+
+    #[test]
+    fn labels_ticks_with_the_seconds_the_step_needs() {
+        assert_eq!(
+            labels(10.0, 10.25, 3, GeoAxis::Latitude),
+            ["10°N", "10°5′N", "10°10′N", "10°15′N"]
+        );
+        assert_eq!(
+            labels(0.0, 0.5 / 3600.0, 5, GeoAxis::Longitude),
+            [
+                "0°",
+                "0°0′0.1″E",
+                "0°0′0.2″E",
+                "0°0′0.3″E",
+                "0°0′0.4″E",
+                "0°0′0.5″E"
+            ]
+        );
+        assert!(labeled_geo_ticks(0.0, 1.0, 0, GeoAxis::Latitude).is_empty());
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{ticks, TickValue};
+use alloc::vec::Vec;
+use half::{bf16, f16};
+
+/// Like [`ticks`] but for [`struct@f16`] values, computed in `f32` so that tiny and subnormal
+/// domains still get sensible ticks.
+///
+/// Each tick is rounded to the nearest `f16`. Where the step is finer than the precision of `f16`
+/// around the domain, ticks that round to the same value are only returned once.
+///
+/// ```
+/// use axis_ticks::ticks_f16;
+/// use half::f16;
+///
+/// let ticks = ticks_f16(f16::from_f32(0.0), f16::from_f32(1.0), 4);
+///
+/// assert_eq!(ticks, [0.0, 0.2, 0.4, 0.6, 0.8, 1.0].map(f16::from_f32));
+/// ```
+pub fn ticks_f16(start: f16, stop: f16, count: usize) -> Vec<f16> {
+    narrow(ticks(start.to_f32(), stop.to_f32(), count), f16::from_f32)
+}
+
+/// Like [`ticks_f16`] but for [`bf16`] values.
+pub fn ticks_bf16(start: bf16, stop: bf16, count: usize) -> Vec<bf16> {
+    narrow(ticks(start.to_f32(), stop.to_f32(), count), bf16::from_f32)
+}
+
+macro_rules! impl_half {
+    ($($half:ident => $ticks:ident),*) => {$(
+        impl TickValue for $half {
+            fn position(&self) -> Option<f64> {
+                if self.is_finite() {
+                    Some(self.to_f64())
+                } else {
+                    None
+                }
+            }
+
+            fn step_multiple(index: i128, mantissa: u32, exponent: i32) -> Option<Self> {
+                f32::step_multiple(index, mantissa, exponent).map($half::from_f32)
+            }
+
+            fn ticks(start: &Self, stop: &Self, count: usize) -> Vec<Self> {
+                $ticks(*start, *stop, count)
+            }
+        }
+    )*};
+}
+
+impl_half!(f16 => ticks_f16, bf16 => ticks_bf16);
+
+fn narrow<H: PartialEq>(ticks: Vec<f32>, from_f32: fn(f32) -> H) -> Vec<H> {
+    let mut ticks: Vec<H> = ticks.into_iter().map(from_f32).collect();
+    ticks.dedup();
+    ticks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticks_tiny_domains() {
+        let ticks = ticks_f16(f16::from_f32(1e-4), f16::from_f32(2e-4), 5);
+
+        // `f16::from_f32(2e-4)` is slightly above `2e-4`, so the ticks extend to `2.2e-4`.
+        assert_eq!(
+            ticks,
+            [1e-4, 1.2e-4, 1.4e-4, 1.6e-4, 1.8e-4, 2e-4, 2.2e-4].map(f16::from_f32)
+        );
+        assert_eq!(
+            ticks_f16(
+                f16::ZERO,
+                f16::MIN_POSITIVE_SUBNORMAL * f16::from_f32(4.0),
+                2
+            )
+            .len(),
+            4
+        );
+    }
+
+    #[test]
+    fn merges_ticks_below_the_precision() {
+        let ticks = ticks_f16(f16::from_f32(1000.0), f16::from_f32(1001.0), 10);
+
+        assert_eq!(ticks, [1000.0, 1000.5, 1001.0].map(f16::from_f32));
+    }
+
+    #[test]
+    fn ticks_bfloat16() {
+        assert_eq!(
+            ticks_bf16(bf16::from_f32(100.0), bf16::ZERO, 4),
+            [100.0, 80.0, 60.0, 40.0, 20.0, 0.0].map(bf16::from_f32)
+        );
+        assert!(ticks_bf16(bf16::NAN, bf16::ONE, 4).is_empty());
+    }
+}
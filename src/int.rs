@@ -0,0 +1,180 @@
+use crate::MAX_TICKS;
+use alloc::{vec, vec::Vec};
+use core::convert::TryFrom;
+
+/// Like [`ticks`](crate::ticks) but for `i64` values, using integer arithmetic throughout so that
+/// values beyond 2^53, such as byte offsets and IDs, stay exact.
+///
+/// The step is 1, 2 or 5 times a power of ten, chosen as [`ticks`](crate::ticks) chooses it, but
+/// never less than 1. The ticks run in the direction from `start` to `stop`, and a `count` of zero
+/// returns an empty `Vec`. At most [`MAX_TICKS`] ticks are returned, falling back to a coarser
+/// step as [`ticks`](crate::ticks) does.
+///
+/// ```
+/// use axis_ticks::ticks_int;
+///
+/// assert_eq!(ticks_int(0, 10, 5), [0, 2, 4, 6, 8, 10]);
+/// assert_eq!(
+///     ticks_int(9_007_199_254_740_993, 9_007_199_254_741_001, 4),
+///     [9_007_199_254_740_994, 9_007_199_254_740_996, 9_007_199_254_740_998, 9_007_199_254_741_000]
+/// );
+/// ```
+pub fn ticks_int(start: i64, stop: i64, count: usize) -> Vec<i64> {
+    int_ticks(i128::from(start), i128::from(stop), count)
+        .into_iter()
+        .map(|tick| i64::try_from(tick).unwrap())
+        .collect()
+}
+
+/// Like [`ticks_int`] but for `u64` values.
+pub fn ticks_uint(start: u64, stop: u64, count: usize) -> Vec<u64> {
+    int_ticks(i128::from(start), i128::from(stop), count)
+        .into_iter()
+        .map(|tick| u64::try_from(tick).unwrap())
+        .collect()
+}
+
+/// The ticks between two values that fit in 64 bits, so that the span and its square fit in
+/// `u128`.
+fn int_ticks(start: i128, stop: i128, count: usize) -> Vec<i128> {
+    if count == 0 {
+        return vec![];
+    }
+    if start == stop {
+        return vec![start];
+    }
+
+    let reverse = stop < start;
+    let (lo, hi) = if reverse {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+    let span = (hi - lo) as u128;
+
+    let mut step = int_step(span, count);
+    let mut bounds = int_bounds(lo, hi, step);
+    if bounds.1 > MAX_TICKS as u128 {
+        // Try the finest grid that might fit first, as `TickRange` does.
+        for &coarse_count in &[MAX_TICKS - 1, MAX_TICKS / 2] {
+            step = int_step(span, coarse_count);
+            bounds = int_bounds(lo, hi, step);
+            if bounds.1 <= MAX_TICKS as u128 {
+                break;
+            }
+        }
+    }
+
+    let (first, len) = bounds;
+    let len = len.min(MAX_TICKS as u128) as usize;
+    let mut ticks: Vec<i128> = (0..len).map(|i| first + i as i128 * step).collect();
+    if reverse {
+        ticks.reverse();
+    }
+
+    ticks
+}
+
+/// The step of 1, 2 or 5 times a power of ten for `count` intervals over `span`, at least 1.
+///
+/// As for floats, the mantissa is the one whose threshold of `sqrt(2)`, `sqrt(10)` or `sqrt(50)`
+/// the exact step divided by its power of ten reaches, compared here by squaring.
+fn int_step(span: u128, count: usize) -> i128 {
+    let count = count as u128;
+    if span < count {
+        return 1;
+    }
+
+    // The largest power of ten with `count * power <= span`.
+    let mut power: u128 = 1;
+    while let Some(next) = power.checked_mul(10) {
+        match next.checked_mul(count) {
+            Some(bin) if bin <= span => power = next,
+            _ => break,
+        }
+    }
+
+    let bin = count * power;
+    let span_squared = span * span;
+    let reaches = |threshold: u128| {
+        bin.checked_mul(bin)
+            .and_then(|bin_squared| bin_squared.checked_mul(threshold))
+            .is_some_and(|bound| span_squared >= bound)
+    };
+    let mantissa = if reaches(50) {
+        10
+    } else if reaches(10) {
+        5
+    } else if reaches(2) {
+        2
+    } else {
+        1
+    };
+
+    (mantissa * power) as i128
+}
+
+/// The first tick and the number of ticks for `step` over `[lo, hi]`.
+fn int_bounds(lo: i128, hi: i128, step: i128) -> (i128, u128) {
+    let first = -(-lo).div_euclid(step) * step;
+    let last = hi.div_euclid(step) * step;
+    if last < first {
+        (first, 0)
+    } else {
+        (first, ((last - first) / step) as u128 + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ticks;
+
+    #[test]
+    fn matches_float_ticks_for_small_values() {
+        for &(start, stop, count) in &[(0, 10, 5), (-37, 91, 10), (100, -100, 4), (3, 5, 10)] {
+            let expected: Vec<i64> = ticks(start as f64, stop as f64, count)
+                .into_iter()
+                .filter(|tick| tick.fract() == 0.0)
+                .map(|tick| tick as i64)
+                .collect();
+
+            assert_eq!(ticks_int(start, stop, count), expected);
+        }
+    }
+
+    #[test]
+    fn stays_exact_beyond_the_float_mantissa() {
+        let start = i64::MAX - 1000;
+        let ticks = ticks_int(start, i64::MAX, 2);
+
+        assert_eq!(ticks, [i64::MAX - 807, i64::MAX - 307]);
+        assert_eq!(
+            ticks_uint(u64::MAX - 10, u64::MAX, 2),
+            [u64::MAX - 10, u64::MAX - 5, u64::MAX]
+        );
+    }
+
+    #[test]
+    fn spans_the_whole_range() {
+        let ticks = ticks_int(i64::MIN, i64::MAX, 4);
+
+        assert_eq!(
+            ticks,
+            [-5_000_000_000_000_000_000, 0, 5_000_000_000_000_000_000]
+        );
+        assert_eq!(ticks_uint(0, u64::MAX, 2), [0, 10_000_000_000_000_000_000]);
+    }
+
+    #[test]
+    fn never_steps_by_less_than_one() {
+        assert_eq!(ticks_int(0, 3, 10), [0, 1, 2, 3]);
+        assert!(ticks_int(0, 1_000_000, usize::MAX).len() <= MAX_TICKS);
+    }
+
+    #[test]
+    fn handles_degenerate_arguments() {
+        assert_eq!(ticks_int(5, 5, 10), [5]);
+        assert!(ticks_int(0, 10, 0).is_empty());
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(feature = "alloc")]
+use crate::ticks;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use num_traits::float::Float;
+#[cfg(feature = "alloc")]
+use num_traits::{cast::FromPrimitive, float::FloatConst};
+
+/// Which ticks coinciding with the ends of the domain to emit, for [`ticks_interval`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Interval {
+    /// Ticks on either endpoint are emitted, as [`ticks`](crate::ticks). The default.
+    #[default]
+    Closed,
+    /// Ticks on either endpoint are dropped, e.g. for gridlines that must not overlap the frame.
+    Open,
+    /// A tick on `start` is emitted but a tick on `stop` is dropped.
+    HalfOpen,
+}
+
+impl Interval {
+    /// Whether `tick` is emitted for the domain from `start` to `stop`. Only ticks exactly equal
+    /// to an endpoint are affected, as the ticks always lie within the domain.
+    pub fn admits<T: Float>(self, tick: T, start: T, stop: T) -> bool {
+        match self {
+            Interval::Closed => true,
+            Interval::Open => tick != start && tick != stop,
+            Interval::HalfOpen => tick != stop || tick == start,
+        }
+    }
+}
+
+/// Like [`ticks`] but drops the ticks coinciding with the endpoints that `interval` excludes.
+///
+/// ```
+/// use axis_ticks::{ticks_interval, Interval};
+///
+/// assert_eq!(ticks_interval(0.0, 1.0, 5, Interval::Open), [0.2, 0.4, 0.6, 0.8]);
+/// assert_eq!(ticks_interval(0.0, 1.0, 5, Interval::HalfOpen), [0.0, 0.2, 0.4, 0.6, 0.8]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn ticks_interval<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+    interval: Interval,
+) -> Vec<T> {
+    let mut ticks = ticks(start, stop, count);
+    ticks.retain(|&tick| interval.admits(tick, start, stop));
+    ticks
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closed_matches_ticks() {
+        assert_eq!(
+            ticks_interval(0.0, 1.0, 10, Interval::Closed),
+            ticks(0.0, 1.0, 10)
+        );
+        assert_eq!(Interval::default(), Interval::Closed);
+    }
+
+    #[test]
+    fn open_drops_both_endpoints() {
+        assert_eq!(
+            ticks_interval(10.0, -10.0, 4, Interval::Open),
+            [5.0, 0.0, -5.0]
+        );
+        assert_eq!(
+            ticks_interval(-0.15, 0.25, 10, Interval::Open),
+            [-0.1, -0.05, 0.0, 0.05, 0.1, 0.15, 0.2]
+        );
+    }
+
+    #[test]
+    fn half_open_drops_only_the_stop() {
+        assert_eq!(
+            ticks_interval(10.0, -10.0, 4, Interval::HalfOpen),
+            [10.0, 5.0, 0.0, -5.0]
+        );
+        assert_eq!(
+            ticks_interval(-0.15, 0.25, 10, Interval::HalfOpen),
+            [-0.15, -0.1, -0.05, 0.0, 0.05, 0.1, 0.15, 0.2]
+        );
+    }
+
+    #[test]
+    fn keeps_a_point_domain_unless_open() {
+        assert_eq!(ticks_interval(1.0, 1.0, 10, Interval::HalfOpen), [1.0]);
+        assert!(ticks_interval(1.0, 1.0, 10, Interval::Open).is_empty());
+    }
+}
@@ -0,0 +1,238 @@
+use crate::TickRange;
+use core::{iter::FusedIterator, ops::Deref};
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// An iterator over the same ticks as [`ticks`](crate::ticks), created by [`ticks_iter`].
+///
+/// Each tick is computed on demand, so iterating never allocates. The remaining extremes and the
+/// step can be queried without advancing the iterator.
+#[derive(Debug, Clone)]
+pub struct TicksIter<T> {
+    range: TickRange<T>,
+    front: usize,
+    back: usize,
+}
+
+/// Returns an iterator over the ticks that [`ticks`](crate::ticks) would return for the same
+/// arguments, without allocating a `Vec`.
+pub fn ticks_iter<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> TicksIter<T> {
+    let range = TickRange::new(start, stop, count);
+    TicksIter {
+        range,
+        front: 0,
+        back: range.len(),
+    }
+}
+
+/// Writes the ticks that [`ticks`](crate::ticks) would return into `buffer` without allocating,
+/// returning the number written.
+///
+/// If `buffer` is too short only the first ticks that fit are written. The exact number of ticks
+/// is the [`len`](ExactSizeIterator::len) of [`ticks_iter`] for the same arguments.
+pub fn ticks_into<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+    buffer: &mut [T],
+) -> usize {
+    buffer
+        .iter_mut()
+        .zip(ticks_iter(start, stop, count))
+        .map(|(slot, tick)| *slot = tick)
+        .count()
+}
+
+/// Up to `N` ticks stored inline, from [`ticks_fixed`]. Dereferences to a slice of the ticks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TickArray<T, const N: usize> {
+    ticks: [T; N],
+    len: usize,
+}
+
+impl<T, const N: usize> Deref for TickArray<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.ticks[..self.len]
+    }
+}
+
+/// Generates the ticks that [`ticks`](crate::ticks) would return into a fixed-capacity array, for
+/// a compile-time bound on memory.
+///
+/// Returns `None` if there are more than `N` ticks. Use [`ticks_into`] to truncate instead.
+pub fn ticks_fixed<T: Float + FloatConst + FromPrimitive, const N: usize>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Option<TickArray<T, N>> {
+    let iter = ticks_iter(start, stop, count);
+    if iter.len() > N {
+        return None;
+    }
+
+    let mut ticks = [T::nan(); N];
+    let len = ticks_into(start, stop, count, &mut ticks);
+    Some(TickArray { ticks, len })
+}
+
+impl<T: Float + FloatConst + FromPrimitive> TicksIter<T> {
+    /// The next tick the iterator will yield, or `None` if it is exhausted.
+    pub fn first(&self) -> Option<T> {
+        if self.front < self.back {
+            Some(self.range.get(self.front))
+        } else {
+            None
+        }
+    }
+
+    /// The final tick the iterator will yield, or `None` if it is exhausted. Unlike the consuming
+    /// [`Iterator::last`] this keeps the iterator.
+    pub fn last_tick(&self) -> Option<T> {
+        if self.front < self.back {
+            Some(self.range.get(self.back - 1))
+        } else {
+            None
+        }
+    }
+
+    /// The signed distance between consecutive ticks, even if only one of them falls in the
+    /// domain, or NaN if there is no step because `start == stop`, `count` is zero or the domain
+    /// is not finite.
+    pub fn step(&self) -> T {
+        self.range.step().unwrap_or_else(T::nan)
+    }
+}
+
+impl<T: Float + FloatConst + FromPrimitive> Iterator for TicksIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let tick = self.first()?;
+        self.front += 1;
+        Some(tick)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T: Float + FloatConst + FromPrimitive> DoubleEndedIterator for TicksIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        let tick = self.last_tick()?;
+        self.back -= 1;
+        Some(tick)
+    }
+}
+
+impl<T: Float + FloatConst + FromPrimitive> ExactSizeIterator for TicksIter<T> {}
+
+impl<T: Float + FloatConst + FromPrimitive> FusedIterator for TicksIter<T> {}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::ticks;
+
+    #[test]
+    fn first_last_tick_and_step_match_the_collected_ticks() {
+        for &(start, stop, count) in &[(0.0, 1.0, 10), (10.0, -10.0, 5), (-0.125, 0.25, 10)] {
+            let iter = ticks_iter(start, stop, count);
+            let expected = ticks(start, stop, count);
+
+            assert_eq!(iter.first(), expected.first().copied());
+            assert_eq!(iter.last_tick(), expected.last().copied());
+            assert!((iter.step() - (expected[1] - expected[0])).abs() < 1e-12);
+            assert_eq!(iter.collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn first_and_last_tick_track_the_remaining_ticks() {
+        let mut iter = ticks_iter(0.0, 1.0, 5);
+
+        assert_eq!(iter.next(), Some(0.0));
+        assert_eq!(iter.first(), Some(0.2));
+        assert_eq!(iter.last_tick(), Some(1.0));
+
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.first(), None);
+        assert_eq!(iter.last_tick(), None);
+    }
+
+    #[test]
+    fn iterates_from_either_end_with_an_exact_size() {
+        let mut iter = ticks_iter(0.0, 1.0, 5);
+
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.next_back(), Some(1.0));
+        assert_eq!(iter.next(), Some(0.0));
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.last_tick(), Some(0.8));
+        assert_eq!(iter.rev().collect::<Vec<_>>(), [0.8, 0.6, 0.4, 0.2]);
+
+        let expected: Vec<f64> = ticks(10.0, -10.0, 5).into_iter().rev().collect();
+        assert_eq!(
+            ticks_iter(10.0, -10.0, 5).rev().collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!(ticks_iter(0.0, 1.0, 0).len(), 0);
+    }
+
+    #[test]
+    fn ticks_into_fills_the_buffer() {
+        let mut buffer = [f64::NAN; 16];
+        let written = ticks_into(-0.125, 0.25, 10, &mut buffer);
+
+        assert_eq!(written, 9);
+        assert_eq!(buffer[..written], ticks(-0.125, 0.25, 10)[..]);
+        assert!(buffer[written].is_nan());
+    }
+
+    #[test]
+    fn ticks_into_truncates_to_a_short_buffer() {
+        let mut buffer = [0.0; 3];
+
+        assert_eq!(ticks_into(0.0, 1.0, 10, &mut buffer), 3);
+        assert_eq!(buffer, [0.0, 0.1, 0.2]);
+        assert_eq!(ticks_into(0.0, 1.0, 10, &mut []), 0);
+    }
+
+    #[test]
+    fn ticks_fixed_stores_ticks_inline() {
+        let fixed = ticks_fixed::<f64, 16>(-0.125, 0.25, 10).unwrap();
+
+        assert_eq!(fixed.len(), 9);
+        assert_eq!(*fixed, ticks(-0.125, 0.25, 10)[..]);
+        assert_eq!(ticks_fixed::<f64, 0>(0.0, 1.0, 0).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn ticks_fixed_returns_none_when_the_ticks_do_not_fit() {
+        assert_eq!(ticks_fixed::<f64, 10>(0.0, 1.0, 10), None);
+        assert!(ticks_fixed::<f64, 11>(0.0, 1.0, 10).is_some());
+    }
+
+    #[test]
+    fn step_is_nan_without_a_step() {
+        assert!(ticks_iter(1.0, 1.0, 10).step().is_nan());
+        assert!(ticks_iter(0.0, 1.0, 0).step().is_nan());
+    }
+
+    #[test]
+    fn step_is_finite_for_a_single_tick() {
+        let iter = ticks_iter(-10.0, 10.0, 1);
+
+        assert_eq!(iter.first(), iter.last_tick());
+        assert_eq!(iter.step(), 20.0);
+    }
+}
@@ -0,0 +1,457 @@
+use crate::{labeled_ticks, tick_step, ticks, Tick, MAX_TICKS};
+use alloc::vec::Vec;
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// Turns ticks into gridline segments spanning the orthogonal axis from `axis_min` to `axis_max`.
+///
+/// With `vertical` set, each tick is treated as an x value and produces a vertical line
+/// `((tick, axis_min), (tick, axis_max))`; otherwise each tick is a y value producing a horizontal
+/// line `((axis_min, tick), (axis_max, tick))`.
+pub fn gridline_segments(
+    ticks: &[f64],
+    axis_min: f64,
+    axis_max: f64,
+    vertical: bool,
+) -> Vec<((f64, f64), (f64, f64))> {
+    ticks
+        .iter()
+        .map(|&tick| {
+            if vertical {
+                ((tick, axis_min), (tick, axis_max))
+            } else {
+                ((axis_min, tick), (axis_max, tick))
+            }
+        })
+        .collect()
+}
+
+/// Generates ticks with their screen position along an axis of length `screen_len`, as
+/// `(value, position, was_clamped)`.
+///
+/// `start` maps to position 0 and `stop` to `screen_len`. Ticks that fall slightly outside the
+/// domain because of rounding are clamped to the ends of the axis with `was_clamped` set, so they
+/// can be drawn at the edge or skipped. If `start == stop` every tick is placed at 0.
+pub fn ticks_screen_clamped(
+    start: f64,
+    stop: f64,
+    count: usize,
+    screen_len: f64,
+) -> Vec<(f64, f64, bool)> {
+    let span = stop - start;
+    let (lo, hi) = if screen_len < 0.0 {
+        (screen_len, 0.0)
+    } else {
+        (0.0, screen_len)
+    };
+
+    ticks(start, stop, count)
+        .into_iter()
+        .map(|value| {
+            let position = if span == 0.0 {
+                0.0
+            } else {
+                (value - start) / span * screen_len
+            };
+            let clamped = position.max(lo).min(hi);
+            (value, clamped, clamped != position)
+        })
+        .collect()
+}
+
+/// Generates the color stops for a gradient legend as `(normalized_position, value)` pairs, where
+/// the position of each nicely rounded tick is normalized so `vmin` is 0 and `vmax` is 1.
+///
+/// Ticks that round outside the domain are dropped. If `vmin == vmax` the single stop is at 0.
+pub fn legend_stops(vmin: f64, vmax: f64, count: usize) -> Vec<(f64, f64)> {
+    let span = vmax - vmin;
+
+    ticks(vmin, vmax, count)
+        .into_iter()
+        .map(|value| {
+            let position = if span == 0.0 {
+                0.0
+            } else {
+                (value - vmin) / span
+            };
+            (position, value)
+        })
+        .filter(|&(position, _)| (0.0..=1.0).contains(&position))
+        .collect()
+}
+
+/// Generates ticks as `(value, position)` pairs, where `position` is the fraction of the way from
+/// `start` to `stop` at which the tick falls, for renderers that scale it by the size of their
+/// viewport themselves.
+///
+/// Unlike [`legend_stops`] every tick is kept, and `start` may be greater than `stop`. If
+/// `start == stop` the single tick is at 0.
+///
+/// ```
+/// use axis_ticks::tick_positions;
+///
+/// assert_eq!(tick_positions(10.0, 0.0, 2), [(10.0, 0.0), (5.0, 0.5), (0.0, 1.0)]);
+/// ```
+pub fn tick_positions<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Vec<(T, f64)> {
+    let span = stop - start;
+
+    ticks(start, stop, count)
+        .into_iter()
+        .map(|value| {
+            let position = if span == T::zero() {
+                0.0
+            } else {
+                ((value - start) / span).to_f64().unwrap_or(f64::NAN)
+            };
+            (value, position)
+        })
+        .collect()
+}
+
+/// The tick count for an axis `px_length` pixels long whose labels need at least
+/// `min_px_spacing` pixels each, such as 10 for a 640 px axis with 60 px labels.
+///
+/// This is the count to pass to [`ticks`], but the nice step [`ticks`] picks can be up to about
+/// 30% finer than `px_length / count`, so use [`ticks_for_px`] to guarantee the spacing. Returns 0
+/// if either length is not finite and positive, and at most [`MAX_TICKS`].
+pub fn count_for(px_length: f64, min_px_spacing: f64) -> usize {
+    let valid = |length: f64| length.is_finite() && length > 0.0;
+    if !valid(px_length) || !valid(min_px_spacing) {
+        return 0;
+    }
+    let count = Float::floor(px_length / min_px_spacing);
+    if count >= MAX_TICKS as f64 {
+        MAX_TICKS
+    } else {
+        count as usize
+    }
+}
+
+/// Generates as many ticks as fit on an axis `px_length` pixels long with at least
+/// `min_px_spacing` pixels between consecutive ticks.
+///
+/// Starts from the count of [`count_for`] and lowers it until the step of [`ticks`] is at least
+/// `min_px_spacing` on screen. Returns an empty `Vec` if not even two ticks fit.
+///
+/// ```
+/// use axis_ticks::ticks_for_px;
+///
+/// assert_eq!(ticks_for_px(0.0, 14.5, 640.0, 60.0), [0.0, 2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0]);
+/// ```
+pub fn ticks_for_px(start: f64, stop: f64, px_length: f64, min_px_spacing: f64) -> Vec<f64> {
+    let span = (stop - start).abs();
+    let fits =
+        |count: usize| tick_step(start, stop, count).abs() / span * px_length >= min_px_spacing;
+
+    match (1..=count_for(px_length, min_px_spacing))
+        .rev()
+        .find(|&count| fits(count))
+    {
+        Some(count) => ticks(start, stop, count),
+        None => Vec::new(),
+    }
+}
+
+/// The direction of an axis, deciding how much of it each label takes up.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Orientation {
+    /// An x axis, along which each label takes up its measured width.
+    Horizontal,
+    /// A y axis, along which each label, written horizontally, takes up one line of
+    /// `line_height` pixels whatever its width.
+    Vertical {
+        /// The height of a line of label text in pixels.
+        line_height: f32,
+    },
+}
+
+/// Generates labeled ticks for an axis `px_length` pixels long whose labels do not overlap,
+/// measuring each label with `measure`, which returns its width in pixels as the renderer would
+/// draw it.
+///
+/// Starting from `count`, the count is lowered until every pair of neighbouring labels, centered
+/// on their ticks, is at least `padding` pixels apart, with labels regenerated for each count as
+/// by [`labeled_ticks`]. If even a count of 1 overlaps, its ticks are thinned, keeping each that
+/// clears the last one kept.
+///
+/// ```
+/// use axis_ticks::{labeled_ticks_fitting, Orientation};
+///
+/// // A monospace font 7 pixels wide.
+/// let measure = |label: &str| label.len() as f32 * 7.0;
+///
+/// let ticks = labeled_ticks_fitting(0.0, 1.0, 10, 200.0, Orientation::Horizontal, 8.0, measure);
+/// let labels: Vec<String> = ticks.into_iter().map(|tick| tick.label).collect();
+///
+/// assert_eq!(labels, ["0.0", "0.2", "0.4", "0.6", "0.8", "1.0"]);
+/// ```
+pub fn labeled_ticks_fitting<F: Fn(&str) -> f32>(
+    start: f64,
+    stop: f64,
+    count: usize,
+    px_length: f64,
+    orientation: Orientation,
+    padding: f32,
+    measure: F,
+) -> Vec<Tick<f64>> {
+    let span = stop - start;
+    let position = |value: f64| {
+        if span == 0.0 {
+            0.0
+        } else {
+            (value - start) / span * px_length
+        }
+    };
+
+    fit_labels(
+        count,
+        orientation,
+        padding,
+        measure,
+        |count| labeled_ticks(start, stop, count),
+        position,
+    )
+}
+
+/// The ticks of `generate` for the highest count up to `count` whose labels, centered on their
+/// `position`s, are at least `padding` pixels apart, or if there is none those for a count of 1
+/// thinned greedily.
+pub(crate) fn fit_labels<F, G, P>(
+    count: usize,
+    orientation: Orientation,
+    padding: f32,
+    measure: F,
+    generate: G,
+    position: P,
+) -> Vec<Tick<f64>>
+where
+    F: Fn(&str) -> f32,
+    G: Fn(usize) -> Vec<Tick<f64>>,
+    P: Fn(f64) -> f64,
+{
+    let extent = |tick: &Tick<f64>| {
+        let size = match orientation {
+            Orientation::Horizontal => measure(&tick.label),
+            Orientation::Vertical { line_height } => line_height,
+        };
+        let center = position(tick.value);
+        (
+            center - f64::from(size) / 2.0,
+            center + f64::from(size) / 2.0,
+        )
+    };
+    let clear = |a: &Tick<f64>, b: &Tick<f64>| {
+        let ((a_lo, a_hi), (b_lo, b_hi)) = (extent(a), extent(b));
+        (b_lo - a_hi).max(a_lo - b_hi) >= f64::from(padding)
+    };
+
+    for count in (1..=count).rev() {
+        let ticks = generate(count);
+        if ticks.windows(2).all(|pair| clear(&pair[0], &pair[1])) {
+            return ticks;
+        }
+    }
+
+    let mut thinned: Vec<Tick<f64>> = Vec::new();
+    for tick in generate(count.min(1)) {
+        match thinned.last() {
+            Some(last) if !clear(last, &tick) => {}
+            _ => thinned.push(tick),
+        }
+    }
+    thinned
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gridline_segments_are_vertical_for_x_ticks() {
+        assert_eq!(
+            gridline_segments(&[0.0, 5.0, 10.0], -1.0, 1.0, true),
+            [
+                ((0.0, -1.0), (0.0, 1.0)),
+                ((5.0, -1.0), (5.0, 1.0)),
+                ((10.0, -1.0), (10.0, 1.0))
+            ]
+        );
+    }
+
+    #[test]
+    fn gridline_segments_are_horizontal_for_y_ticks() {
+        assert_eq!(
+            gridline_segments(&[0.2, 0.4], 0.0, 100.0, false),
+            [((0.0, 0.2), (100.0, 0.2)), ((0.0, 0.4), (100.0, 0.4))]
+        );
+        assert_eq!(gridline_segments(&[], 0.0, 100.0, false), []);
+    }
+
+    #[test]
+    fn ticks_screen_clamped_positions_ticks_along_the_axis() {
+        assert_eq!(
+            ticks_screen_clamped(0.0, 10.0, 2, 100.0),
+            [(0.0, 0.0, false), (5.0, 50.0, false), (10.0, 100.0, false)]
+        );
+        assert_eq!(
+            ticks_screen_clamped(10.0, 0.0, 2, 100.0),
+            [(10.0, 0.0, false), (5.0, 50.0, false), (0.0, 100.0, false)]
+        );
+    }
+
+    #[test]
+    fn ticks_screen_clamped_clamps_niced_ticks_past_the_domain() {
+        let ticks = ticks_screen_clamped(0.125, 0.25, 5, 100.0);
+
+        assert_eq!(ticks.len(), 8);
+        assert_eq!(ticks[0], (0.12, 0.0, true));
+        assert_eq!(ticks[7], (0.26, 100.0, true));
+        assert!(ticks[1..7]
+            .iter()
+            .all(|&(_, p, clamped)| !clamped && p > 0.0 && p < 100.0));
+    }
+
+    #[test]
+    fn ticks_screen_clamped_places_equal_bounds_at_zero() {
+        assert_eq!(
+            ticks_screen_clamped(5.0, 5.0, 10, 100.0),
+            [(5.0, 0.0, false)]
+        );
+    }
+
+    #[test]
+    fn tick_positions_are_fractions_of_the_domain() {
+        assert_eq!(
+            tick_positions(0.0, 50.0, 5),
+            [
+                (0.0, 0.0),
+                (10.0, 0.2),
+                (20.0, 0.4),
+                (30.0, 0.6),
+                (40.0, 0.8),
+                (50.0, 1.0)
+            ]
+        );
+        assert_eq!(tick_positions(-1.0f32, 3.0, 2), [(0.0, 0.25), (2.0, 0.75)]);
+        assert_eq!(tick_positions(2.0, 2.0, 5), [(2.0, 0.0)]);
+        assert!(tick_positions(0.0, 1.0, 0).is_empty());
+    }
+
+    #[test]
+    fn legend_stops_normalizes_tick_positions() {
+        assert_eq!(
+            legend_stops(0.0, 50.0, 5),
+            [
+                (0.0, 0.0),
+                (0.2, 10.0),
+                (0.4, 20.0),
+                (0.6, 30.0),
+                (0.8, 40.0),
+                (1.0, 50.0)
+            ]
+        );
+        assert!(legend_stops(0.125, 0.25, 5)
+            .iter()
+            .all(|(position, _)| (0.0..=1.0).contains(position)));
+    }
+
+    #[test]
+    fn legend_stops_handles_equal_bounds() {
+        assert_eq!(legend_stops(3.0, 3.0, 5), [(0.0, 3.0)]);
+    }
+
+    #[test]
+    fn count_for_divides_the_axis_into_labels() {
+        assert_eq!(count_for(640.0, 60.0), 10);
+        assert_eq!(count_for(59.0, 60.0), 0);
+        assert_eq!(count_for(640.0, 0.0), 0);
+        assert_eq!(count_for(f64::NAN, 60.0), 0);
+        assert_eq!(count_for(1e300, 1e-300), MAX_TICKS);
+    }
+
+    #[test]
+    fn ticks_for_px_keeps_the_minimum_spacing() {
+        for &(start, stop) in &[(0.0, 1.0), (0.0, 14.5), (-3.7, 123.4), (1e6, 0.0)] {
+            let ticks = ticks_for_px(start, stop, 640.0, 60.0);
+            let px = |value: f64| (value - start) / (stop - start) * 640.0;
+            assert!(ticks.len() >= 2);
+            assert!(ticks
+                .windows(2)
+                .all(|pair| (px(pair[1]) - px(pair[0])).abs() >= 60.0));
+        }
+
+        assert_eq!(ticks_for_px(0.0, 1.0, 640.0, 60.0), ticks(0.0, 1.0, 10));
+        assert_eq!(ticks_for_px(0.0, 1.0, 100.0, 60.0), [0.0, 1.0]);
+        assert!(ticks_for_px(0.0, 1.0, 50.0, 60.0).is_empty());
+        assert!(ticks_for_px(1.0, 1.0, 640.0, 60.0).is_empty());
+        assert!(ticks_for_px(f64::NAN, 1.0, 640.0, 60.0).is_empty());
+    }
+
+    #[test]
+    fn labeled_ticks_fitting_avoids_overlaps() {
+        let measure = |label: &str| label.len() as f32 * 7.0;
+        let labels = |ticks: Vec<Tick<f64>>| -> Vec<String> {
+            ticks.into_iter().map(|tick| tick.label).collect()
+        };
+
+        assert_eq!(
+            labels(labeled_ticks_fitting(
+                0.0,
+                1.0,
+                10,
+                1000.0,
+                Orientation::Horizontal,
+                8.0,
+                measure
+            )),
+            labels(labeled_ticks(0.0, 1.0, 10))
+        );
+        assert_eq!(
+            labels(labeled_ticks_fitting(
+                1e6,
+                0.0,
+                10,
+                300.0,
+                Orientation::Horizontal,
+                8.0,
+                measure
+            )),
+            ["1000000", "800000", "600000", "400000", "200000", "0"]
+        );
+        assert_eq!(
+            labeled_ticks_fitting(
+                0.0,
+                1.0,
+                10,
+                200.0,
+                Orientation::Vertical { line_height: 12.0 },
+                4.0,
+                measure
+            )
+            .len(),
+            11
+        );
+        assert_eq!(
+            labels(labeled_ticks_fitting(
+                0.0,
+                1.0,
+                10,
+                20.0,
+                Orientation::Horizontal,
+                20.0,
+                measure
+            )),
+            ["0"]
+        );
+        assert!(
+            labeled_ticks_fitting(0.0, 1.0, 0, 200.0, Orientation::Horizontal, 0.0, measure)
+                .is_empty()
+        );
+    }
+}
@@ -10,65 +10,1217 @@ assert_eq!(
     [-0.15, -0.1, -0.05, 0.0, 0.05, 0.1, 0.15, 0.2, 0.25]
 );
 ```
+
+## Optional features
+
+- `std` (default): enables `alloc` and uses the standard library for float math. Disable default
+  features and enable `libm` instead to use the crate in `no_std` environments.
+- `alloc`: functions returning a `Vec` or `String`. Without it, `ticks_iter`, `ticks_into`,
+  `nice`, `tick_step` and the other allocation-free functions remain available.
+- `libm`: float math through `libm` for `no_std` builds.
+- `chrono`: `datetime_ticks` and `format_datetime_tick` for `chrono::DateTime<Utc>`, and
+  `datetime_ticks_tz` for local time in any `chrono::TimeZone` such as a `chrono_tz::Tz`.
+- `egui`: `grid_marks` and `grid_spacer`, giving an `egui_plot::Plot` the grid of `ticks` and
+  `minor_ticks`.
+- `fixed`: `fixed_ticks` for the `fixed` crate's fixed-point numbers, using only integer
+  arithmetic. It needs `alloc` but not `std`.
+- `half`: `ticks_f16` and `ticks_bf16` for the `half` crate's 16-bit floats, computed in `f32`.
+- `num-complex`: `ticks_from_complex` for ticking the magnitudes of complex data.
+- `num-rational`: `rational_ticks` for exact ticks on `Ratio<i64>`, `BigRational` and other
+  `num_rational::Ratio` values.
+- `plotters`: `TickedRange`, a `plotters` coordinate whose grid lines and labels are placed by
+  `ticks` and labeled by `tick_format`.
+- `rust_decimal`: `decimal_ticks` for exact ticks on `rust_decimal::Decimal` values.
+- `serde`: deserializing a `FormatLocale` from the JSON of a d3-format locale file.
+- `time`: `offset_datetime_ticks` and `primitive_datetime_ticks` for the `time` crate's date-time types.
 */
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("axis-ticks needs either the `std` or the `libm` feature for float math");
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
 use num_traits::{
     cast::FromPrimitive,
     float::{Float, FloatConst},
 };
 
+#[cfg(feature = "alloc")]
+mod aligned;
+#[cfg(feature = "alloc")]
+mod angle;
+#[cfg(feature = "alloc")]
+mod axis;
+#[cfg(feature = "alloc")]
+mod broken;
+#[cfg(feature = "alloc")]
+mod builder;
+#[cfg(feature = "alloc")]
+mod bytes;
+#[cfg(feature = "num-complex")]
+mod complex;
+#[cfg(feature = "alloc")]
+mod currency;
+#[cfg(feature = "alloc")]
+mod data;
+#[cfg(feature = "chrono")]
+mod datetime;
+#[cfg(feature = "alloc")]
+mod decibel;
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+#[cfg(feature = "alloc")]
+mod diff;
+#[cfg(feature = "egui")]
+mod egui_plot;
+mod error;
+#[cfg(feature = "alloc")]
+mod extended;
+#[cfg(feature = "fixed")]
+mod fixed;
+#[cfg(feature = "alloc")]
+mod format;
+#[cfg(feature = "alloc")]
+mod geo;
+#[cfg(feature = "half")]
+mod half;
+#[cfg(feature = "alloc")]
+mod int;
+mod interval;
+mod iter;
+#[cfg(feature = "alloc")]
+mod layout;
+#[cfg(feature = "alloc")]
+mod locator;
+#[cfg(feature = "alloc")]
+mod log;
+#[cfg(feature = "alloc")]
+mod merge;
+#[cfg(feature = "alloc")]
+mod minor;
+#[cfg(feature = "alloc")]
+mod number_format;
+#[cfg(feature = "alloc")]
+mod percent;
+#[cfg(feature = "plotters")]
+mod plotters;
+#[cfg(feature = "alloc")]
+mod pow;
+#[cfg(feature = "alloc")]
+mod precision;
+#[cfg(feature = "alloc")]
+mod pretty;
+#[cfg(feature = "num-rational")]
+mod rational;
+mod scale;
+#[cfg(feature = "alloc")]
+mod sexagesimal;
+#[cfg(feature = "alloc")]
+mod threshold;
+#[cfg(feature = "alloc")]
+mod tick;
+#[cfg(feature = "std")]
+mod time;
+#[cfg(feature = "time")]
+mod time_crate;
+#[cfg(feature = "alloc")]
+mod tracker;
+mod validate;
+#[cfg(feature = "alloc")]
+mod value;
+
+#[cfg(feature = "egui")]
+pub use self::egui_plot::{grid_marks, grid_spacer};
+#[cfg(feature = "fixed")]
+pub use self::fixed::fixed_ticks;
+#[cfg(feature = "half")]
+pub use self::half::{ticks_bf16, ticks_f16};
+#[cfg(feature = "plotters")]
+pub use self::plotters::TickedRange;
+#[cfg(feature = "std")]
+pub use self::time::{
+    duration_ticks, time_tick_interval, time_ticks, timestamp_ticks, TimeInterval, TimeUnit,
+};
+#[cfg(feature = "alloc")]
+pub use aligned::aligned_ticks;
+#[cfg(feature = "alloc")]
+pub use angle::{angle_ticks, format_pi_fraction, polar_grid, radian_ticks, wrap_degrees};
+#[cfg(feature = "alloc")]
+pub use axis::{describe_axis, Axis, AxisDescription, PlacedTick};
+#[cfg(feature = "alloc")]
+pub use broken::{broken_axis_ticks, BrokenAxis};
+#[cfg(feature = "alloc")]
+pub use builder::{ticks_including_zero, Ticks, TicksBuilder};
+#[cfg(feature = "alloc")]
+pub use bytes::{byte_ticks, format_bytes, labeled_byte_ticks, ByteUnits};
+#[cfg(feature = "num-complex")]
+pub use complex::ticks_from_complex;
+#[cfg(feature = "alloc")]
+pub use currency::CurrencyFormat;
+#[cfg(feature = "alloc")]
+pub use data::{
+    quantile_ticks, robust_extent, ticks_density_weighted, ticks_from_data, ticks_from_data_robust,
+};
+#[cfg(feature = "chrono")]
+pub use datetime::{datetime_ticks, datetime_ticks_tz, format_datetime_tick};
+#[cfg(feature = "alloc")]
+pub use decibel::{decibel_ticks, decibel_ticks_linear, DecibelScale};
+#[cfg(feature = "rust_decimal")]
+pub use decimal::decimal_ticks;
+#[cfg(feature = "alloc")]
+pub use diff::{diff_ticks, AnimatedTick, TickDiff};
+pub use error::TickError;
+#[cfg(feature = "alloc")]
+pub use extended::extended_ticks;
+#[cfg(feature = "alloc")]
+pub use format::{
+    format_grouped, format_ticks_aligned, tick_format, GroupingStyle, TickFormat, TickNotation,
+};
+#[cfg(feature = "alloc")]
+pub use geo::{format_dms, geo_ticks, labeled_geo_ticks, GeoAxis};
+#[cfg(feature = "alloc")]
+pub use int::{ticks_int, ticks_uint};
+#[cfg(feature = "alloc")]
+pub use interval::ticks_interval;
+pub use interval::Interval;
+pub use iter::{ticks_fixed, ticks_into, ticks_iter, TickArray, TicksIter};
+#[cfg(feature = "alloc")]
+pub use layout::{
+    count_for, gridline_segments, labeled_ticks_fitting, legend_stops, tick_positions,
+    ticks_for_px, ticks_screen_clamped, Orientation,
+};
+#[cfg(feature = "alloc")]
+pub use locator::{MaxNLocator, Prune};
+#[cfg(feature = "alloc")]
+pub use log::{log_minor_ticks, log_ticks, log_ticks_grouped, logit_ticks, symlog_ticks};
+#[cfg(feature = "alloc")]
+pub use merge::{ticks_pinned, ticks_union};
+#[cfg(feature = "alloc")]
+pub use minor::{minor_ticks, minor_ticks_between};
+#[cfg(feature = "alloc")]
+pub use number_format::{
+    number_format, tick_format_specifier, FormatLocale, NumberFormat, ParseSpecifierError,
+};
+#[cfg(feature = "alloc")]
+pub use percent::percent_ticks;
+#[cfg(feature = "alloc")]
+pub use pow::pow_ticks;
+#[cfg(feature = "alloc")]
+pub use precision::{tick_decimals, tick_decimals_f32, ticks_round_endpoints};
+#[cfg(feature = "alloc")]
+pub use pretty::pretty;
+#[cfg(feature = "num-rational")]
+pub use rational::rational_ticks;
+pub use scale::LinearScale;
+#[cfg(feature = "alloc")]
+pub use sexagesimal::{format_hms, sexagesimal_ticks};
+#[cfg(feature = "alloc")]
+pub use threshold::{
+    bin_thresholds, threshold_freedman_diaconis, threshold_scott, threshold_sturges,
+};
+#[cfg(feature = "alloc")]
+pub use tick::{labeled_ticks, labeled_ticks_with_minor, Tick, TickKind};
+#[cfg(feature = "time")]
+pub use time_crate::{offset_datetime_ticks, primitive_datetime_ticks};
+#[cfg(feature = "alloc")]
+pub use tracker::{ExtentTracker, StableTicker};
+pub use validate::{validate_ticks, ValidationError};
+#[cfg(feature = "alloc")]
+pub use value::{tick_values, TickValue};
+
+/// The most ticks any function in this crate will generate.
+///
+/// Requests that would produce more, such as a huge `count` or a pathological domain, fall back to
+/// a coarser step that fits within the limit so that untrusted input cannot exhaust memory.
+pub const MAX_TICKS: usize = 10_000;
+
+/// Generates approximately `count + 1` nicely rounded ticks between `start` and `stop`.
+///
+/// Ticks are multiples of 1, 2 or 5 times a power of ten. If `stop < start` the ticks are returned
+/// in descending order. Each tick is the value of `T` nearest its exact decimal, so the ticks of
+/// `ticks(0.0, 1.0, 10)` format as `0.3` rather than `0.30000000000000004`.
+///
+/// A `count` of zero always returns an empty `Vec`, as does a NaN or infinite bound; use
+/// [`try_ticks`] to tell these apart. Otherwise, if `start == stop` the result is `[start]`
+/// regardless of `count`. At most [`MAX_TICKS`] ticks are returned.
+///
+/// As in d3, a `count` of 1 can pick a step as large as the whole domain, so `ticks(-10.0, 10.0, 1)`
+/// is the single tick `[0.0]` rather than the endpoints and `ticks(12.0, 48.0, 1)` is empty. Use
+/// [`ticks_endpoints_when_one`] to bracket the domain instead.
+///
+/// The bounds [`Float`], [`FloatConst`] and [`FromPrimitive`] are all implemented by `f32` and
+/// `f64`. Generic callers can use [`lit`] to build constants of `T`. To tick integers, decimals,
+/// dates or user types through the same API, see [`tick_values`].
+#[cfg(feature = "alloc")]
 pub fn ticks<T: Float + FloatConst + FromPrimitive>(start: T, stop: T, count: usize) -> Vec<T> {
-    if start == stop && count > 0 {
-        return vec![start];
+    try_ticks(start, stop, count).unwrap_or_default()
+}
+
+/// Like [`ticks`] but reports why no ticks could be generated instead of returning an empty `Vec`.
+#[cfg(feature = "alloc")]
+pub fn try_ticks<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Result<Vec<T>, TickError> {
+    check_arguments(start, stop, count)?;
+    Ok(ticks_iter(start, stop, count).collect())
+}
+
+#[cfg(feature = "alloc")]
+fn check_arguments<T: Float + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Result<(), TickError> {
+    if !start.is_finite() || !stop.is_finite() {
+        Err(TickError::NonFiniteDomain)
+    } else if count == 0 {
+        Err(TickError::ZeroCount)
+    } else if T::from_usize(count).is_none() {
+        Err(TickError::ConversionFailure)
+    } else {
+        Ok(())
+    }
+}
+
+/// Like [`ticks`] but with at most `max_ticks` ticks instead of [`MAX_TICKS`], falling back to a
+/// coarser step when `count` would give more.
+///
+/// This bounds the memory used for untrusted input, such as the zoom level of an interactive plot.
+#[cfg(feature = "alloc")]
+pub fn ticks_with_limit<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+    max_ticks: usize,
+) -> Vec<T> {
+    let range = TickRange::with_limit(start, stop, count, max_ticks);
+    (0..range.len()).map(|i| range.get(i)).collect()
+}
+
+/// Like [`try_ticks`] but returns [`TickError::TooManyTicks`] rather than a coarser step when
+/// `count` would give more than `max_ticks` ticks.
+#[cfg(feature = "alloc")]
+pub fn try_ticks_with_limit<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+    max_ticks: usize,
+) -> Result<Vec<T>, TickError> {
+    check_arguments(start, stop, count)?;
+    let len = TickRange::with_limit(start, stop, count, usize::MAX).len();
+    if len > max_ticks {
+        return Err(TickError::TooManyTicks { len });
     }
 
-    let reverse = stop < start;
-    let (start, stop) = if reverse {
-        (stop, start)
+    Ok(ticks_iter(start, stop, count).collect())
+}
+
+/// The ticks for a domain, stored as a range of step multiples so that they can be generated on
+/// demand.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TickRange<T> {
+    /// The multiple of the step at the lowest tick.
+    first: T,
+    len: usize,
+    /// The step as `(mantissa, exponent)` meaning `mantissa * 10^exponent`, or `None` for a
+    /// single tick at `first` when the domain is a point.
+    step: Option<(T, i32)>,
+    reverse: bool,
+}
+
+impl<T: Float + FloatConst + FromPrimitive> TickRange<T> {
+    pub(crate) fn new(start: T, stop: T, count: usize) -> Self {
+        TickRange::with_limit(start, stop, count, MAX_TICKS)
+    }
+
+    /// Like [`TickRange::new`] but falls back to a coarser step above `limit` ticks rather than
+    /// above [`MAX_TICKS`].
+    pub(crate) fn with_limit(start: T, stop: T, count: usize, limit: usize) -> Self {
+        TickRange::with_multiples(start, stop, count, limit, None)
+    }
+
+    /// Like [`TickRange::with_limit`] but chooses the step from `multiples` of powers of ten, as
+    /// [`ticks_with_multiples`], rather than d3's 1, 2 and 5.
+    pub(crate) fn with_multiples(
+        start: T,
+        stop: T,
+        count: usize,
+        limit: usize,
+        multiples: Option<&[T]>,
+    ) -> Self {
+        let empty = TickRange {
+            first: T::zero(),
+            len: 0,
+            step: None,
+            reverse: false,
+        };
+
+        if count == 0 || limit == 0 {
+            return empty;
+        }
+
+        if start == stop {
+            return TickRange {
+                first: start,
+                len: 1,
+                ..empty
+            };
+        }
+
+        // The span of a domain near the limits of `T` can overflow even though both bounds are
+        // finite, so tick a scaled-down copy of the domain and scale the result back up.
+        if (stop - start).is_infinite() && start.is_finite() && stop.is_finite() {
+            let scale = T::from_f64(10.0).unwrap();
+            let range =
+                TickRange::with_multiples(start / scale, stop / scale, count, limit, multiples);
+            return TickRange {
+                step: range
+                    .step
+                    .map(|(mantissa, exponent)| (mantissa, exponent + 1)),
+                ..range
+            };
+        }
+
+        let reverse = stop < start;
+        let (start, stop) = if reverse {
+            (stop, start)
+        } else {
+            (start, stop)
+        };
+
+        // The step as passed to `tick_bounds` alongside its `(mantissa, exponent)`.
+        let choose_step = |count: usize| match multiples {
+            None => {
+                let increment = tick_increment(start, stop, count);
+                if increment.is_zero() || !increment.is_finite() {
+                    None
+                } else {
+                    Some((increment, decimal_step(increment)))
+                }
+            }
+            Some(multiples) => {
+                let step = ladder_step(start, stop, count, multiples)?;
+                Some((multiple(step, T::one()), step))
+            }
+        };
+
+        let (mut increment, mut step) = match choose_step(count) {
+            Some(step) => step,
+            None => return empty,
+        };
+
+        let (mut first, mut len) = tick_bounds(start, stop, increment);
+        if len > limit {
+            // Try the finest grid that might fit first. The chosen step is never less than
+            // `0.6 * span / count`, so ticking with half the limit is sure to fit.
+            for &coarse_count in &[limit - 1, limit / 2] {
+                if let Some((coarse_increment, coarse_step)) = choose_step(coarse_count.max(1)) {
+                    increment = coarse_increment;
+                    step = coarse_step;
+                }
+                let (coarse_first, coarse_len) = tick_bounds(start, stop, increment);
+                first = coarse_first;
+                len = coarse_len;
+                if len <= limit {
+                    break;
+                }
+            }
+            len = len.min(limit);
+        }
+
+        TickRange {
+            first,
+            len,
+            step: Some(step),
+            reverse,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// The tick at `index` in output order, which must be less than [`TickRange::len`].
+    pub(crate) fn get(&self, index: usize) -> T {
+        let index = if self.reverse {
+            self.len - 1 - index
+        } else {
+            index
+        };
+        match self.step {
+            // Adding the index also turns a `-0.0` from rounding the bounds into `0.0`.
+            Some(step) => multiple(step, self.first + T::from_usize(index).unwrap()),
+            None => self.first,
+        }
+    }
+
+    /// The signed distance between consecutive ticks in output order, or `None` if there is no
+    /// step because there are no ticks or the domain is a point.
+    pub(crate) fn step(&self) -> Option<T> {
+        let step = multiple(self.step?, T::one());
+        Some(if self.reverse { -step } else { step })
+    }
+
+    /// The power of ten of the step, so that the step is 1, 2 or 5 times `10^power`, or `None` if
+    /// there is no step.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn power(&self) -> Option<i32> {
+        let (mantissa, exponent) = self.step?;
+        if mantissa >= T::from_f64(10.0).unwrap() {
+            Some(exponent + 1)
+        } else {
+            Some(exponent)
+        }
+    }
+
+    /// The distance from the first tick to the tick at `index`, as an exact multiple of the step.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn offset(&self, index: usize) -> T {
+        let step = match self.step {
+            Some(step) => step,
+            None => return T::zero(),
+        };
+        let offset = multiple(step, T::from_usize(index).unwrap());
+        if self.reverse {
+            -offset
+        } else {
+            offset
+        }
+    }
+}
+
+/// Splits an increment, as returned by [`tick_increment`], into the `(mantissa, exponent)` of the
+/// step it represents.
+fn decimal_step<T: Float + FromPrimitive>(increment: T) -> (T, i32) {
+    if increment.is_sign_positive() {
+        let exponent = increment.log10().round().to_i32().unwrap();
+        let exponent = if pow10::<T>(exponent) > increment {
+            exponent - 1
+        } else {
+            exponent
+        };
+        ((increment / pow10(exponent)).round(), exponent)
     } else {
-        (start, stop)
-    };
+        // A negative increment is the inverse of a step of `mantissa / 10^digits`.
+        let inverse = -increment;
+        let digits = inverse.log10().ceil().to_i32().unwrap();
+        ((pow10::<T>(digits) / inverse).round(), -digits)
+    }
+}
 
-    let step = tick_increment(start, stop, count);
-    if step.is_zero() || !step.is_finite() {
-        return vec![];
+/// The step for `count` intervals over the ascending domain `[start, stop]` as the
+/// `(mantissa, exponent)` of the element of `multiples`, times a power of ten, nearest the exact
+/// step on a log scale. Multiples that are not finite and positive are ignored, and `None` is
+/// returned if there are none or the domain is a point.
+fn ladder_step<T: Float + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+    multiples: &[T],
+) -> Option<(T, i32)> {
+    let exact = (stop - start) / T::from_usize(count)?;
+    if exact <= T::zero() || !exact.is_finite() {
+        return None;
     }
+    let power = exact.log10().floor().to_i32()?;
+    let error = (exact / pow10(power)).ln();
 
-    let mut ticks = if step.is_sign_positive() {
-        let start: T = (start / step).ceil();
-        let stop: T = (stop / step).floor();
-        let n = (stop - start + T::from_f64(1.0).unwrap())
-            .ceil()
-            .to_usize()
-            .unwrap();
-        let mut ticks = vec![T::from_f64(0.0).unwrap(); n];
-        for i in 0..n {
-            ticks[i] = (start + T::from_usize(i).unwrap()) * step;
+    // Try each multiple against the neighbouring decades too, so that e.g. 1 can round 9 up.
+    let (mut mantissa, mut exponent) = multiples
+        .iter()
+        .filter(|m| m.is_finite() && **m > T::zero())
+        .flat_map(|&m| (-1..=1).map(move |shift| (m, power + shift)))
+        .min_by(|&(a, a_exponent), &(b, b_exponent)| {
+            let distance =
+                |m: T, exponent: i32| (m.ln() + pow10::<T>(exponent - power).ln() - error).abs();
+            distance(a, a_exponent)
+                .partial_cmp(&distance(b, b_exponent))
+                .unwrap()
+        })?;
+
+    // An integer mantissa keeps `multiple` exact, so move any fraction into the exponent.
+    let ten = T::from_f64(10.0).unwrap();
+    for _ in 0..exact_powers_of_ten::<T>() {
+        if mantissa.fract().is_zero() {
+            break;
+        }
+        mantissa = mantissa * ten;
+        exponent -= 1;
+    }
+
+    Some((mantissa, exponent))
+}
+
+/// `k` times `step`, computed as an integer times a power of ten so that the result is the
+/// representable value nearest the exact decimal tick. In particular `0.3` comes out as `0.3`
+/// rather than `0.30000000000000004`, and powers of ten, such as the ends of a `(0, 1000)` domain,
+/// come out exact.
+fn multiple<T: Float + FromPrimitive>((mantissa, exponent): (T, i32), k: T) -> T {
+    let ten = T::from_f64(10.0).unwrap();
+
+    let mut digits = k * mantissa;
+    let mut exponent = exponent;
+    while !digits.is_zero() && (digits / ten).fract().is_zero() {
+        digits = digits / ten;
+        exponent += 1;
+    }
+
+    // Dividing or multiplying by an exactly representable power of ten rounds correctly, but
+    // beyond that, such as past 10^22 for `f64`, only parsing the decimal does.
+    let exact_powers = exact_powers_of_ten::<T>();
+    if exponent < 0 && -exponent <= exact_powers {
+        digits / pow10(-exponent)
+    } else if (0..=exact_powers).contains(&exponent) {
+        digits * pow10(exponent)
+    } else {
+        parse_decimal(digits, exponent).unwrap_or_else(|| digits * pow10(exponent))
+    }
+}
+
+/// The representable value nearest to `digits * 10^exponent` for an integer `digits`, or `None`
+/// if `digits` is too large to format exactly.
+fn parse_decimal<T: Float + FromPrimitive>(digits: T, exponent: i32) -> Option<T> {
+    let mut decimal = StackString::default();
+    write!(decimal, "{}e{}", digits.to_i64()?, exponent).ok()?;
+    T::from_str_radix(decimal.as_str(), 10).ok()
+}
+
+/// The largest power of ten that `T` represents exactly, e.g. 22 for `f64`.
+fn exact_powers_of_ten<T: Float + FromPrimitive>() -> i32 {
+    let mantissa_bits = T::one() - T::epsilon().log2();
+    let (two, five) = (T::from_f64(2.0).unwrap(), T::from_f64(5.0).unwrap());
+    (mantissa_bits * two.ln() / five.ln())
+        .floor()
+        .to_i32()
+        .unwrap()
+}
+
+/// The representable value nearest to `10^exponent`.
+fn pow10<T: Float + FromPrimitive>(exponent: i32) -> T {
+    let ten = T::from_f64(10.0).unwrap();
+    if exponent.abs() <= exact_powers_of_ten::<T>() {
+        // Dividing by an exact power is correctly rounded, where `libm` may not round `powi` so.
+        if exponent < 0 {
+            T::one() / ten.powi(-exponent)
+        } else {
+            ten.powi(exponent)
         }
-        ticks
+    } else {
+        // `powf` is not correctly rounded for every power, but parsing is.
+        let mut digits = StackString::default();
+        write!(digits, "1e{}", exponent).unwrap();
+        T::from_str_radix(digits.as_str(), 10).unwrap_or_else(|_| ten.powi(exponent))
+    }
+}
+
+/// A string short enough to format on the stack, such as an exponent for [`pow10`] or a decimal
+/// for [`parse_decimal`].
+#[derive(Default)]
+struct StackString {
+    bytes: [u8; 32],
+    len: usize,
+}
+
+impl StackString {
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+impl fmt::Write for StackString {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// The first tick index and the number of ticks for `step`, as returned by [`tick_increment`],
+/// over the ascending domain `[start, stop]`.
+fn tick_bounds<T: Float + FromPrimitive>(start: T, stop: T, step: T) -> (T, usize) {
+    let (first, last) = if step.is_sign_positive() {
+        (snap(start / step).ceil(), snap(stop / step).floor())
     } else {
         let step = step * T::from_f64(-1.0).unwrap();
-        let start = (start * step).floor();
-        let stop = (stop * step).ceil();
-        let n = (stop - start + T::from_f64(1.0).unwrap())
-            .ceil()
-            .to_usize()
-            .unwrap();
-        let mut ticks = vec![T::from_f64(0.0).unwrap(); n];
-        for i in 0..n {
-            ticks[i] = (start + T::from_usize(i).unwrap()) / step;
+        (snap(start * step).floor(), snap(stop * step).ceil())
+    };
+    let n = (last - first + T::from_f64(1.0).unwrap())
+        .ceil()
+        .max(T::zero())
+        .to_usize()
+        .unwrap_or(usize::MAX);
+
+    (first, n)
+}
+
+/// Rounds a tick index that is within floating point error of an integer, so domains ending
+/// exactly on a tick don't gain or lose a tick to rounding.
+fn snap<T: Float + FromPrimitive>(index: T) -> T {
+    let rounded = index.round();
+    let tolerance = T::epsilon() * T::from_f64(4.0).unwrap() * index.abs();
+    if (index - rounded).abs() <= tolerance {
+        rounded
+    } else {
+        index
+    }
+}
+
+/// Like [`ticks`] but takes a signed `count`, as passed by some FFI callers.
+///
+/// A `count` of zero or less returns an empty `Vec` rather than being cast to a huge `usize`.
+#[cfg(feature = "alloc")]
+pub fn ticks_signed<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: i64,
+) -> Vec<T> {
+    if count <= 0 {
+        return vec![];
+    }
+
+    ticks(start, stop, usize::try_from(count).unwrap_or(usize::MAX))
+}
+
+/// Like [`ticks`] but takes a fractional `count`, such as `width / spacing`.
+///
+/// `count` is rounded to the nearest integer, with a minimum of 1. A `count` that is NaN, zero or
+/// negative returns an empty `Vec`.
+#[cfg(feature = "alloc")]
+pub fn ticks_float_count<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: f64,
+) -> Vec<T> {
+    if count.is_nan() || count <= 0.0 {
+        return vec![];
+    }
+
+    ticks(start, stop, count.round().max(1.0) as usize)
+}
+
+/// Like [`ticks`] but chooses the step from `multiples` of powers of ten instead of d3's 1, 2
+/// and 5, such as `[1.0, 2.5, 5.0]` for metric rulers or `[1.0, 3.0]` for a coarser ladder.
+///
+/// The step is the multiple, times a power of ten, nearest `(stop - start) / count` on a log
+/// scale, so `[1.0, 2.0, 5.0]` gives the same ticks as [`ticks`]. Multiples that are not finite
+/// and positive are ignored and an empty `Vec` is returned if none remain.
+///
+/// ```
+/// use axis_ticks::ticks_with_multiples;
+///
+/// assert_eq!(
+///     ticks_with_multiples(0.0, 10.0, 4, &[1.0, 2.5, 5.0]),
+///     [0.0, 2.5, 5.0, 7.5, 10.0]
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn ticks_with_multiples<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+    multiples: &[T],
+) -> Vec<T> {
+    let range = TickRange::with_multiples(start, stop, count, MAX_TICKS, Some(multiples));
+    (0..range.len()).map(|i| range.get(i)).collect()
+}
+
+/// Like [`ticks`] but prefers a step that is a power of a thousand, such as 1k or 1M, over a 2 or
+/// 5 step a rung of the 1, 2, 5 ladder away, so that the ticks fall on whole multiples of the SI
+/// prefixes of [`TickNotation::Engineering`] labels.
+///
+/// The step is chosen from those of [`ticks`] for counts between half and twice `count`, taking
+/// the one for the count nearest `count`, and is otherwise the step of [`ticks`].
+///
+/// ```
+/// use axis_ticks::{engineering_ticks, ticks};
+///
+/// assert_eq!(ticks(0.0, 4000.0, 10).len(), 9);
+/// assert_eq!(engineering_ticks(0.0, 4000.0, 10), [0.0, 1000.0, 2000.0, 3000.0, 4000.0]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn engineering_ticks<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Vec<T> {
+    let count = count.min(MAX_TICKS);
+    let ten = T::from_f64(10.0).unwrap();
+    let power_of_thousand = |count: usize| {
+        let range = TickRange::new(start, stop, count);
+        match (range.step, range.power()) {
+            (Some((mantissa, _)), Some(power)) => {
+                (mantissa == T::one() || mantissa == ten) && power.rem_euclid(3) == 0
+            }
+            _ => false,
         }
+    };
+
+    // Compare candidate counts by their ratio to `count`, as the ladder is logarithmic.
+    let distance = |candidate: usize| {
+        let (a, b) = (candidate.max(count), candidate.min(count));
+        a as f64 / b as f64
+    };
+    let preferred = (count.div_ceil(2)..=count.saturating_mul(2).min(MAX_TICKS))
+        .filter(|&candidate| candidate > 0 && power_of_thousand(candidate))
+        .min_by(|&a, &b| distance(a).partial_cmp(&distance(b)).unwrap());
+
+    ticks(start, stop, preferred.unwrap_or(count))
+}
+
+/// Like [`ticks`] but aligns the ticks to `anchor`, so they are `anchor + k * step` rather than
+/// `k * step`, e.g. to step from a threshold.
+///
+/// The step is the same as for [`ticks`]. Returns an empty `Vec` if `anchor` is not finite.
+///
+/// ```
+/// use axis_ticks::ticks_anchored;
+///
+/// assert_eq!(ticks_anchored(0.0, 100.0, 5, 37.5), [17.5, 37.5, 57.5, 77.5, 97.5]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn ticks_anchored<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+    anchor: T,
+) -> Vec<T> {
+    ticks(start - anchor, stop - anchor, count)
+        .into_iter()
+        .map(|tick| tick + anchor)
+        .collect()
+}
+
+/// Generates exactly `count + 1` evenly spaced values from `start` to `stop` inclusive, without
+/// rounding the step, e.g. for colorbars where the count matters more than nice values.
+///
+/// The first and last values are exactly `start` and `stop`. `count` is capped at
+/// `MAX_TICKS - 1` so at most [`MAX_TICKS`] values are returned. Returns an empty `Vec` if `count`
+/// is zero or the domain is not finite, as [`ticks`] does.
+///
+/// ```
+/// use axis_ticks::ticks_exact;
+///
+/// assert_eq!(ticks_exact(0.0, 3.0, 4), [0.0, 0.75, 1.5, 2.25, 3.0]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn ticks_exact<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Vec<T> {
+    if count == 0 || !start.is_finite() || !stop.is_finite() {
+        return vec![];
+    }
+    let count = count.min(MAX_TICKS - 1);
+    let n = match T::from_usize(count) {
+        Some(n) => n,
+        None => return vec![],
+    };
+
+    let span = stop - start;
+    let mut values: Vec<T> = (0..count)
+        .map(|i| start + span * T::from_usize(i).unwrap() / n)
+        .collect();
+    values.push(stop);
+    values
+}
+
+/// Like [`ticks`] but returns the domain endpoints `[start, stop]` when `count == 1`, for
+/// bracketing labels, instead of d3's single interior tick.
+#[cfg(feature = "alloc")]
+pub fn ticks_endpoints_when_one<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Vec<T> {
+    if count == 1 && start != stop && start.is_finite() && stop.is_finite() {
+        return vec![start, stop];
+    }
+
+    ticks(start, stop, count)
+}
+
+/// Like [`ticks`] but always includes the exact `start` and `stop`, for axes whose extremes must
+/// be labeled, such as a gauge from 3 to 97.
+///
+/// The interior ticks stay nice. Any tick closer than half a step to an endpoint is dropped so
+/// its label does not crowd the endpoint's. Returns an empty `Vec` if [`ticks`] would.
+///
+/// ```
+/// use axis_ticks::ticks_closed;
+///
+/// assert_eq!(ticks_closed(3.0, 97.0, 5), [3.0, 20.0, 40.0, 60.0, 80.0, 97.0]);
+/// assert_eq!(ticks_closed(8.0, 92.0, 10), [8.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 92.0]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn ticks_closed<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Vec<T> {
+    close_ticks(ticks(start, stop, count), start, stop)
+}
+
+/// Replaces the ticks nearest the endpoints of a domain with the endpoints themselves, as
+/// [`ticks_closed`].
+#[cfg(feature = "alloc")]
+pub(crate) fn close_ticks<T: Float>(ticks: Vec<T>, start: T, stop: T) -> Vec<T> {
+    if ticks.is_empty() {
+        return ticks;
+    }
+    if start == stop {
+        return vec![start];
+    }
+
+    let half_step = match ticks.as_slice() {
+        [first, second, ..] => (*second - *first).abs() / (T::one() + T::one()),
+        _ => T::zero(),
+    };
+    let clear_of = |tick: T, endpoint: T| tick != endpoint && (tick - endpoint).abs() >= half_step;
+
+    let mut closed = Vec::with_capacity(ticks.len() + 2);
+    closed.push(start);
+    closed.extend(
         ticks
+            .into_iter()
+            .filter(|&tick| clear_of(tick, start) && clear_of(tick, stop)),
+    );
+    closed.push(stop);
+    closed
+}
+
+/// Like [`ticks`] but takes the number of labels wanted rather than the number of intervals.
+///
+/// [`ticks`] returns approximately `count + 1` ticks, so this starts from a `count` of
+/// `target_labels - 1` and tries the nearby counts up to `2 * target_labels`, returning whichever
+/// tick set has a length closest to `target_labels`. Ties go to the count nearest
+/// `target_labels - 1`. A `target_labels` of zero returns an empty `Vec`.
+#[cfg(feature = "alloc")]
+pub fn ticks_target_labels<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    target_labels: usize,
+) -> Vec<T> {
+    if target_labels == 0 {
+        return vec![];
+    }
+
+    let preferred = target_labels.saturating_sub(1).max(1);
+    let count = (1..=target_labels.saturating_mul(2).min(MAX_TICKS))
+        .min_by_key(|&count| {
+            let len = TickRange::new(start, stop, count).len();
+            (len.abs_diff(target_labels), count.abs_diff(preferred))
+        })
+        .unwrap_or(preferred);
+
+    ticks(start, stop, count)
+}
+
+/// Like [`ticks`] but constrains the number of ticks to `min_ticks..=max_ticks`, returning the
+/// ticks and the `count` chosen to generate them.
+///
+/// The counts up to `2 * max_ticks` are searched for the tick set whose length is in range and
+/// closest to the middle of it, with ties going to the smaller count. Returns `None` if no count
+/// gives a length in range, such as when `min_ticks > max_ticks` or the domain is a point.
+///
+/// ```
+/// use axis_ticks::ticks_between;
+///
+/// let (ticks, count) = ticks_between(0.0, 1.0, 4, 6).unwrap();
+/// assert_eq!(ticks, [0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+/// assert_eq!(count, 4);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn ticks_between<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    min_ticks: usize,
+    max_ticks: usize,
+) -> Option<(Vec<T>, usize)> {
+    let middle = min_ticks + (max_ticks.saturating_sub(min_ticks)) / 2;
+    let count = (1..=max_ticks.saturating_mul(2).min(MAX_TICKS))
+        .filter(|&count| {
+            let len = TickRange::new(start, stop, count).len();
+            len >= min_ticks && len <= max_ticks
+        })
+        .min_by_key(|&count| {
+            let len = TickRange::new(start, stop, count).len();
+            (len.abs_diff(middle), count)
+        })?;
+
+    Some((ticks(start, stop, count), count))
+}
+
+/// Generates `(position, label)` pairs with positions ascending but labels counting down, as for
+/// a countdown axis.
+///
+/// Positions are the ticks between `start` and `stop` in ascending order and each label is the
+/// position mirrored about the centre of the domain, `start + stop - position`.
+#[cfg(feature = "alloc")]
+pub fn ticks_with_reversed_labels<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Vec<(T, T)> {
+    let (lo, hi) = if stop < start {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+
+    ticks(lo, hi, count)
+        .into_iter()
+        .map(|position| (position, start + stop - position))
+        .collect()
+}
+
+/// Generates ticks as `(first_tick, offsets)`, where each offset is the distance from the first
+/// tick, so renderers can translate an existing grid rather than recompute it.
+///
+/// Offsets are exact multiples of the step, negative if `stop < start`. If there are no ticks the
+/// first tick is NaN.
+#[cfg(feature = "alloc")]
+pub fn tick_offsets<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> (T, Vec<T>) {
+    let range = TickRange::new(start, stop, count);
+    if range.len() == 0 {
+        return (T::nan(), vec![]);
+    }
+
+    (
+        range.get(0),
+        (0..range.len()).map(|i| range.offset(i)).collect(),
+    )
+}
+
+/// The ticks for a domain together with how they were chosen, from [`ticks_spec`].
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TickSpec<T> {
+    /// The ticks, as returned by [`ticks`].
+    pub values: Vec<T>,
+    /// The signed distance between consecutive ticks, or NaN if there is no step because there
+    /// are no ticks or `start == stop`.
+    pub step: T,
+    /// The power of ten of the step, so the step is 1, 2 or 5 times `10^power`. Zero if there is
+    /// no step.
+    pub power: i32,
+    /// The decimal places needed to label the ticks.
+    pub precision: usize,
+}
+
+/// Generates ticks along with the step, its power of ten and the decimal places needed to label
+/// them, so callers don't need to derive them from the ticks.
+#[cfg(feature = "alloc")]
+pub fn ticks_spec<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> TickSpec<T> {
+    let range = TickRange::new(start, stop, count);
+    let power = range.power().unwrap_or(0);
+
+    TickSpec {
+        values: (0..range.len()).map(|i| range.get(i)).collect(),
+        step: range.step().unwrap_or_else(T::nan),
+        power,
+        precision: usize::try_from(-power).unwrap_or(0),
+    }
+}
+
+/// The mantissa of the step chosen for the ticks between `start` and `stop`: 1, 2 or 5.
+///
+/// A step of ten times a power of ten is reported as 1, the mantissa of the next decade. Returns
+/// `None` if no step was chosen because there are no ticks or `start == stop`.
+pub fn tick_mantissa<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Option<u8> {
+    let step = TickRange::new(start, stop, count).step()?.abs();
+    let power = T::from_f64(10.0).unwrap().powf(step.log10().floor());
+    match (step / power).round().to_u8()? {
+        10 => Some(1),
+        mantissa => Some(mantissa),
+    }
+}
+
+/// The index of `value` in [`ticks`] for the same arguments, computed without generating the
+/// ticks.
+///
+/// Returns `None` unless `value` is one of the ticks, allowing a small relative tolerance for
+/// floating point error.
+pub fn tick_index_of<T: Float + FloatConst + FromPrimitive>(
+    value: T,
+    start: T,
+    stop: T,
+    count: usize,
+) -> Option<usize> {
+    let range = TickRange::new(start, stop, count);
+    if range.len() == 0 {
+        return None;
+    }
+
+    let (index, tolerance) = match range.step() {
+        Some(step) => (
+            ((value - range.get(0)) / step).round().to_usize()?,
+            step.abs() * T::epsilon().sqrt(),
+        ),
+        None => (0, T::zero()),
+    };
+    if index < range.len() && (range.get(index) - value).abs() <= tolerance {
+        Some(index)
+    } else {
+        None
+    }
+}
+
+/// Converts an `f64` literal into any [`FromPrimitive`] type to cut down on boilerplate in code
+/// that is generic over the tick type.
+///
+/// ```
+/// use axis_ticks::{lit, ticks};
+/// use num_traits::{Float, FloatConst, FromPrimitive};
+///
+/// fn padded_ticks<T: Float + FloatConst + FromPrimitive>(start: T, stop: T) -> Vec<T> {
+///     let padding: T = lit(0.5);
+///     ticks(start - padding, stop + padding, 10)
+/// }
+///
+/// assert_eq!(padded_ticks(0.5f32, 9.5), [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]);
+/// assert_eq!(padded_ticks(0.5f64, 9.5), [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `x` cannot be represented by `T`.
+pub fn lit<T: FromPrimitive>(x: f64) -> T {
+    T::from_f64(x).unwrap()
+}
+
+/// The signed step between the ticks of [`ticks`], as d3's `tickStep`.
+///
+/// The step is negative if `stop < start`. Unlike [`ticks`], it ignores the [`MAX_TICKS`] limit.
+pub fn tick_step<T: Float + FloatConst + FromPrimitive>(start: T, stop: T, count: usize) -> T {
+    let reverse = stop < start;
+    let increment = if reverse {
+        tick_increment(stop, start, count)
+    } else {
+        tick_increment(start, stop, count)
+    };
+    let step = if increment < T::zero() {
+        T::one() / -increment
+    } else {
+        increment
     };
 
     if reverse {
-        ticks.reverse()
+        -step
+    } else {
+        step
+    }
+}
+
+/// Expands `[start, stop]` outwards so that it starts and ends on a tick, as d3's `nice`.
+///
+/// Widening the domain can change the step, so this repeats until the step settles. A reversed
+/// domain stays reversed. Returns the domain unchanged if no step can be chosen, such as when
+/// `count` is zero or `start == stop`.
+///
+/// ```
+/// assert_eq!(axis_ticks::nice(0.201, 0.996, 10), (0.2, 1.0));
+/// ```
+pub fn nice<T: Float + FloatConst + FromPrimitive>(start: T, stop: T, count: usize) -> (T, T) {
+    let reverse = stop < start;
+    let (mut lo, mut hi) = if reverse {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+
+    let mut previous = None;
+    for _ in 0..10 {
+        let increment = tick_increment(lo, hi, count);
+        if previous == Some(increment) || increment.is_zero() || !increment.is_finite() {
+            break;
+        }
+        if increment > T::zero() {
+            lo = (lo / increment).floor() * increment;
+            hi = (hi / increment).ceil() * increment;
+        } else {
+            lo = (lo * increment).ceil() / increment;
+            hi = (hi * increment).floor() / increment;
+        }
+        previous = Some(increment);
     }
 
-    ticks
+    if reverse {
+        (hi, lo)
+    } else {
+        (lo, hi)
+    }
 }
 
-fn tick_increment<T: Float + FloatConst + FromPrimitive>(start: T, stop: T, count: usize) -> T {
+/// Like [`nice`] but expands the domain to the steps of [`ticks_with_multiples`].
+#[cfg(feature = "alloc")]
+pub(crate) fn nice_with_multiples<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+    multiples: &[T],
+) -> (T, T) {
+    let reverse = stop < start;
+    let (mut lo, mut hi) = if reverse {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+
+    let mut previous = None;
+    for _ in 0..10 {
+        let step = match ladder_step(lo, hi, count, multiples) {
+            Some(step) => step,
+            None => break,
+        };
+        if previous == Some(step) {
+            break;
+        }
+        let size = multiple(step, T::one());
+        lo = multiple(step, snap(lo / size).floor());
+        hi = multiple(step, snap(hi / size).ceil());
+        previous = Some(step);
+    }
+
+    if reverse {
+        (hi, lo)
+    } else {
+        (lo, hi)
+    }
+}
+
+/// The nice step between ticks for an ascending domain, encoded as in d3's `tickIncrement`.
+///
+/// A positive result is the step itself. A negative result `-n` means a step of `1 / n`, which
+/// avoids the rounding error of a fractional step such as `0.1`. The result is not finite if
+/// `count` is zero or `start == stop`. Use [`tick_step`] for the step as a plain value.
+pub fn tick_increment<T: Float + FloatConst + FromPrimitive>(start: T, stop: T, count: usize) -> T {
     let step = (stop - start) / T::from_usize(count).unwrap();
     let power = (step.ln() / T::LN_10()).floor();
     let error = step / T::from_f64(10.0).unwrap().powf(power);
@@ -94,25 +1246,86 @@ fn tick_increment<T: Float + FloatConst + FromPrimitive>(start: T, stop: T, coun
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::*;
 
+    #[test]
+    fn try_ticks_reports_why_there_are_no_ticks() {
+        assert_eq!(
+            try_ticks(0.0, 1.0, 5),
+            Ok(vec![0.0, 0.2, 0.4, 0.6, 0.8, 1.0])
+        );
+        assert_eq!(try_ticks(1.0, 1.0, 5), Ok(vec![1.0]));
+        assert_eq!(try_ticks(f64::NAN, 1.0, 5), Err(TickError::NonFiniteDomain));
+        assert_eq!(
+            try_ticks(0.0, f64::INFINITY, 5),
+            Err(TickError::NonFiniteDomain)
+        );
+        assert_eq!(try_ticks(0.0, 1.0, 0), Err(TickError::ZeroCount));
+        assert_eq!(TickError::ZeroCount.to_string(), "the tick count is zero");
+    }
+
+    #[test]
+    fn ticks_with_limit_falls_back_to_a_coarser_step() {
+        assert_eq!(ticks_with_limit(0.0, 1.0, 10, 20), ticks(0.0, 1.0, 10));
+        assert_eq!(
+            ticks_with_limit(0.0, 1.0, 10, 6),
+            [0.0, 0.2, 0.4, 0.6, 0.8, 1.0]
+        );
+        assert!(ticks_with_limit(0.0, 1.0, usize::MAX, 100).len() <= 100);
+        assert!(ticks_with_limit(0.0, 1.0, 10, 0).is_empty());
+    }
+
+    #[test]
+    fn try_ticks_with_limit_rejects_too_many_ticks() {
+        assert_eq!(
+            try_ticks_with_limit(0.0, 1.0, 10, 11),
+            Ok(ticks(0.0, 1.0, 10))
+        );
+        assert_eq!(
+            try_ticks_with_limit(0.0, 1.0, 10, 10),
+            Err(TickError::TooManyTicks { len: 11 })
+        );
+        assert_eq!(
+            try_ticks_with_limit(0.0, 1e12, 1_000_000_000_000, MAX_TICKS),
+            Err(TickError::TooManyTicks {
+                len: 1_000_000_000_001
+            })
+        );
+        assert_eq!(
+            try_ticks_with_limit(f64::NAN, 1.0, 10, 10),
+            Err(TickError::NonFiniteDomain)
+        );
+        assert_eq!(
+            try_ticks_with_limit(0.0, 1.0, 0, 10),
+            Err(TickError::ZeroCount)
+        );
+    }
+
     #[test]
     fn returns_empty_vec_if_any_argument_is_nan() {
-        assert_eq!(ticks(f32::NAN, 1.0, 1), []);
-        assert_eq!(ticks(0.0, f32::NAN, 1), []);
-        assert_eq!(ticks(f32::NAN, f32::NAN, 1), []);
+        assert!(ticks(f32::NAN, 1.0, 1).is_empty());
+        assert!(ticks(0.0, f32::NAN, 1).is_empty());
+        assert!(ticks(f32::NAN, f32::NAN, 1).is_empty());
     }
 
     #[test]
     fn returns_the_empty_vec_if_start_equal_stop() {
-        assert_eq!(ticks(1.0, 1.0, 0), []);
+        assert!(ticks(1.0, 1.0, 0).is_empty());
+    }
+
+    #[test]
+    fn returns_start_if_start_equal_stop_and_count_is_positive() {
+        assert!(ticks(5.0, 5.0, 0).is_empty());
+        assert_eq!(ticks(5.0, 5.0, 1), [5.0]);
+        assert_eq!(ticks(5.0, 5.0, 2), [5.0]);
+        assert_eq!(ticks(5.0, 5.0, 1000), [5.0]);
     }
 
     #[test]
     fn returns_the_empty_vec_if_count_is_not_positive() {
-        assert_eq!(ticks(0.0, 1.0, 0), []);
+        assert!(ticks(0.0, 1.0, 0).is_empty());
     }
 
     #[test]
@@ -179,6 +1392,463 @@ mod tests {
         assert_eq!(ticks(-10.0, 10.0, 1), [0.0]);
     }
 
+    #[test]
+    fn returns_ticks_for_domains_near_the_limits_of_the_type() {
+        assert_eq!(
+            ticks(-f64::MAX / 2.0, f64::MAX / 2.0, 5),
+            [-5e307, 0.0, 5e307]
+        );
+
+        let extreme = ticks(-f64::MAX, f64::MAX, 5);
+        assert_eq!(extreme.len(), 3);
+        assert_eq!(extreme[1], 0.0);
+        assert!(extreme.iter().all(|t| t.is_finite()));
+
+        let reversed = ticks(f64::MAX, -f64::MAX, 5);
+        assert_eq!(reversed, extreme.into_iter().rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn caps_the_number_of_ticks() {
+        let capped = ticks(0.0, 1.0, usize::MAX);
+        assert!(capped.len() <= MAX_TICKS);
+        assert_eq!(capped[0], 0.0);
+        assert_eq!(capped[capped.len() - 1], 1.0);
+
+        assert!(ticks(0.0, 1e9, MAX_TICKS * 2).len() <= MAX_TICKS);
+        assert!(ticks(1e9, 0.0, MAX_TICKS * 2).len() <= MAX_TICKS);
+        assert_eq!(ticks(0.0, 1.0, MAX_TICKS / 2).len(), MAX_TICKS / 2 + 1);
+    }
+
+    #[test]
+    fn ticks_signed_returns_empty_vec_if_count_is_not_positive() {
+        assert!(ticks_signed(0.0, 1.0, -5).is_empty());
+        assert!(ticks_signed(0.0, 1.0, 0).is_empty());
+        assert!(ticks_signed(1.0, 1.0, -1).is_empty());
+    }
+
+    #[test]
+    fn ticks_signed_matches_ticks_for_positive_count() {
+        assert_eq!(ticks_signed(0.0, 1.0, 10), ticks(0.0, 1.0, 10));
+        assert_eq!(ticks_signed(-10.0, 10.0, 5), ticks(-10.0, 10.0, 5));
+    }
+
+    #[test]
+    fn ticks_float_count_rounds_count_to_nearest_integer() {
+        assert_eq!(ticks_float_count(0.0, 1.0, 9.6), ticks(0.0, 1.0, 10));
+        assert_eq!(ticks_float_count(0.0, 1.0, 2.4), ticks(0.0, 1.0, 2));
+        assert_eq!(ticks_float_count(0.0, 1.0, 0.2), ticks(0.0, 1.0, 1));
+    }
+
+    #[test]
+    fn ticks_float_count_returns_empty_vec_if_count_is_nan_or_not_positive() {
+        assert!(ticks_float_count(0.0, 1.0, f64::NAN).is_empty());
+        assert!(ticks_float_count(0.0, 1.0, 0.0).is_empty());
+        assert!(ticks_float_count(0.0, 1.0, -3.0).is_empty());
+    }
+
+    #[test]
+    fn returns_a_single_interior_tick_for_a_count_of_one() {
+        assert_eq!(ticks(-10.0, 10.0, 1), [0.0]);
+        assert_eq!(ticks_endpoints_when_one(-10.0, 10.0, 1), [-10.0, 10.0]);
+        assert_eq!(ticks_endpoints_when_one(10.0, -10.0, 1), [10.0, -10.0]);
+    }
+
+    #[test]
+    fn returns_a_positive_zero_for_a_count_of_one_about_zero() {
+        for &(start, stop) in &[
+            (-10.0, 10.0),
+            (10.0, -10.0),
+            (-7.0, 3.0),
+            (3.0, -7.0),
+            (-0.5, 0.5),
+        ] {
+            let t = ticks(start, stop, 1);
+            assert_eq!(t, [0.0]);
+            assert!(t[0].is_sign_positive(), "{} {}", start, stop);
+        }
+    }
+
+    #[test]
+    fn orders_ticks_for_a_count_of_one_by_the_domain() {
+        assert_eq!(ticks(0.0, 10.0, 1), [0.0, 10.0]);
+        assert_eq!(ticks(10.0, 0.0, 1), [10.0, 0.0]);
+        assert_eq!(ticks(-10.0, 0.0, 1), [-10.0, 0.0]);
+        assert_eq!(ticks(0.0, -10.0, 1), [0.0, -10.0]);
+        assert!(ticks(0.0, -10.0, 1)[0].is_sign_positive());
+        assert!(ticks(12.0, 48.0, 1).is_empty());
+        assert!(ticks(48.0, 12.0, 1).is_empty());
+    }
+
+    #[test]
+    fn ticks_endpoints_when_one_matches_ticks_otherwise() {
+        assert_eq!(
+            ticks_endpoints_when_one(-10.0, 10.0, 2),
+            ticks(-10.0, 10.0, 2)
+        );
+        assert_eq!(ticks_endpoints_when_one(5.0, 5.0, 1), [5.0]);
+        assert!(ticks_endpoints_when_one(f64::NAN, 1.0, 1).is_empty());
+    }
+
+    #[test]
+    fn ticks_target_labels_returns_approximately_target_labels_ticks() {
+        assert_eq!(ticks_target_labels(0.0, 8.0, 5), [0.0, 2.0, 4.0, 6.0, 8.0]);
+        assert_eq!(ticks(0.0, 30.0, 5).len(), 7);
+        assert_eq!(ticks_target_labels(0.0, 30.0, 5), [0.0, 10.0, 20.0, 30.0]);
+        assert_eq!(ticks_target_labels(0.0, 1.0, 2), [0.0, 1.0]);
+        assert_eq!(ticks_target_labels(0.0, 1.0, 1), [0.0, 1.0]);
+        assert!(ticks_target_labels(0.0, 1.0, 0).is_empty());
+    }
+
+    #[test]
+    fn ticks_with_reversed_labels_counts_labels_down() {
+        assert_eq!(
+            ticks_with_reversed_labels(0.0, 10.0, 5),
+            [
+                (0.0, 10.0),
+                (2.0, 8.0),
+                (4.0, 6.0),
+                (6.0, 4.0),
+                (8.0, 2.0),
+                (10.0, 0.0)
+            ]
+        );
+        assert_eq!(
+            ticks_with_reversed_labels(10.0, 0.0, 5),
+            ticks_with_reversed_labels(0.0, 10.0, 5)
+        );
+    }
+
+    #[test]
+    fn tick_offsets_are_relative_to_the_first_tick() {
+        for &(start, stop, count) in &[(0.0, 1.0, 10), (-0.125, 0.25, 10), (10.0, -10.0, 5)] {
+            let expected = ticks(start, stop, count);
+            let (first, offsets) = tick_offsets(start, stop, count);
+
+            assert_eq!(first, expected[0]);
+            assert_eq!(offsets.len(), expected.len());
+            for (offset, tick) in offsets.iter().zip(&expected) {
+                assert!((first + offset - tick).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn tick_offsets_are_exact_multiples_of_the_step() {
+        let (first, offsets) = tick_offsets(0.0, 1.0, 10);
+
+        assert_eq!(first, 0.0);
+        assert_eq!(
+            offsets,
+            [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0]
+        );
+        assert_eq!(tick_offsets(10.0, 0.0, 2).1, [0.0, -5.0, -10.0]);
+
+        let (first, offsets) = tick_offsets(0.0, 1.0, 0);
+        assert!(first.is_nan());
+        assert!(offsets.is_empty());
+    }
+
+    #[test]
+    fn ticks_with_multiples_uses_the_ladder() {
+        assert_eq!(
+            ticks_with_multiples(0.0, 10.0, 4, &[1.0, 2.5, 5.0]),
+            [0.0, 2.5, 5.0, 7.5, 10.0]
+        );
+        assert_eq!(
+            ticks_with_multiples(0.0, 1.0, 4, &[1.0, 2.5, 5.0]),
+            [0.0, 0.25, 0.5, 0.75, 1.0]
+        );
+        assert_eq!(
+            ticks_with_multiples(0.0, 100.0, 4, &[1.0, 3.0]),
+            [0.0, 30.0, 60.0, 90.0]
+        );
+        assert_eq!(
+            ticks_with_multiples(0.0, 100.0, 12, &[1.0, 3.0]),
+            [0.0, 10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0, 100.0]
+        );
+        assert_eq!(
+            ticks_with_multiples(1.0, 0.0, 4, &[2.5]),
+            [1.0, 0.75, 0.5, 0.25, 0.0]
+        );
+    }
+
+    #[test]
+    fn ticks_with_multiples_matches_ticks_for_the_default_ladder() {
+        for &(start, stop, count) in &[(0.0, 1.0, 10), (-7.3, 19.1, 5), (1e-7, 3e-7, 7)] {
+            assert_eq!(
+                ticks_with_multiples(start, stop, count, &[1.0, 2.0, 5.0]),
+                ticks(start, stop, count)
+            );
+        }
+    }
+
+    #[test]
+    fn ticks_with_multiples_ignores_invalid_multiples() {
+        assert_eq!(
+            ticks_with_multiples(0.0, 10.0, 4, &[-1.0, f64::NAN, 2.5]),
+            [0.0, 2.5, 5.0, 7.5, 10.0]
+        );
+        assert!(ticks_with_multiples(0.0, 1.0, 10, &[0.0]).is_empty());
+        assert!(ticks_with_multiples(0.0, 1.0, 10, &[]).is_empty());
+        assert_eq!(ticks_with_multiples(2.0, 2.0, 10, &[3.0]), [2.0]);
+    }
+
+    #[test]
+    fn engineering_ticks_prefer_powers_of_a_thousand() {
+        assert_eq!(engineering_ticks(0.0, 10000.0, 4), ticks(0.0, 10000.0, 8));
+        assert_eq!(engineering_ticks(0.0, 4e6, 10).len(), 5);
+        assert_eq!(engineering_ticks(-0.004, 0.0, 10).len(), 5);
+        // No power of a thousand is within a rung of the step of 1e5.
+        assert_eq!(engineering_ticks(0.0, 1e6, 10), ticks(0.0, 1e6, 10));
+        assert_eq!(engineering_ticks(0.0, 1000.0, 1), [0.0, 1000.0]);
+        assert!(engineering_ticks(0.0, 1.0, 0).is_empty());
+        assert_eq!(engineering_ticks(3.0, 3.0, 10), [3.0]);
+    }
+
+    #[test]
+    fn ticks_anchored_steps_from_the_anchor() {
+        assert_eq!(
+            ticks_anchored(0.0, 100.0, 5, 37.5),
+            [17.5, 37.5, 57.5, 77.5, 97.5]
+        );
+        assert_eq!(ticks_anchored(10.0, -10.0, 4, 1.0), [6.0, 1.0, -4.0, -9.0]);
+        assert_eq!(ticks_anchored(0.0, 1.0, 10, 0.0), ticks(0.0, 1.0, 10));
+    }
+
+    #[test]
+    fn ticks_anchored_returns_empty_vec_for_a_non_finite_anchor() {
+        assert!(ticks_anchored(0.0, 1.0, 10, f64::NAN).is_empty());
+        assert!(ticks_anchored(0.0, 1.0, 10, f64::INFINITY).is_empty());
+    }
+
+    #[test]
+    fn ticks_exact_returns_count_plus_one_values() {
+        assert_eq!(ticks_exact(0.0, 1.0, 3).len(), 4);
+        assert_eq!(ticks_exact(-0.125, 0.25, 3), [-0.125, 0.0, 0.125, 0.25]);
+        assert_eq!(ticks_exact(10.0, 0.0, 4), [10.0, 7.5, 5.0, 2.5, 0.0]);
+
+        let values = ticks_exact(0.1, 0.7, 7);
+        assert_eq!(values.len(), 8);
+        assert_eq!((values[0], values[7]), (0.1, 0.7));
+    }
+
+    #[test]
+    fn ticks_exact_handles_degenerate_arguments() {
+        assert!(ticks_exact(0.0, 1.0, 0).is_empty());
+        assert!(ticks_exact(f64::NAN, 1.0, 3).is_empty());
+        assert_eq!(ticks_exact(2.0, 2.0, 2), [2.0, 2.0, 2.0]);
+    }
+
+    #[test]
+    fn ticks_exact_caps_the_count() {
+        for &count in &[1_000_000, usize::MAX] {
+            let values = ticks_exact(0.0, 1.0, count);
+            assert_eq!(values.len(), MAX_TICKS);
+            assert_eq!((values[0], values[MAX_TICKS - 1]), (0.0, 1.0));
+        }
+    }
+
+    #[test]
+    fn ticks_between_keeps_the_length_in_range() {
+        for &(min_ticks, max_ticks) in &[(2, 3), (3, 5), (5, 8), (8, 10)] {
+            let (ticks, count) = ticks_between(-0.125, 0.25, min_ticks, max_ticks).unwrap();
+
+            assert!(ticks.len() >= min_ticks && ticks.len() <= max_ticks);
+            assert_eq!(ticks, super::ticks(-0.125, 0.25, count));
+        }
+    }
+
+    #[test]
+    fn ticks_between_prefers_the_middle_of_the_range() {
+        let (ticks, count) = ticks_between(0.0, 100.0, 2, 12).unwrap();
+
+        assert_eq!(ticks, [0.0, 20.0, 40.0, 60.0, 80.0, 100.0]);
+        assert_eq!(count, 4);
+    }
+
+    #[test]
+    fn ticks_between_returns_none_when_nothing_fits() {
+        assert_eq!(ticks_between(0.0, 1.0, 5, 4), None);
+        assert_eq!(ticks_between(1.0, 1.0, 2, 4), None);
+        assert_eq!(ticks_between(f64::NAN, 1.0, 1, 4), None);
+    }
+
+    #[test]
+    fn ticks_closed_includes_the_exact_endpoints() {
+        assert_eq!(
+            ticks_closed(3.0, 97.0, 5),
+            [3.0, 20.0, 40.0, 60.0, 80.0, 97.0]
+        );
+        assert_eq!(ticks_closed(0.0, 1.0, 5), ticks(0.0, 1.0, 5));
+        assert_eq!(
+            ticks_closed(97.0, 3.0, 5),
+            [97.0, 80.0, 60.0, 40.0, 20.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn ticks_closed_drops_ticks_crowding_an_endpoint() {
+        assert_eq!(
+            ticks_closed(8.0, 92.0, 10),
+            [8.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 92.0]
+        );
+        assert_eq!(ticks_closed(12.0, 48.0, 2), [12.0, 48.0]);
+    }
+
+    #[test]
+    fn ticks_closed_handles_degenerate_domains() {
+        assert_eq!(ticks_closed(2.5, 2.5, 10), [2.5]);
+        assert!(ticks_closed(0.0, 1.0, 0).is_empty());
+        assert!(ticks_closed(f64::NAN, 1.0, 10).is_empty());
+    }
+
+    #[test]
+    fn ticks_spec_describes_the_chosen_step() {
+        let spec = ticks_spec(0.0, 1.0, 5);
+        assert_eq!(spec.values, ticks(0.0, 1.0, 5));
+        assert_eq!((spec.step, spec.power, spec.precision), (0.2, -1, 1));
+
+        let spec = ticks_spec(1000.0, 0.0, 1);
+        assert_eq!(spec.values, [1000.0, 0.0]);
+        assert_eq!((spec.step, spec.power, spec.precision), (-1000.0, 3, 0));
+
+        let spec = ticks_spec(0.0, 0.05, 5);
+        assert_eq!((spec.step, spec.power, spec.precision), (0.01, -2, 2));
+    }
+
+    #[test]
+    fn ticks_spec_has_no_step_for_a_point() {
+        let spec = ticks_spec(1.5, 1.5, 10);
+
+        assert_eq!(spec.values, [1.5]);
+        assert!(spec.step.is_nan());
+        assert_eq!((spec.power, spec.precision), (0, 0));
+    }
+
+    #[test]
+    fn tick_mantissa_reports_the_chosen_multiple() {
+        assert_eq!(tick_mantissa(0.0, 1.0, 10), Some(1));
+        assert_eq!(tick_mantissa(0.0, 1.0, 5), Some(2));
+        assert_eq!(tick_mantissa(0.0, 1.0, 2), Some(5));
+        assert_eq!(tick_mantissa(0.0, 100.0, 5), Some(2));
+        assert_eq!(tick_mantissa(10.0, -10.0, 4), Some(5));
+        assert_eq!(tick_mantissa(0.0, 10.0, 1), Some(1));
+        assert_eq!(tick_mantissa(0.0f32, 0.001, 10), Some(1));
+    }
+
+    #[test]
+    fn tick_mantissa_is_none_without_a_step() {
+        assert_eq!(tick_mantissa(0.0, 1.0, 0), None);
+        assert_eq!(tick_mantissa(1.0, 1.0, 10), None);
+        assert_eq!(tick_mantissa(f64::NAN, 1.0, 10), None);
+    }
+
+    #[test]
+    fn returns_exact_endpoints_for_power_of_ten_spans() {
+        for exponent in -300..=300 {
+            let stop: f64 = format!("1e{}", exponent).parse().unwrap();
+            for &count in &[1, 2, 5, 10, 20] {
+                let t = ticks(0.0, stop, count);
+                assert_eq!(t[0], 0.0, "1e{} / {}", exponent, count);
+                assert_eq!(t[t.len() - 1], stop, "1e{} / {}", exponent, count);
+            }
+        }
+
+        for exponent in -30..=30 {
+            let stop: f32 = format!("1e{}", exponent).parse().unwrap();
+            for &count in &[1, 2, 5, 10, 20] {
+                let t = ticks(0.0, stop, count);
+                assert_eq!(t[0], 0.0, "1e{} / {}", exponent, count);
+                assert_eq!(t[t.len() - 1], stop, "1e{} / {}", exponent, count);
+            }
+        }
+
+        assert_eq!(ticks(0.0, 1e-7, 5), [0.0, 2e-8, 4e-8, 6e-8, 8e-8, 1e-7]);
+        assert_eq!(ticks(1e20, 0.0, 2), [1e20, 5e19, 0.0]);
+    }
+
+    #[test]
+    fn ticks_are_the_nearest_values_to_their_decimals() {
+        assert_eq!(
+            ticks(1e-30, 2e-30, 5),
+            [1e-30, 1.2e-30, 1.4e-30, 1.6e-30, 1.8e-30, 2e-30]
+        );
+        assert_eq!(ticks(0.0, 3e25, 3), [0.0, 1e25, 2e25, 3e25]);
+        assert_eq!(ticks(1e-20f32, 2e-20, 2), [1e-20, 1.5e-20, 2e-20]);
+
+        // So formatting a tick shows its decimal without trailing noise.
+        let labels: Vec<String> = ticks(7e-25, 9e-25, 4)
+            .iter()
+            .map(|tick| tick.to_string())
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                "0.0000000000000000000000007",
+                "0.00000000000000000000000075",
+                "0.0000000000000000000000008",
+                "0.00000000000000000000000085",
+                "0.0000000000000000000000009"
+            ]
+        );
+    }
+
+    #[test]
+    fn tick_index_of_finds_grid_ticks() {
+        assert_eq!(tick_index_of(0.3, 0.0, 1.0, 10), Some(3));
+        assert_eq!(tick_index_of(0.0, 0.0, 1.0, 10), Some(0));
+        assert_eq!(tick_index_of(1.0, 0.0, 1.0, 10), Some(10));
+        assert_eq!(tick_index_of(-5.0, 10.0, -10.0, 5), Some(3));
+        assert_eq!(tick_index_of(1.0, 1.0, 1.0, 10), Some(0));
+    }
+
+    #[test]
+    fn tick_index_of_rejects_values_off_the_grid() {
+        assert_eq!(tick_index_of(0.35, 0.0, 1.0, 10), None);
+        assert_eq!(tick_index_of(1.1, 0.0, 1.0, 10), None);
+        assert_eq!(tick_index_of(-0.1, 0.0, 1.0, 10), None);
+        assert_eq!(tick_index_of(f64::NAN, 0.0, 1.0, 10), None);
+        assert_eq!(tick_index_of(0.3, 0.0, 1.0, 0), None);
+    }
+
+    #[test]
+    fn tick_increment_encodes_fractional_steps_as_negative_inverses() {
+        assert_eq!(tick_increment(0.0, 1.0, 10), -10.0);
+        assert_eq!(tick_increment(0.0, 1.0, 5), -5.0);
+        assert_eq!(tick_increment(0.0, 10.0, 10), 1.0);
+        assert_eq!(tick_increment(0.0, 100.0, 5), 20.0);
+        assert_eq!(tick_increment(1.0, 1.0, 10), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn tick_step_returns_the_signed_step() {
+        assert_eq!(tick_step(0.0, 1.0, 10), 0.1);
+        assert_eq!(tick_step(0.0, 1.0, 5), 0.2);
+        assert_eq!(tick_step(1.0, 0.0, 10), -0.1);
+        assert_eq!(tick_step(-10.0, 10.0, 4), 5.0);
+        assert_eq!(tick_step(10.0, -10.0, 4), -5.0);
+        assert_eq!(tick_step(0.0, 1e6, 3), 5e5);
+    }
+
+    #[test]
+    fn nice_expands_the_domain_to_ticks() {
+        assert_eq!(nice(0.201, 0.996, 10), (0.2, 1.0));
+        assert_eq!(nice(1.1, 10.9, 10), (1.0, 11.0));
+        assert_eq!(nice(10.9, 1.1, 10), (11.0, 1.0));
+        assert_eq!(nice(-0.125, 0.25, 10), (-0.15, 0.25));
+        assert_eq!(nice(0.0, 123.4, 5), (0.0, 140.0));
+    }
+
+    #[test]
+    fn nice_returns_the_domain_if_no_step_can_be_chosen() {
+        assert_eq!(nice(1.5, 1.5, 10), (1.5, 1.5));
+        assert_eq!(nice(0.5, 1.5, 0), (0.5, 1.5));
+        let (start, stop) = nice(f64::NAN, 1.0, 10);
+        assert!(start.is_nan());
+        assert_eq!(stop, 1.0);
+    }
+
     #[test]
     fn some_more_complex_tests() {
         assert_eq!(
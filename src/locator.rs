@@ -0,0 +1,417 @@
+use crate::MAX_TICKS;
+use alloc::{vec, vec::Vec};
+use num_traits::float::Float;
+
+/// Which end ticks [`MaxNLocator`] removes, as matplotlib's `prune`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Prune {
+    /// Removes the lowest tick.
+    Lower,
+    /// Removes the highest tick.
+    Upper,
+    /// Removes the lowest and highest ticks.
+    Both,
+}
+
+/// A port of matplotlib's `MaxNLocator`, for tick positions that match a matplotlib plot exactly.
+///
+/// Finds at most `nbins + 1` ticks at a step from `steps` times a power of ten. As in matplotlib,
+/// the ticks may extend one step beyond the view limits, and are always ascending. The
+/// `'round_numbers'` autolimit mode and `nbins='auto'` are not supported.
+///
+/// ```
+/// use axis_ticks::{MaxNLocator, Prune};
+///
+/// let locator = MaxNLocator::new(5).steps(&[1.0, 2.0, 2.5, 5.0, 10.0]);
+/// assert_eq!(locator.tick_values(0.0, 7.3), [0.0, 2.0, 4.0, 6.0, 8.0]);
+/// assert_eq!(locator.prune(Prune::Both).tick_values(0.0, 7.3), [2.0, 4.0, 6.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxNLocator {
+    nbins: usize,
+    steps: Vec<f64>,
+    integer: bool,
+    symmetric: bool,
+    prune: Option<Prune>,
+    min_n_ticks: usize,
+}
+
+impl Default for MaxNLocator {
+    /// A locator with matplotlib's defaults, 10 bins.
+    fn default() -> Self {
+        MaxNLocator::new(10)
+    }
+}
+
+impl MaxNLocator {
+    /// Creates a locator for at most `nbins` intervals between ticks with matplotlib's other
+    /// defaults: steps of 1, 2, 2.5, 5 and 10, no pruning and at least 2 ticks.
+    ///
+    /// `nbins` is capped at `MAX_TICKS - 1`, so that there are never more than [`MAX_TICKS`]
+    /// ticks.
+    pub fn new(nbins: usize) -> Self {
+        MaxNLocator {
+            nbins: nbins.min(MAX_TICKS - 1),
+            steps: vec![1.0, 2.0, 2.5, 5.0, 10.0],
+            integer: false,
+            symmetric: false,
+            prune: None,
+            min_n_ticks: 2,
+        }
+    }
+
+    /// Sets the nice step multiples. As in matplotlib, 1 and 10 are added if missing; multiples
+    /// outside `[1, 10]` are ignored.
+    pub fn steps(mut self, steps: &[f64]) -> Self {
+        let mut steps: Vec<f64> = steps
+            .iter()
+            .copied()
+            .filter(|&step| (1.0..=10.0).contains(&step))
+            .collect();
+        steps.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        steps.dedup();
+        if steps.first() != Some(&1.0) {
+            steps.insert(0, 1.0);
+        }
+        if steps.last() != Some(&10.0) {
+            steps.push(10.0);
+        }
+        self.steps = steps;
+        self
+    }
+
+    /// Only places ticks on integers, as for counts.
+    pub fn integer(mut self, integer: bool) -> Self {
+        self.integer = integer;
+        self
+    }
+
+    /// Makes the view limits symmetric about zero.
+    pub fn symmetric(mut self, symmetric: bool) -> Self {
+        self.symmetric = symmetric;
+        self
+    }
+
+    /// Removes ticks from the ends.
+    pub fn prune(mut self, prune: Prune) -> Self {
+        self.prune = Some(prune);
+        self
+    }
+
+    /// Sets the fewest ticks within the view limits, falling back to smaller steps to reach it.
+    pub fn min_n_ticks(mut self, min_n_ticks: usize) -> Self {
+        self.min_n_ticks = min_n_ticks;
+        self
+    }
+
+    /// The ticks for the view limits `[vmin, vmax]`, as matplotlib's `tick_values`.
+    ///
+    /// Returns an empty `Vec` if `nbins` is zero.
+    pub fn tick_values(&self, vmin: f64, vmax: f64) -> Vec<f64> {
+        if self.nbins == 0 {
+            return vec![];
+        }
+
+        let (vmin, vmax) = if self.symmetric {
+            let vmax = vmin.abs().max(vmax.abs());
+            (-vmax, vmax)
+        } else {
+            (vmin, vmax)
+        };
+        let (vmin, vmax) = nonsingular(vmin, vmax, 1e-13, 1e-14);
+        let mut locs = self.raw_ticks(vmin, vmax);
+
+        let (lower, upper) = match self.prune {
+            None => (0, 0),
+            Some(Prune::Lower) => (1, 0),
+            Some(Prune::Upper) => (0, 1),
+            Some(Prune::Both) => (1, 1),
+        };
+        if locs.len() < lower + upper {
+            return vec![];
+        }
+        locs.truncate(locs.len() - upper);
+        locs.drain(..lower);
+        locs
+    }
+
+    fn raw_ticks(&self, vmin: f64, vmax: f64) -> Vec<f64> {
+        let nbins = self.nbins as f64;
+        let (scale, offset) = scale_range(vmin, vmax, nbins);
+        let (vmin, vmax) = (vmin - offset, vmax - offset);
+
+        // The steps, with the neighbouring decades, at this scale.
+        let last = self.steps.len() - 1;
+        let mut steps: Vec<f64> = self.steps[..last]
+            .iter()
+            .map(|step| 0.1 * step)
+            .chain(self.steps.iter().copied())
+            .chain(self.steps.get(1).map(|step| 10.0 * step))
+            .map(|step| step * scale)
+            .collect();
+        if self.integer {
+            steps.retain(|&step| step < 1.0 || (step - step.round()).abs() < 0.001);
+        }
+
+        let raw_step = (vmax - vmin) / nbins;
+        let istep = steps
+            .iter()
+            .position(|&step| step >= raw_step)
+            .unwrap_or(steps.len() - 1);
+
+        let mut ticks = vec![];
+        for &step in steps[..=istep].iter().rev() {
+            let step =
+                if self.integer && vmax.floor() - vmin.ceil() >= self.min_n_ticks as f64 - 1.0 {
+                    step.max(1.0)
+                } else {
+                    step
+                };
+            let best_vmin = floor_div(vmin, step) * step;
+
+            let edge = EdgeInteger::new(step, offset);
+            let low = edge.le(vmin - best_vmin);
+            let high = edge.ge(vmax - best_vmin);
+            let count = (high - low + 1.0).max(0.0) as usize;
+            // A finer step for `min_n_ticks` may not go over the limit.
+            if count > MAX_TICKS {
+                break;
+            }
+            ticks = (0..count)
+                .map(|i| (low + i as f64) * step + best_vmin)
+                .collect();
+
+            let shown = ticks
+                .iter()
+                .filter(|&&tick| tick >= vmin && tick <= vmax)
+                .count();
+            if shown >= self.min_n_ticks {
+                break;
+            }
+        }
+
+        ticks.into_iter().map(|tick| tick + offset).collect()
+    }
+}
+
+/// Rounds the step to the edges of the view limits, allowing for lost precision when the offset
+/// is large, as matplotlib's `_Edge_integer`.
+struct EdgeInteger {
+    step: f64,
+    offset: f64,
+}
+
+impl EdgeInteger {
+    fn new(step: f64, offset: f64) -> Self {
+        EdgeInteger {
+            step,
+            offset: offset.abs(),
+        }
+    }
+
+    fn close_to(&self, ms: f64, edge: f64) -> bool {
+        let tolerance = if self.offset > 0.0 {
+            let digits = (self.offset / self.step).log10();
+            10f64.powf(digits - 12.0).clamp(1e-10, 0.4999)
+        } else {
+            1e-10
+        };
+        (ms - edge).abs() < tolerance
+    }
+
+    /// The largest `n` with `n * step <= x`.
+    fn le(&self, x: f64) -> f64 {
+        let (d, m) = div_mod(x, self.step);
+        if self.close_to(m / self.step, 1.0) {
+            d + 1.0
+        } else {
+            d
+        }
+    }
+
+    /// The smallest `n` with `n * step >= x`.
+    fn ge(&self, x: f64) -> f64 {
+        let (d, m) = div_mod(x, self.step);
+        if self.close_to(m / self.step, 0.0) {
+            d
+        } else {
+            d + 1.0
+        }
+    }
+}
+
+/// The power of ten scale of the bins and, for limits far from zero, the offset ticks are
+/// computed relative to, as matplotlib's `scale_range`.
+fn scale_range(vmin: f64, vmax: f64, n: f64) -> (f64, f64) {
+    let dv = (vmax - vmin).abs();
+    let meanv = (vmax + vmin) / 2.0;
+    let offset = if meanv.abs() / dv < 100.0 {
+        0.0
+    } else {
+        10f64.powf(meanv.abs().log10().floor()).copysign(meanv)
+    };
+    let scale = 10f64.powf((dv / n).log10().floor());
+    (scale, offset)
+}
+
+/// Expands limits that are too close together to tick, as matplotlib's `nonsingular`.
+fn nonsingular(vmin: f64, vmax: f64, expander: f64, tiny: f64) -> (f64, f64) {
+    if !vmin.is_finite() || !vmax.is_finite() {
+        return (-expander, expander);
+    }
+
+    let (vmin, vmax) = if vmax < vmin {
+        (vmax, vmin)
+    } else {
+        (vmin, vmax)
+    };
+    let max_abs = vmin.abs().max(vmax.abs());
+    if max_abs < (1e6 / tiny) * f64::MIN_POSITIVE {
+        (-expander, expander)
+    } else if vmax - vmin <= max_abs * tiny {
+        if vmax == 0.0 && vmin == 0.0 {
+            (-expander, expander)
+        } else {
+            (vmin - expander * vmin.abs(), vmax + expander * vmax.abs())
+        }
+    } else {
+        (vmin, vmax)
+    }
+}
+
+/// Python's float `divmod`, flooring the quotient and giving the remainder the divisor's sign.
+fn div_mod(x: f64, y: f64) -> (f64, f64) {
+    let mut m = x % y;
+    let mut div = (x - m) / y;
+    if m != 0.0 {
+        if (y < 0.0) != (m < 0.0) {
+            m += y;
+            div -= 1.0;
+        }
+    } else {
+        m = 0.0f64.copysign(y);
+    }
+
+    let floor_div = if div != 0.0 {
+        let floor = Float::floor(div);
+        if div - floor > 0.5 {
+            floor + 1.0
+        } else {
+            floor
+        }
+    } else {
+        0.0f64.copysign(x / y)
+    };
+    (floor_div, m)
+}
+
+/// Python's float `//`.
+fn floor_div(x: f64, y: f64) -> f64 {
+    div_mod(x, y).0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_matplotlib_defaults() {
+        let locator = MaxNLocator::default();
+
+        assert_eq!(
+            locator.tick_values(0.0, 1.0),
+            [
+                0.0,
+                0.1,
+                0.2,
+                0.30000000000000004,
+                0.4,
+                0.5,
+                0.6000000000000001,
+                0.7000000000000001,
+                0.8,
+                0.9,
+                1.0
+            ]
+        );
+        assert_eq!(
+            locator.tick_values(-3.7, 12.9),
+            [-4.0, -2.0, 0.0, 2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0]
+        );
+    }
+
+    #[test]
+    fn uses_custom_steps() {
+        let locator = MaxNLocator::new(4).steps(&[1.0, 3.0]);
+
+        assert_eq!(
+            locator.tick_values(0.0, 100.0),
+            [0.0, 30.0, 60.0, 90.0, 120.0]
+        );
+    }
+
+    #[test]
+    fn prunes_the_ends() {
+        let locator = MaxNLocator::new(5);
+
+        assert_eq!(locator.tick_values(0.0, 7.3), [0.0, 2.0, 4.0, 6.0, 8.0]);
+        assert_eq!(
+            locator.clone().prune(Prune::Lower).tick_values(0.0, 7.3),
+            [2.0, 4.0, 6.0, 8.0]
+        );
+        assert_eq!(
+            locator.clone().prune(Prune::Upper).tick_values(0.0, 7.3),
+            [0.0, 2.0, 4.0, 6.0]
+        );
+        assert_eq!(
+            locator.prune(Prune::Both).tick_values(0.0, 7.3),
+            [2.0, 4.0, 6.0]
+        );
+    }
+
+    #[test]
+    fn places_integer_ticks() {
+        let locator = MaxNLocator::new(10).integer(true);
+
+        assert_eq!(locator.tick_values(0.0, 3.2), [0.0, 1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn makes_the_limits_symmetric() {
+        let locator = MaxNLocator::new(4).symmetric(true);
+
+        assert_eq!(locator.tick_values(-1.0, 3.0), [-4.0, -2.0, 0.0, 2.0, 4.0]);
+    }
+
+    #[test]
+    fn ticks_limits_far_from_zero_relative_to_an_offset() {
+        let ticks = MaxNLocator::new(4).tick_values(1e6 + 1.0, 1e6 + 3.0);
+
+        assert_eq!(
+            ticks,
+            [1e6 + 1.0, 1e6 + 1.5, 1e6 + 2.0, 1e6 + 2.5, 1e6 + 3.0]
+        );
+    }
+
+    #[test]
+    fn handles_degenerate_limits() {
+        assert_eq!(
+            MaxNLocator::new(4).tick_values(0.0, 0.0),
+            [-1e-13, -5e-14, 0.0, 5.0000000000000014e-14, 1e-13]
+        );
+        assert!(MaxNLocator::new(0).tick_values(0.0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn caps_the_number_of_ticks() {
+        assert!(MaxNLocator::new(1_000_000).tick_values(0.0, 1.0).len() <= MAX_TICKS);
+        assert!(MaxNLocator::new(usize::MAX).tick_values(0.0, 1e9).len() <= MAX_TICKS);
+        assert!(
+            MaxNLocator::new(MAX_TICKS)
+                .min_n_ticks(usize::MAX)
+                .tick_values(0.0, 1.0)
+                .len()
+                <= MAX_TICKS
+        );
+    }
+}
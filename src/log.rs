@@ -0,0 +1,635 @@
+use crate::{ticks, MAX_TICKS};
+use alloc::{vec, vec::Vec};
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// Generates ticks for a logarithmic axis with the given `base`, as d3's `scaleLog().ticks()`.
+///
+/// If the domain spans fewer than `count` powers of an integer `base`, every integer multiple of
+/// each power within the domain is a tick, falling back to the linear [`ticks`] when that gives
+/// fewer than `count / 2`. Otherwise the ticks are powers of `base` whose exponents are nicely
+/// rounded. The domain may be entirely negative but must not include zero, and `base` must be
+/// greater than 1; otherwise the result is empty. At most [`MAX_TICKS`] ticks are returned.
+pub fn log_ticks<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+    base: T,
+) -> Vec<T> {
+    let reverse = stop < start;
+    let (lo, hi) = if reverse {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+
+    if !lo.is_finite() || !hi.is_finite() || !base.is_finite() || base <= T::one() {
+        return vec![];
+    }
+
+    let mut ticks = if lo > T::zero() {
+        positive_log_ticks(lo, hi, count, base)
+    } else if hi < T::zero() {
+        // Mirror a negative domain onto the positive one.
+        let mut ticks = positive_log_ticks(-hi, -lo, count, base);
+        ticks.reverse();
+        ticks.iter_mut().for_each(|tick| *tick = -*tick);
+        ticks
+    } else {
+        return vec![];
+    };
+
+    if reverse {
+        ticks.reverse();
+    }
+    ticks
+}
+
+/// Generates ticks for a symmetric log axis, which is linear within `threshold` of zero and
+/// logarithmic beyond it, for domains that cross zero.
+///
+/// The ticks are zero, when it lies in the domain, and the powers of ten from `threshold` outwards
+/// on either side. The same powers are used on both sides, thinned to about `count / 2` per side
+/// when the domain crosses zero. If the domain reaches no power of ten beyond `threshold` the
+/// linear [`ticks`] are returned instead. Returns an empty `Vec` if `threshold` is not positive.
+pub fn symlog_ticks<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+    threshold: T,
+) -> Vec<T> {
+    let reverse = stop < start;
+    let (lo, hi) = if reverse {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+
+    if count == 0
+        || !lo.is_finite()
+        || !hi.is_finite()
+        || !threshold.is_finite()
+        || threshold <= T::zero()
+    {
+        return vec![];
+    }
+
+    let ten = T::from_f64(10.0).unwrap();
+    let extent = lo.abs().max(hi.abs());
+    let first = match floor_log(threshold, ten) {
+        p if pow(ten, p) < threshold => p + 1,
+        p => p,
+    };
+    let last = floor_log(extent, ten);
+    if extent <= threshold || first > last {
+        return ticks(start, stop, count);
+    }
+
+    let sides = if lo < T::zero() && hi > T::zero() {
+        2
+    } else {
+        1
+    };
+    let exponents: Vec<i32> = ticks(
+        T::from_i32(first).unwrap(),
+        T::from_i32(last).unwrap(),
+        (count / sides).max(1),
+    )
+    .into_iter()
+    .filter(|p| p.fract().is_zero())
+    .filter_map(|p| p.to_i32())
+    .collect();
+
+    let negative = exponents.iter().rev().map(|&p| -pow(ten, p));
+    let positive = exponents.iter().map(|&p| pow(ten, p));
+    let mut ticks: Vec<T> = negative
+        .chain(Some(T::zero()))
+        .chain(positive)
+        .filter(|&t| t >= lo && t <= hi)
+        .collect();
+
+    if reverse {
+        ticks.reverse();
+    }
+    ticks
+}
+
+fn positive_log_ticks<T: Float + FloatConst + FromPrimitive>(
+    lo: T,
+    hi: T,
+    count: usize,
+    base: T,
+) -> Vec<T> {
+    let span = (hi.ln() - lo.ln()) / base.ln();
+    let n = T::from_usize(count).unwrap();
+
+    if base.fract().is_zero() && span < n {
+        let multiples: Vec<T> = (floor_log(lo, base)..=floor_log(hi, base))
+            .flat_map(|p| {
+                // Skip straight to the multiples of this power within the domain, as `base` may
+                // be huge.
+                let power = pow(base, p);
+                let first = (lo / power).floor().max(T::one()).to_u64().unwrap_or(1);
+                let last = (hi / power)
+                    .ceil()
+                    .min(base - T::one())
+                    .to_u64()
+                    .unwrap_or(0);
+                (first..=last).map(move |k| mul_pow(T::from_u64(k).unwrap(), base, p))
+            })
+            .filter(|&t| t >= lo && t <= hi)
+            .take(MAX_TICKS)
+            .collect();
+
+        if multiples.len() * 2 < count {
+            ticks(lo, hi, count)
+        } else {
+            multiples
+        }
+    } else {
+        // Only a non-integer base gets here with fewer powers than `count`.
+        let count = if span < n {
+            span.round().to_usize().unwrap_or(1).max(1)
+        } else {
+            count
+        };
+        let (i, j) = (lo.ln() / base.ln(), hi.ln() / base.ln());
+        ticks(i, j, count)
+            .into_iter()
+            .map(|p| match p.to_i32() {
+                Some(whole) if p.fract().is_zero() => pow(base, whole),
+                _ => base.powf(p),
+            })
+            .collect()
+    }
+}
+
+/// Generates ticks for a logit axis over probabilities, as for ROC curves and normal probability
+/// plots, at the decades on either side of one half: 0.001, 0.01, 0.1, 0.5, 0.9, 0.99, 0.999 and
+/// so on, which are symmetric in log-odds.
+///
+/// The decades are thinned to nicely rounded steps of about `count` intervals, always keeping 0.5
+/// when it lies in the domain. A domain reaching fewer than two of them, such as 0.2 to 0.4, gets
+/// the linear [`ticks`] instead. Both bounds must lie strictly between 0 and 1; otherwise the
+/// result is empty.
+///
+/// ```
+/// use axis_ticks::logit_ticks;
+///
+/// assert_eq!(
+///     logit_ticks(0.001, 0.999, 6),
+///     [0.001, 0.01, 0.1, 0.5, 0.9, 0.99, 0.999]
+/// );
+/// assert_eq!(logit_ticks(0.001, 0.999, 3), [0.01, 0.5, 0.99]);
+/// ```
+pub fn logit_ticks<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Vec<T> {
+    let reverse = stop < start;
+    let (lo, hi) = if reverse {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+    if count == 0 || !(lo > T::zero() && hi < T::one()) {
+        return vec![];
+    }
+    if lo == hi {
+        return vec![start];
+    }
+
+    // The decades within the domain in order, from the smallest: 10^-k below one half and
+    // 1 - 10^-k above it.
+    let mut decades = vec![];
+    let mut k = 1;
+    loop {
+        let p = crate::pow10::<T>(-k);
+        if p < lo {
+            break;
+        }
+        if p <= hi {
+            decades.push((-k, p));
+        }
+        k += 1;
+    }
+    decades.reverse();
+    let half = T::from_f64(0.5).unwrap();
+    if lo <= half && half <= hi {
+        decades.push((0, half));
+    }
+    let mut k = 1;
+    loop {
+        let p = T::one() - crate::pow10::<T>(-k);
+        if p > hi || p == T::one() {
+            break;
+        }
+        if p >= lo {
+            decades.push((k, p));
+        }
+        k += 1;
+    }
+
+    let (first, last) = match decades.as_slice() {
+        [(first, _), .., (last, _)] => (*first, *last),
+        _ => return ticks(start, stop, count),
+    };
+    let mut ticks: Vec<T> = ticks(
+        T::from_i32(first).unwrap(),
+        T::from_i32(last).unwrap(),
+        count,
+    )
+    .into_iter()
+    .filter(|index| index.fract().is_zero())
+    .filter_map(|index| decades.get((index.to_i32()? - first) as usize))
+    .map(|&(_, p)| p)
+    .collect();
+
+    if reverse {
+        ticks.reverse();
+    }
+    ticks
+}
+
+/// Generates ticks for a logarithmic axis grouped by decade, so renderers can draw decade
+/// separators or banded backgrounds.
+///
+/// Each group is `(decade, ticks)` where `decade` is the power of `base` starting the decade and
+/// `ticks` are the integer multiples of it within `[start, stop]`. A `stop` that is an exact power
+/// of `base` closes the final group rather than opening a new one. Returns an empty `Vec` unless
+/// the domain is strictly positive and `base > 1`. At most [`MAX_TICKS`] ticks are returned in
+/// total.
+///
+/// `T` is typically `f32` or `f64`; see [`ticks`](crate::ticks) for the bounds.
+pub fn log_ticks_grouped<T: Float + FromPrimitive>(start: T, stop: T, base: T) -> Vec<(T, Vec<T>)> {
+    let reverse = stop < start;
+    let (lo, hi) = if reverse {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+
+    if lo.is_nan() || lo <= T::zero() || !hi.is_finite() || !base.is_finite() || base <= T::one() {
+        return vec![];
+    }
+
+    let first = floor_log(lo, base);
+    let mut last = floor_log(hi, base);
+    if last > first && pow(base, last) == hi {
+        last -= 1;
+    }
+
+    let mut remaining = MAX_TICKS;
+    let mut groups = Vec::new();
+    for p in first..=last {
+        if remaining == 0 {
+            break;
+        }
+
+        let closing = if p == last {
+            Some(pow(base, p + 1))
+        } else {
+            None
+        };
+        // Skip straight to the multiples of this power within the domain, as `base` may be huge.
+        let power = pow(base, p);
+        let first = (lo / power).floor().max(T::one()).to_u64().unwrap_or(1);
+        let last = (hi / power)
+            .ceil()
+            .min((base - T::one()).ceil())
+            .to_u64()
+            .unwrap_or(u64::MAX);
+        let ticks: Vec<T> = (first..=last)
+            .map(|k| T::from_u64(k).unwrap())
+            .take_while(|&k| k < base)
+            .map(|k| mul_pow(k, base, p))
+            .chain(closing)
+            .filter(|&t| t >= lo && t <= hi)
+            .take(remaining)
+            .collect();
+        remaining -= ticks.len();
+        groups.push((power, ticks));
+    }
+
+    if reverse {
+        groups.reverse();
+        for (_, ticks) in groups.iter_mut() {
+            ticks.reverse();
+        }
+    }
+
+    groups
+}
+
+/// The multiples of each power of ten used as minor ticks by [`log_minor_ticks`], densest first.
+const MINOR_MULTIPLES: [&[u8]; 6] = [
+    &[2, 3, 4, 5, 6, 7, 8, 9],
+    &[2, 4, 6, 8],
+    &[2, 3, 5],
+    &[2, 5],
+    &[3],
+    &[],
+];
+
+/// Generates the minor ticks of a base 10 logarithmic axis, the multiples 2 to 9 of each power of
+/// ten, thinned so that they stay at least `min_spacing` pixels apart when a decade spans
+/// `pixels_per_decade` pixels.
+///
+/// As decades get narrower the multiples used are thinned to 2, 4, 6 and 8, then 2, 3 and 5, then
+/// 2 and 5, then 3 alone, and finally none. The spacing includes the gaps to the powers of ten
+/// either side. Returns an empty `Vec` unless the domain is strictly positive. At most
+/// [`MAX_TICKS`] ticks are returned.
+///
+/// ```
+/// use axis_ticks::log_minor_ticks;
+///
+/// assert_eq!(log_minor_ticks(1.0, 100.0, 50.0, 12.0), [2.0, 5.0, 20.0, 50.0]);
+/// ```
+pub fn log_minor_ticks<T: Float + FromPrimitive>(
+    start: T,
+    stop: T,
+    pixels_per_decade: T,
+    min_spacing: T,
+) -> Vec<T> {
+    let reverse = stop < start;
+    let (lo, hi) = if reverse {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+    if lo.is_nan() || lo <= T::zero() || !hi.is_finite() {
+        return vec![];
+    }
+
+    let ten = T::from_f64(10.0).unwrap();
+    let multiples = MINOR_MULTIPLES
+        .iter()
+        .find(|multiples| {
+            let marks = core::iter::once(1)
+                .chain(multiples.iter().copied())
+                .chain(core::iter::once(10));
+            let positions: Vec<T> = marks.map(|k| T::from_u8(k).unwrap().log10()).collect();
+            positions
+                .windows(2)
+                .all(|pair| (pair[1] - pair[0]) * pixels_per_decade >= min_spacing)
+        })
+        .map_or(&[][..], |multiples| *multiples);
+
+    let mut minors: Vec<T> = (floor_log(lo, ten)..=floor_log(hi, ten))
+        .flat_map(|p| {
+            multiples
+                .iter()
+                .map(move |&k| mul_pow(T::from_u8(k).unwrap(), ten, p))
+        })
+        .filter(|&t| t >= lo && t <= hi)
+        .take(MAX_TICKS)
+        .collect();
+    if reverse {
+        minors.reverse();
+    }
+
+    minors
+}
+
+/// The largest `p` for which `base^p <= x`, corrected for rounding in `ln`.
+pub(crate) fn floor_log<T: Float + FromPrimitive>(x: T, base: T) -> i32 {
+    let mut p = (x.ln() / base.ln()).floor().to_i32().unwrap_or(0);
+    while pow(base, p) > x {
+        p -= 1;
+    }
+    while pow(base, p + 1) <= x {
+        p += 1;
+    }
+    p
+}
+
+/// `base^p`, dividing for negative powers to avoid `0.1 * 3`-style rounding.
+pub(crate) fn pow<T: Float + FromPrimitive>(base: T, p: i32) -> T {
+    mul_pow(T::one(), base, p)
+}
+
+fn mul_pow<T: Float + FromPrimitive>(k: T, base: T, p: i32) -> T {
+    let power = T::from_i32(p.abs()).unwrap();
+    if p >= 0 {
+        k * base.powf(power)
+    } else {
+        k / base.powf(power)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_minor_ticks_uses_every_multiple_in_wide_decades() {
+        assert_eq!(
+            log_minor_ticks(1.0, 10.0, 500.0, 10.0),
+            [2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]
+        );
+        assert_eq!(
+            log_minor_ticks(0.15, 3.5, 500.0, 10.0),
+            [0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 2.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn log_minor_ticks_thins_narrow_decades() {
+        let thinned = |pixels_per_decade: f64| log_minor_ticks(1.0, 10.0, pixels_per_decade, 10.0);
+
+        assert_eq!(thinned(150.0), [2.0, 4.0, 6.0, 8.0]);
+        assert_eq!(thinned(60.0), [2.0, 3.0, 5.0]);
+        assert_eq!(thinned(35.0), [2.0, 5.0]);
+        assert_eq!(thinned(25.0), [3.0]);
+        assert!(thinned(15.0).is_empty());
+    }
+
+    #[test]
+    fn log_minor_ticks_follows_the_domain_direction() {
+        assert_eq!(
+            log_minor_ticks(100.0, 1.0, 50.0, 12.0),
+            [50.0, 20.0, 5.0, 2.0]
+        );
+        assert!(log_minor_ticks(-1.0, 10.0, 500.0, 10.0).is_empty());
+        assert!(log_minor_ticks(1.0, f64::INFINITY, 500.0, 10.0).is_empty());
+    }
+
+    #[test]
+    fn log_ticks_returns_every_multiple_for_small_spans() {
+        assert_eq!(
+            log_ticks(1.0, 10.0, 10, 10.0),
+            [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]
+        );
+        assert_eq!(
+            log_ticks(0.5, 5.0, 10, 10.0),
+            [0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 2.0, 3.0, 4.0, 5.0]
+        );
+        assert_eq!(
+            log_ticks(1.0, 32.0, 10, 2.0),
+            [1.0, 2.0, 4.0, 8.0, 16.0, 32.0]
+        );
+    }
+
+    #[test]
+    fn log_ticks_handles_reversed_and_negative_domains() {
+        assert_eq!(
+            log_ticks(-1.0, -10.0, 10, 10.0),
+            [-1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0, -8.0, -9.0, -10.0]
+        );
+        assert_eq!(
+            log_ticks(-10.0, -1.0, 10, 10.0),
+            [-10.0, -9.0, -8.0, -7.0, -6.0, -5.0, -4.0, -3.0, -2.0, -1.0]
+        );
+        assert_eq!(
+            log_ticks(10.0, 1.0, 10, 10.0),
+            [10.0, 9.0, 8.0, 7.0, 6.0, 5.0, 4.0, 3.0, 2.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn log_ticks_returns_powers_for_large_spans() {
+        assert_eq!(
+            log_ticks(1.0, 1e20, 10, 10.0),
+            [1.0, 1e2, 1e4, 1e6, 1e8, 1e10, 1e12, 1e14, 1e16, 1e18, 1e20]
+        );
+        assert_eq!(
+            log_ticks(1e-10, 1e10, 4, 10.0),
+            [1e-10, 1e-5, 1.0, 1e5, 1e10]
+        );
+    }
+
+    #[test]
+    fn log_ticks_falls_back_to_linear_ticks_for_tiny_spans() {
+        assert_eq!(log_ticks(1.0, 1.5, 10, 10.0), ticks(1.0, 1.5, 10));
+    }
+
+    #[test]
+    fn log_ticks_returns_empty_vec_for_domains_including_zero() {
+        assert!(log_ticks(0.0, 10.0, 10, 10.0).is_empty());
+        assert!(log_ticks(-1.0, 10.0, 10, 10.0).is_empty());
+        assert!(log_ticks(1.0, 10.0, 10, 1.0).is_empty());
+        assert!(log_ticks(1.0, 10.0, 0, 10.0).is_empty());
+    }
+
+    #[test]
+    fn symlog_ticks_are_symmetric_powers_around_zero() {
+        assert_eq!(
+            symlog_ticks(-1e6, 1e6, 4, 1.0),
+            [-1e6, -1e4, -1e2, -1.0, 0.0, 1.0, 1e2, 1e4, 1e6]
+        );
+        assert_eq!(
+            symlog_ticks(1e3, -10.0, 10, 1.0),
+            [1e3, 1e2, 10.0, 1.0, 0.0, -1.0, -10.0]
+        );
+        assert_eq!(symlog_ticks(0.0, 1e3, 10, 5.0), [0.0, 10.0, 100.0, 1e3]);
+    }
+
+    #[test]
+    fn symlog_ticks_are_linear_within_the_threshold() {
+        assert_eq!(symlog_ticks(-5.0, 5.0, 10, 10.0), ticks(-5.0, 5.0, 10));
+        assert_eq!(symlog_ticks(-5.0, 5.0, 10, 2.0), ticks(-5.0, 5.0, 10));
+        assert!(symlog_ticks(-5.0, 5.0, 10, 0.0).is_empty());
+        assert!(symlog_ticks(-5.0, 5.0, 0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn groups_ticks_by_decade() {
+        let groups = log_ticks_grouped(1.0, 1000.0, 10.0);
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(
+            groups,
+            [
+                (1.0, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+                (
+                    10.0,
+                    vec![10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0]
+                ),
+                (
+                    100.0,
+                    vec![100.0, 200.0, 300.0, 400.0, 500.0, 600.0, 700.0, 800.0, 900.0, 1000.0]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn groups_partial_decades() {
+        assert_eq!(
+            log_ticks_grouped(0.3, 2.0, 10.0),
+            [
+                (0.1, vec![0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9]),
+                (1.0, vec![1.0, 2.0]),
+            ]
+        );
+    }
+
+    #[test]
+    fn reverses_groups_for_reversed_domain() {
+        assert_eq!(
+            log_ticks_grouped(100.0, 10.0, 10.0),
+            [(
+                10.0,
+                vec![100.0, 90.0, 80.0, 70.0, 60.0, 50.0, 40.0, 30.0, 20.0, 10.0]
+            )]
+        );
+    }
+
+    #[test]
+    fn visits_only_the_multiples_within_the_domain() {
+        assert_eq!(
+            log_ticks_grouped(1.0, 3.0, 1e12),
+            [(1.0, vec![1.0, 2.0, 3.0])]
+        );
+        assert_eq!(
+            log_ticks_grouped(2e12, 4e12, 1e12),
+            [(1e12, vec![2e12, 3e12, 4e12])]
+        );
+    }
+
+    #[test]
+    fn caps_the_total_number_of_ticks() {
+        let groups = log_ticks_grouped(1.0, 1e300, 10.0);
+        let total: usize = groups.iter().map(|(_, ticks)| ticks.len()).sum();
+
+        assert_eq!(groups.len(), 300);
+        assert_eq!(total, 2701);
+
+        let groups = log_ticks_grouped(1.0, 1e10, 1e6);
+        let total: usize = groups.iter().map(|(_, ticks)| ticks.len()).sum();
+
+        assert_eq!(total, MAX_TICKS);
+    }
+
+    #[test]
+    fn returns_empty_vec_for_non_positive_domain_or_base() {
+        assert_eq!(log_ticks_grouped(0.0, 10.0, 10.0), []);
+        assert_eq!(log_ticks_grouped(-1.0, 10.0, 10.0), []);
+        assert_eq!(log_ticks_grouped(1.0, 10.0, 1.0), []);
+        assert_eq!(log_ticks_grouped(f64::NAN, 10.0, 10.0), []);
+    }
+
+    #[test]
+    fn logit_ticks_are_symmetric_in_log_odds() {
+        assert_eq!(logit_ticks(0.05, 0.95, 4), [0.1, 0.5, 0.9]);
+        assert_eq!(logit_ticks(1e-6, 1.0 - 1e-6, 2), [1e-5, 0.5, 1.0 - 1e-5]);
+        assert_eq!(logit_ticks(0.999, 0.001, 6).len(), 7);
+        assert_eq!(logit_ticks(0.999, 0.001, 6)[0], 0.999);
+        assert_eq!(logit_ticks(1e-4, 0.02, 5), [1e-4, 1e-3, 1e-2]);
+        assert_eq!(logit_ticks(0.9f32, 0.9999, 3), [0.9, 0.99, 0.999, 0.9999]);
+    }
+
+    #[test]
+    fn logit_ticks_fall_back_to_linear_ticks() {
+        assert_eq!(logit_ticks(0.2, 0.4, 4), ticks(0.2, 0.4, 4));
+        assert_eq!(logit_ticks(0.3, 0.7, 4), ticks(0.3, 0.7, 4));
+        assert!(logit_ticks(0.0, 0.5, 4).is_empty());
+        assert!(logit_ticks(0.5, 1.0, 4).is_empty());
+        assert!(logit_ticks(f64::NAN, 0.5, 4).is_empty());
+        assert!(logit_ticks(0.1, 0.9, 0).is_empty());
+        assert_eq!(logit_ticks(0.25, 0.25, 4), [0.25]);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::ticks;
+use alloc::vec::Vec;
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// Merges any number of tick sets, such as the linear and log ticks of a broken scale, into one
+/// ascending `Vec` with values within `epsilon` of each other merged.
+///
+/// When ticks are merged the smallest is kept. NaN values are dropped.
+pub fn ticks_union(sets: &[&[f64]], epsilon: f64) -> Vec<f64> {
+    let mut all: Vec<f64> = sets
+        .iter()
+        .flat_map(|set| set.iter().copied())
+        .filter(|tick| !tick.is_nan())
+        .collect();
+    all.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut union: Vec<f64> = Vec::with_capacity(all.len());
+    for tick in all {
+        match union.last() {
+            Some(&last) if tick - last <= epsilon => {}
+            _ => union.push(tick),
+        }
+    }
+
+    union
+}
+
+/// Generates ticks as [`ticks`] with the `pinned` values, such as today's date or a regulatory
+/// limit, always included.
+///
+/// Generated ticks closer than `min_gap` to a pinned value are dropped so their labels do not
+/// collide; a `min_gap` of zero only drops exact duplicates. Pinned values are kept even outside
+/// the domain, NaN values are dropped and the result runs in the direction from `start` to
+/// `stop`.
+pub fn ticks_pinned<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+    pinned: &[T],
+    min_gap: T,
+) -> Vec<T> {
+    let mut merged: Vec<T> = ticks(start, stop, count)
+        .into_iter()
+        .filter(|&tick| {
+            pinned
+                .iter()
+                .all(|&pin| pin.is_nan() || (tick != pin && (tick - pin).abs() >= min_gap))
+        })
+        .chain(pinned.iter().copied().filter(|pin| !pin.is_nan()))
+        .collect();
+    merged.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    merged.dedup();
+    if stop < start {
+        merged.reverse();
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticks_union_merges_overlapping_sets() {
+        let linear = [0.0, 0.5, 1.0];
+        let log = [0.1, 1.0, 10.0];
+        let extra = [1.0000000001, 5.0, 0.5];
+
+        assert_eq!(
+            ticks_union(&[&linear, &log, &extra], 1e-6),
+            [0.0, 0.1, 0.5, 1.0, 5.0, 10.0]
+        );
+    }
+
+    #[test]
+    fn ticks_union_keeps_ticks_further_apart_than_epsilon() {
+        assert_eq!(ticks_union(&[&[0.0, 0.1], &[0.05]], 0.01), [0.0, 0.05, 0.1]);
+        assert_eq!(
+            ticks_union(&[&[3.0, f64::NAN], &[1.0, 2.0]], 0.0),
+            [1.0, 2.0, 3.0]
+        );
+        assert!(ticks_union(&[], 0.0).is_empty());
+    }
+
+    #[test]
+    fn ticks_pinned_merges_pinned_values() {
+        assert_eq!(
+            ticks_pinned(0.0, 100.0, 5, &[75.0, 20.0], 0.0),
+            [0.0, 20.0, 40.0, 60.0, 75.0, 80.0, 100.0]
+        );
+        assert_eq!(
+            ticks_pinned(100.0, 0.0, 5, &[75.0, 120.0], 0.0),
+            [120.0, 100.0, 80.0, 75.0, 60.0, 40.0, 20.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn ticks_pinned_drops_ticks_too_close_to_a_pin() {
+        assert_eq!(
+            ticks_pinned(0.0, 100.0, 5, &[75.0], 10.0),
+            [0.0, 20.0, 40.0, 60.0, 75.0, 100.0]
+        );
+        assert_eq!(
+            ticks_pinned(0.0, 1.0, 5, &[0.5, 0.5, f64::NAN], 0.15),
+            [0.0, 0.2, 0.5, 0.8, 1.0]
+        );
+        assert_eq!(ticks_pinned(0.0, 1.0, 5, &[], 0.1), ticks(0.0, 1.0, 5));
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{multiple, tick_bounds, TickRange, MAX_TICKS};
+use alloc::{vec, vec::Vec};
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// Generates the minor ticks between the major ticks that [`ticks`](crate::ticks) returns for the
+/// same arguments, across the whole domain.
+///
+/// Each major step is divided into `subdivisions` parts, or if `None` a conventional number for
+/// the step: 5 for a step of 1 or 5 and 4 for a step of 2, times a power of ten. `subdivisions`
+/// is reduced so that the majors and minors together number at most [`MAX_TICKS`]. The minor
+/// ticks run in the direction from `start` to `stop` and exclude the majors. Returns an empty
+/// `Vec` if there is no major step or `subdivisions` is less than 2.
+///
+/// ```
+/// use axis_ticks::minor_ticks;
+///
+/// assert_eq!(minor_ticks(0.0, 4.0, 2, None), [0.5, 1.0, 1.5, 2.5, 3.0, 3.5]);
+/// ```
+pub fn minor_ticks<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+    subdivisions: Option<usize>,
+) -> Vec<T> {
+    range_minor_ticks(
+        &TickRange::new(start, stop, count),
+        start,
+        stop,
+        subdivisions,
+    )
+}
+
+/// The minor ticks of [`minor_ticks`] between the major ticks of `range` over `start` to `stop`.
+pub(crate) fn range_minor_ticks<T: Float + FloatConst + FromPrimitive>(
+    range: &TickRange<T>,
+    start: T,
+    stop: T,
+    subdivisions: Option<usize>,
+) -> Vec<T> {
+    let step = match range.step {
+        Some(step) => step,
+        None => return vec![],
+    };
+    let subdivisions = subdivisions
+        .unwrap_or_else(|| default_subdivisions(step))
+        .min(MAX_TICKS / range.len().saturating_add(1));
+    if subdivisions < 2 {
+        return vec![];
+    }
+
+    // Keep the minor step an integer times a power of ten where possible, as the major step is.
+    let parts = T::from_usize(subdivisions).unwrap();
+    let ten = T::from_f64(10.0).unwrap();
+    let (mut digits, mut exponent) = step;
+    for _ in 0..4 {
+        if (digits / parts).fract().is_zero() {
+            break;
+        }
+        digits = digits * ten;
+        exponent -= 1;
+    }
+    let minor = if (digits / parts).fract().is_zero() {
+        (digits / parts, exponent)
+    } else {
+        (multiple(step, T::one()) / parts, 0)
+    };
+
+    let (lo, hi) = if stop < start {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+    let (first, len) = tick_bounds(lo, hi, multiple(minor, T::one()));
+    let max_len = (range.len() + 1) * subdivisions;
+    let mut minors: Vec<T> = (0..len.min(max_len))
+        .map(|i| first + T::from_usize(i).unwrap())
+        .filter(|index| !(*index / parts).fract().is_zero())
+        .map(|index| multiple(minor, index))
+        .collect();
+    if stop < start {
+        minors.reverse();
+    }
+
+    minors
+}
+
+/// The conventional number of parts to divide a major step into: 4 for a step of 2 and 5 for a
+/// step of 1 or 5, times a power of ten.
+pub(crate) fn default_subdivisions<T: Float>((mantissa, _): (T, i32)) -> usize {
+    match mantissa.to_u8() {
+        Some(2) => 4,
+        _ => 5,
+    }
+}
+
+/// Generates minor ticks between an existing, ascending set of major ticks, which need not be
+/// evenly spaced.
+///
+/// Each gap between consecutive majors is divided into `subdivisions` equal parts, inserting
+/// `subdivisions - 1` minor ticks. Only the minor ticks are returned, with `subdivisions` reduced
+/// so that there are at most [`MAX_TICKS`].
+pub fn minor_ticks_between<T: Float>(majors: &[T], subdivisions: usize) -> Vec<T> {
+    let gaps = majors.len().saturating_sub(1);
+    if gaps == 0 {
+        return vec![];
+    }
+    let subdivisions = subdivisions.min(MAX_TICKS / gaps + 1);
+    if subdivisions < 2 {
+        return vec![];
+    }
+
+    let parts = T::from(subdivisions).unwrap();
+    majors
+        .windows(2)
+        .flat_map(|pair| {
+            let (a, b) = (pair[0], pair[1]);
+            (1..subdivisions).map(move |k| a + (b - a) * T::from(k).unwrap() / parts)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minor_ticks_subdivides_by_the_major_mantissa() {
+        assert_eq!(
+            minor_ticks(0.0, 1.0, 5, None),
+            [0.05, 0.1, 0.15, 0.25, 0.3, 0.35, 0.45, 0.5, 0.55, 0.65, 0.7, 0.75, 0.85, 0.9, 0.95]
+        );
+        assert_eq!(
+            minor_ticks(0.0, 1.0, 2, None),
+            [0.1, 0.2, 0.3, 0.4, 0.6, 0.7, 0.8, 0.9]
+        );
+        assert_eq!(
+            minor_ticks(0.0, 20.0, 2, None),
+            [2.0, 4.0, 6.0, 8.0, 12.0, 14.0, 16.0, 18.0]
+        );
+    }
+
+    #[test]
+    fn minor_ticks_cover_the_domain_beyond_the_majors() {
+        assert_eq!(
+            minor_ticks(0.13, 0.61, 2, None),
+            [0.15, 0.25, 0.3, 0.35, 0.45, 0.5, 0.55]
+        );
+        assert_eq!(minor_ticks(4.0, 0.0, 2, Some(2)), [3.0, 1.0]);
+    }
+
+    #[test]
+    fn minor_ticks_takes_custom_subdivisions() {
+        assert_eq!(
+            minor_ticks(0.0, 2.0, 2, Some(4)),
+            [0.25, 0.5, 0.75, 1.25, 1.5, 1.75]
+        );
+
+        let thirds = minor_ticks(0.0, 1.0, 1, Some(3));
+        assert_eq!(thirds.len(), 2);
+        assert!((thirds[0] - 1.0 / 3.0).abs() < 1e-12);
+        assert!((thirds[1] - 2.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn minor_ticks_needs_a_step_and_subdivisions() {
+        assert!(minor_ticks(0.0, 1.0, 5, Some(1)).is_empty());
+        assert!(minor_ticks(1.0, 1.0, 5, None).is_empty());
+        assert!(minor_ticks(0.0, 1.0, 0, None).is_empty());
+    }
+
+    #[test]
+    fn minor_ticks_caps_the_number_of_ticks() {
+        for &count in &[1_000_000, usize::MAX] {
+            let minors = minor_ticks(0.0, 1.0, 5, Some(count));
+            assert!(!minors.is_empty() && minors.len() <= MAX_TICKS);
+            assert!(minor_ticks(0.0, 1.0, count, None).len() <= MAX_TICKS);
+            assert!(minor_ticks(0.0, 1.0, count, Some(count)).len() <= MAX_TICKS);
+        }
+    }
+
+    #[test]
+    fn minor_ticks_between_subdivides_irregular_majors() {
+        let minors = minor_ticks_between(&[0.0, 0.3, 1.0], 3);
+        let expected = [0.1, 0.2, 0.3 + 0.7 / 3.0, 0.3 + 1.4 / 3.0];
+
+        assert_eq!(minors.len(), expected.len());
+        for (minor, expected) in minors.iter().zip(&expected) {
+            assert!((minor - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn minor_ticks_between_needs_two_majors_and_subdivisions() {
+        assert!(minor_ticks_between(&[0.0, 1.0], 1).is_empty());
+        assert!(minor_ticks_between(&[0.0, 1.0], 0).is_empty());
+        assert!(minor_ticks_between(&[0.0], 4).is_empty());
+        assert_eq!(minor_ticks_between(&[0.0f32, 1.0], 2), [0.5]);
+    }
+
+    #[test]
+    fn minor_ticks_between_caps_the_number_of_ticks() {
+        for &count in &[1_000_000, usize::MAX] {
+            assert_eq!(minor_ticks_between(&[0.0, 1.0], count).len(), MAX_TICKS);
+            assert_eq!(
+                minor_ticks_between(&[0.0, 1.0, 2.0], count).len(),
+                MAX_TICKS
+            );
+        }
+    }
+}
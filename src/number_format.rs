@@ -0,0 +1,1137 @@
+use crate::{format::SI_PREFIXES, pow10, tick_step};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{fmt, str::FromStr};
+use num_traits::float::Float;
+
+/// The error returned when a d3-format specifier cannot be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseSpecifierError;
+
+impl fmt::Display for ParseSpecifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid format specifier")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseSpecifierError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Left,
+    Right,
+    Center,
+    AfterSign,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sign {
+    Minus,
+    Plus,
+    Space,
+    Parentheses,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Symbol {
+    None,
+    Currency,
+    Base,
+}
+
+/// A parsed specifier, `[[fill]align][sign][symbol][0][width][,][.precision][~][type]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Specifier {
+    fill: char,
+    align: Align,
+    sign: Sign,
+    symbol: Symbol,
+    zero: bool,
+    width: usize,
+    comma: bool,
+    precision: Option<usize>,
+    trim: bool,
+    kind: Option<char>,
+}
+
+impl FromStr for Specifier {
+    type Err = ParseSpecifierError;
+
+    fn from_str(specifier: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = specifier.chars().collect();
+        let mut rest = &chars[..];
+        let align = |c: char| match c {
+            '<' => Some(Align::Left),
+            '>' => Some(Align::Right),
+            '^' => Some(Align::Center),
+            '=' => Some(Align::AfterSign),
+            _ => None,
+        };
+
+        let mut parsed = Specifier {
+            fill: ' ',
+            align: Align::Right,
+            sign: Sign::Minus,
+            symbol: Symbol::None,
+            zero: false,
+            width: 0,
+            comma: false,
+            precision: None,
+            trim: false,
+            kind: None,
+        };
+
+        if let Some(a) = rest.get(1).and_then(|&c| align(c)) {
+            parsed.fill = rest[0];
+            parsed.align = a;
+            rest = &rest[2..];
+        } else if let Some(a) = rest.first().and_then(|&c| align(c)) {
+            parsed.align = a;
+            rest = &rest[1..];
+        }
+
+        if let Some(sign) = rest.first().and_then(|c| match c {
+            '-' => Some(Sign::Minus),
+            '+' => Some(Sign::Plus),
+            ' ' => Some(Sign::Space),
+            '(' => Some(Sign::Parentheses),
+            _ => None,
+        }) {
+            parsed.sign = sign;
+            rest = &rest[1..];
+        }
+        if let Some(symbol) = rest.first().and_then(|c| match c {
+            '$' => Some(Symbol::Currency),
+            '#' => Some(Symbol::Base),
+            _ => None,
+        }) {
+            parsed.symbol = symbol;
+            rest = &rest[1..];
+        }
+        if rest.first() == Some(&'0') {
+            parsed.zero = true;
+            rest = &rest[1..];
+        }
+        let (width, after) = parse_digits(rest)?;
+        parsed.width = width.unwrap_or(0);
+        rest = after;
+        if rest.first() == Some(&',') {
+            parsed.comma = true;
+            rest = &rest[1..];
+        }
+        if rest.first() == Some(&'.') {
+            let (precision, after) = parse_digits(&rest[1..])?;
+            parsed.precision = Some(precision.ok_or(ParseSpecifierError)?);
+            rest = after;
+        }
+        if rest.first() == Some(&'~') {
+            parsed.trim = true;
+            rest = &rest[1..];
+        }
+        if let Some(&kind) = rest.first() {
+            if !(kind.is_ascii_alphabetic() || kind == '%') {
+                return Err(ParseSpecifierError);
+            }
+            parsed.kind = Some(kind);
+            rest = &rest[1..];
+        }
+
+        if rest.is_empty() {
+            Ok(parsed)
+        } else {
+            Err(ParseSpecifierError)
+        }
+    }
+}
+
+/// The leading decimal number of `chars`, if any, and the characters after it.
+fn parse_digits(chars: &[char]) -> Result<(Option<usize>, &[char]), ParseSpecifierError> {
+    let len = chars.iter().take_while(|c| c.is_ascii_digit()).count();
+    if len == 0 {
+        return Ok((None, chars));
+    }
+
+    let digits: String = chars[..len].iter().collect();
+    let value = digits.parse().map_err(|_| ParseSpecifierError)?;
+    Ok((Some(value), &chars[len..]))
+}
+
+/// The symbols a [`NumberFormat`] writes numbers with, as d3-format's locale definitions.
+///
+/// With the `serde` feature, a locale can be deserialized from the JSON of d3-format's
+/// [locale files](https://github.com/d3/d3-format/tree/main/locale), where any field not given
+/// takes d3's default.
+///
+/// ```
+/// use axis_ticks::FormatLocale;
+///
+/// let format = FormatLocale::de_de().format(",.1f").unwrap();
+/// assert_eq!(format.format(1234.5), "1.234,5");
+///
+/// let format = FormatLocale::de_de().format("$,.1f").unwrap();
+/// assert_eq!(format.format(1234.5), "1.234,5\u{a0}€");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct FormatLocale {
+    /// The decimal point, such as `"."`.
+    #[cfg_attr(feature = "serde", serde(default = "default_decimal"))]
+    pub decimal: String,
+    /// The separator between groups of digits, such as `","`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub thousands: String,
+    /// The sizes of the groups of digits from the right, the last repeating, such as `[3]`. Digits
+    /// are not grouped if this is empty.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub grouping: Vec<usize>,
+    /// The text before and after a currency amount, such as `("$", "")`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub currency: (String, String),
+    /// The ten strings that replace the digits 0 to 9, if any.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub numerals: Option<Vec<String>>,
+    /// The percent sign, such as `"%"`.
+    #[cfg_attr(feature = "serde", serde(default = "default_percent"))]
+    pub percent: String,
+    /// The minus sign, such as `"\u{2212}"`.
+    #[cfg_attr(feature = "serde", serde(default = "default_minus"))]
+    pub minus: String,
+    /// The text for NaN.
+    #[cfg_attr(feature = "serde", serde(default = "default_nan"))]
+    pub nan: String,
+}
+
+#[cfg(feature = "serde")]
+fn default_decimal() -> String {
+    ".".to_string()
+}
+
+fn default_percent() -> String {
+    "%".to_string()
+}
+
+fn default_minus() -> String {
+    "\u{2212}".to_string()
+}
+
+fn default_nan() -> String {
+    "NaN".to_string()
+}
+
+impl FormatLocale {
+    /// d3-format's default locale, `en-US`.
+    pub fn en_us() -> Self {
+        FormatLocale::new(".", ",", ("$", ""))
+    }
+
+    /// The `en-GB` locale, as `en-US` but with pounds.
+    pub fn en_gb() -> Self {
+        FormatLocale::new(".", ",", ("\u{a3}", ""))
+    }
+
+    /// The `de-DE` locale, such as `1.234,5\u{a0}€`.
+    pub fn de_de() -> Self {
+        FormatLocale::new(",", ".", ("", "\u{a0}\u{20ac}"))
+    }
+
+    /// The `fr-FR` locale, such as `1\u{a0}234,5\u{a0}€`.
+    pub fn fr_fr() -> Self {
+        FormatLocale {
+            percent: "\u{202f}%".to_string(),
+            ..FormatLocale::new(",", "\u{a0}", ("", "\u{a0}\u{20ac}"))
+        }
+    }
+
+    fn new(decimal: &str, thousands: &str, currency: (&str, &str)) -> Self {
+        FormatLocale {
+            decimal: decimal.to_string(),
+            thousands: thousands.to_string(),
+            grouping: vec![3],
+            currency: (currency.0.to_string(), currency.1.to_string()),
+            numerals: None,
+            percent: default_percent(),
+            minus: default_minus(),
+            nan: default_nan(),
+        }
+    }
+
+    /// Like [`number_format`] but with the symbols of this locale.
+    pub fn format(&self, specifier: &str) -> Result<NumberFormat, ParseSpecifierError> {
+        Ok(NumberFormat::new(specifier.parse()?, self.clone()))
+    }
+
+    /// Like [`tick_format_specifier`] but with the symbols of this locale.
+    pub fn tick_format(
+        &self,
+        start: f64,
+        stop: f64,
+        count: usize,
+        specifier: &str,
+    ) -> Result<NumberFormat, ParseSpecifierError> {
+        let step = tick_step(start, stop, count);
+        let mut specifier: Specifier = specifier.parse()?;
+        let magnitude = start.abs().max(stop.abs());
+
+        match specifier.kind {
+            Some('s') => {
+                if specifier.precision.is_none() {
+                    specifier.precision = precision_prefix(step, magnitude);
+                }
+                specifier.kind = Some('f');
+                let prefix = exponent(magnitude).unwrap_or(0).div_euclid(3).clamp(-8, 8) * 3;
+                return Ok(NumberFormat {
+                    fixed_prefix: Some(prefix),
+                    ..NumberFormat::new(specifier, self.clone())
+                });
+            }
+            None | Some('e') | Some('g') | Some('p') | Some('r')
+                if specifier.precision.is_none() =>
+            {
+                specifier.precision = precision_round(step, magnitude)
+                    .map(|precision| precision - usize::from(specifier.kind == Some('e')));
+            }
+            Some('f') | Some('%') if specifier.precision.is_none() => {
+                specifier.precision = precision_fixed(step).map(|precision| {
+                    precision.saturating_sub(2 * usize::from(specifier.kind == Some('%')))
+                });
+            }
+            _ => {}
+        }
+
+        Ok(NumberFormat::new(specifier, self.clone()))
+    }
+}
+
+impl Default for FormatLocale {
+    fn default() -> Self {
+        FormatLocale::en_us()
+    }
+}
+
+/// A formatter for numbers from a d3-format specifier, from [`number_format`],
+/// [`tick_format_specifier`] or a [`FormatLocale`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberFormat {
+    specifier: Specifier,
+    kind: char,
+    precision: usize,
+    prefix: String,
+    suffix: String,
+    maybe_suffix: bool,
+    /// The power of ten of an SI prefix every value is scaled by, as d3-format's `formatPrefix`.
+    fixed_prefix: Option<i32>,
+    locale: FormatLocale,
+}
+
+impl NumberFormat {
+    fn new(specifier: Specifier, locale: FormatLocale) -> Self {
+        let mut specifier = specifier;
+        let mut kind = specifier.kind.unwrap_or('g');
+        if specifier.kind == Some('n') {
+            specifier.comma = true;
+            kind = 'g';
+        } else if !specifier
+            .kind
+            .is_some_and(|kind| "%bcdefgoprsXx".contains(kind))
+        {
+            specifier.precision = specifier.precision.or(Some(12));
+            specifier.trim = true;
+            kind = 'g';
+        }
+        if specifier.zero || (specifier.fill == '0' && specifier.align == Align::AfterSign) {
+            specifier.zero = true;
+            specifier.fill = '0';
+            specifier.align = Align::AfterSign;
+        }
+
+        let prefix = match specifier.symbol {
+            Symbol::Currency => locale.currency.0.clone(),
+            Symbol::Base if "boxX".contains(kind) => format!("0{}", kind.to_ascii_lowercase()),
+            _ => String::new(),
+        };
+        let suffix = match specifier.symbol {
+            Symbol::Currency => locale.currency.1.clone(),
+            _ if "%p".contains(kind) => locale.percent.clone(),
+            _ => String::new(),
+        };
+        let precision = match specifier.precision {
+            None => 6,
+            Some(precision) if "gprs".contains(kind) => precision.clamp(1, 21),
+            Some(precision) => precision.min(20),
+        };
+
+        NumberFormat {
+            specifier,
+            kind,
+            precision,
+            prefix,
+            suffix,
+            maybe_suffix: "defgprs%".contains(kind),
+            fixed_prefix: None,
+            locale,
+        }
+    }
+
+    /// Formats `value` as d3-format would.
+    pub fn format(&self, value: f64) -> String {
+        match self.fixed_prefix {
+            Some(exponent) => format!(
+                "{}{}",
+                self.format_number(value * pow10::<f64>(-exponent)),
+                SI_PREFIXES[(exponent / 3 + 8) as usize]
+            ),
+            None => self.format_number(value),
+        }
+    }
+
+    fn format_number(&self, value: f64) -> String {
+        let spec = &self.specifier;
+        let mut value_prefix = self.prefix.clone();
+        let mut value_suffix = self.suffix.clone();
+        let mut body;
+
+        if self.kind == 'c' {
+            value_suffix = js_to_string(value) + &value_suffix;
+            body = String::new();
+        } else {
+            let mut negative = value < 0.0 || (value == 0.0 && value.is_sign_negative());
+            let mut prefix_exponent = 0;
+            body = if value.is_nan() {
+                self.locale.nan.clone()
+            } else {
+                format_type(self.kind, value.abs(), self.precision, &mut prefix_exponent)
+            };
+            if spec.trim {
+                body = format_trim(&body);
+            }
+            if negative && body.parse::<f64>() == Ok(0.0) && spec.sign != Sign::Plus {
+                negative = false;
+            }
+
+            let sign = match (negative, spec.sign) {
+                (true, Sign::Parentheses) => "(",
+                (true, _) => self.locale.minus.as_str(),
+                (false, Sign::Plus) => "+",
+                (false, Sign::Space) => " ",
+                (false, _) => "",
+            };
+            value_prefix = format!("{}{}", sign, value_prefix);
+            let si = if self.kind == 's' {
+                SI_PREFIXES[(prefix_exponent / 3 + 8) as usize]
+            } else {
+                ""
+            };
+            let close = if negative && spec.sign == Sign::Parentheses {
+                ")"
+            } else {
+                ""
+            };
+            value_suffix = format!("{}{}{}", si, value_suffix, close);
+
+            if self.maybe_suffix {
+                if let Some(i) = body.find(|c: char| !c.is_ascii_digit()) {
+                    let tail = if body[i..].starts_with('.') {
+                        format!("{}{}", self.locale.decimal, &body[i + 1..])
+                    } else {
+                        body[i..].to_string()
+                    };
+                    value_suffix = tail + &value_suffix;
+                    body.truncate(i);
+                }
+            }
+        }
+
+        if spec.comma && !spec.zero {
+            body = self.group(&body, usize::MAX);
+        }
+        let length =
+            value_prefix.chars().count() + body.chars().count() + value_suffix.chars().count();
+        let mut padding: String = if length < spec.width {
+            core::iter::repeat_n(spec.fill, spec.width - length).collect()
+        } else {
+            String::new()
+        };
+        if spec.comma && spec.zero {
+            let width = if padding.is_empty() {
+                usize::MAX
+            } else {
+                spec.width.saturating_sub(value_suffix.chars().count())
+            };
+            body = self.group(&(padding + &body), width);
+            padding = String::new();
+        }
+
+        let formatted = match spec.align {
+            Align::Left => format!("{}{}{}{}", value_prefix, body, value_suffix, padding),
+            Align::AfterSign => format!("{}{}{}{}", value_prefix, padding, body, value_suffix),
+            Align::Center => {
+                let half = padding.chars().count() / 2;
+                let split = padding.char_indices().nth(half).map_or(0, |(i, _)| i);
+                format!(
+                    "{}{}{}{}{}",
+                    &padding[..split],
+                    value_prefix,
+                    body,
+                    value_suffix,
+                    &padding[split..]
+                )
+            }
+            Align::Right => format!("{}{}{}{}", padding, value_prefix, body, value_suffix),
+        };
+
+        match &self.locale.numerals {
+            Some(numerals) => formatted
+                .chars()
+                .map(|c| {
+                    c.to_digit(10)
+                        .and_then(|digit| numerals.get(digit as usize).cloned())
+                        .unwrap_or_else(|| c.to_string())
+                })
+                .collect(),
+            None => formatted,
+        }
+    }
+
+    /// Groups the integer digits of `value`, stopping once the result would exceed `width`.
+    fn group(&self, value: &str, width: usize) -> String {
+        let grouping = &self.locale.grouping;
+        if grouping.is_empty() {
+            return value.to_string();
+        }
+
+        let chars: Vec<char> = value.chars().collect();
+        let mut end = chars.len();
+        let mut groups: Vec<String> = Vec::new();
+        let mut j = 0;
+        let mut size = grouping[0];
+        let mut length = 0;
+        while end > 0 && size > 0 {
+            if length + size + 1 > width {
+                size = width.saturating_sub(length).max(1);
+            }
+            let start = end.saturating_sub(size);
+            groups.push(chars[start..end].iter().collect());
+            end = start;
+            length += size + 1;
+            if length > width {
+                break;
+            }
+            j = (j + 1) % grouping.len();
+            size = grouping[j];
+        }
+
+        groups.reverse();
+        groups.join(&self.locale.thousands)
+    }
+}
+
+impl FromStr for NumberFormat {
+    type Err = ParseSpecifierError;
+
+    fn from_str(specifier: &str) -> Result<Self, Self::Err> {
+        number_format(specifier)
+    }
+}
+
+/// Parses a d3-format specifier such as `".2f"`, `"~s"`, `"$,.2f"`, `"%"` or `"e"` into a
+/// formatter that writes numbers exactly as d3's `format(specifier)` does.
+///
+/// The full mini-language `[[fill]align][sign][symbol][0][width][,][.precision][~][type]` is
+/// supported, with d3-format's default `en-US` locale, including its minus sign `−`. Use
+/// [`FormatLocale::format`] for other locales.
+///
+/// ```
+/// use axis_ticks::number_format;
+///
+/// assert_eq!(number_format("$,.2f").unwrap().format(1234.5), "$1,234.50");
+/// assert_eq!(number_format("~s").unwrap().format(1500.0), "1.5k");
+/// assert_eq!(number_format(".0%").unwrap().format(0.123), "12%");
+/// assert!(number_format(".f").is_err());
+/// ```
+pub fn number_format(specifier: &str) -> Result<NumberFormat, ParseSpecifierError> {
+    FormatLocale::default().format(specifier)
+}
+
+/// A port of d3-scale's `tickFormat(start, stop, count, specifier)`: like [`number_format`] but,
+/// if `specifier` has no precision, infers one from the step of the ticks between `start` and
+/// `stop`.
+///
+/// As in d3, an `s` specifier formats every tick with the SI prefix of the larger magnitude of
+/// `start` and `stop`. d3's default specifier is `",f"`.
+///
+/// ```
+/// use axis_ticks::tick_format_specifier;
+///
+/// let format = tick_format_specifier(0.0, 1.0, 10, ",f").unwrap();
+/// assert_eq!(format.format(0.3), "0.3");
+///
+/// let format = tick_format_specifier(0.0, 1e6, 5, "s").unwrap();
+/// assert_eq!(format.format(2e5), "0.2M");
+/// ```
+pub fn tick_format_specifier(
+    start: f64,
+    stop: f64,
+    count: usize,
+    specifier: &str,
+) -> Result<NumberFormat, ParseSpecifierError> {
+    FormatLocale::default().tick_format(start, stop, count, specifier)
+}
+
+/// d3-format's `precisionFixed`, the decimal places for a fixed-point step.
+fn precision_fixed(step: f64) -> Option<usize> {
+    Some((-exponent(step.abs())?).max(0) as usize)
+}
+
+/// d3-format's `precisionPrefix`, the decimal places for a step under the SI prefix of `value`.
+fn precision_prefix(step: f64, value: f64) -> Option<usize> {
+    let prefix = exponent(value)?.div_euclid(3).clamp(-8, 8) * 3;
+    Some((prefix - exponent(step.abs())?).max(0) as usize)
+}
+
+/// d3-format's `precisionRound`, the significant digits for a step up to `max`.
+fn precision_round(step: f64, max: f64) -> Option<usize> {
+    let step = step.abs();
+    let max = max.abs() - step;
+    Some((exponent(max)? - exponent(step)?).max(0) as usize + 1)
+}
+
+/// The exponent of `value` in scientific notation, or `None` if it is not finite.
+fn exponent(value: f64) -> Option<i32> {
+    decimal_parts(value, 0).map(|(_, exponent)| exponent)
+}
+
+fn format_type(kind: char, x: f64, precision: usize, prefix_exponent: &mut i32) -> String {
+    match kind {
+        '%' => to_fixed(x * 100.0, precision),
+        'b' => to_radix(x.round(), 1),
+        'd' => format!("{:.0}", x.round()),
+        'e' => to_exponential(x, precision),
+        'f' => to_fixed(x, precision),
+        'g' => to_precision(x, precision),
+        'o' => to_radix(x.round(), 3),
+        'p' => format_rounded(x * 100.0, precision),
+        'r' => format_rounded(x, precision),
+        's' => format_prefix_auto(x, precision, prefix_exponent),
+        'X' => to_radix(x.round(), 4).to_ascii_uppercase(),
+        'x' => to_radix(x.round(), 4),
+        _ => js_to_string(x),
+    }
+}
+
+/// d3-format's `formatTrim`, which removes insignificant trailing zeros.
+fn format_trim(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let (mut i0, mut i1) = (None, 0);
+    for (i, &b) in bytes.iter().enumerate().skip(1) {
+        match b {
+            b'.' => {
+                i0 = Some(i);
+                i1 = i;
+            }
+            b'0' => {
+                if i0 == Some(0) {
+                    i0 = Some(i);
+                }
+                i1 = i;
+            }
+            b'1'..=b'9' => {
+                if i0.is_some_and(|i0| i0 > 0) {
+                    i0 = Some(0);
+                }
+            }
+            _ => break,
+        }
+    }
+
+    match i0 {
+        Some(i0) if i0 > 0 => format!("{}{}", &s[..i0], &s[i1 + 1..]),
+        _ => s.to_string(),
+    }
+}
+
+/// d3-format's `formatRounded`, `x` rounded to `p` significant digits in fixed-point notation.
+fn format_rounded(x: f64, p: usize) -> String {
+    let (coefficient, exponent) = match decimal_parts(x, p) {
+        Some(parts) => parts,
+        None => return js_to_string(x),
+    };
+    let len = coefficient.len() as i32;
+
+    if exponent < 0 {
+        format!("0.{}{}", zeros(-exponent - 1), coefficient)
+    } else if len > exponent + 1 {
+        let (integer, fraction) = coefficient.split_at(exponent as usize + 1);
+        format!("{}.{}", integer, fraction)
+    } else {
+        format!("{}{}", coefficient, zeros(exponent - len + 1))
+    }
+}
+
+/// d3-format's `formatPrefixAuto`, `x` rounded to `p` significant digits under its own SI
+/// prefix, whose exponent is written to `prefix_exponent`.
+fn format_prefix_auto(x: f64, p: usize, prefix_exponent: &mut i32) -> String {
+    let (coefficient, exponent) = match decimal_parts(x, p) {
+        Some(parts) => parts,
+        None => return js_to_string(x),
+    };
+    *prefix_exponent = exponent.div_euclid(3).clamp(-8, 8) * 3;
+    let i = exponent - *prefix_exponent + 1;
+    let n = coefficient.len() as i32;
+
+    if i == n {
+        coefficient
+    } else if i > n {
+        format!("{}{}", coefficient, zeros(i - n))
+    } else if i > 0 {
+        let (integer, fraction) = coefficient.split_at(i as usize);
+        format!("{}.{}", integer, fraction)
+    } else {
+        // Less than 1y.
+        let digits = decimal_parts(x, (p as i32 + i - 1).max(0) as usize)
+            .map_or(String::new(), |parts| parts.0);
+        format!("0.{}{}", zeros(-i), digits)
+    }
+}
+
+/// d3-format's `formatDecimalParts`: the significant digits of `x` rounded to `p` significant
+/// digits, or as few as identify it if `p` is zero, and its exponent in scientific notation.
+fn decimal_parts(x: f64, p: usize) -> Option<(String, i32)> {
+    if !x.is_finite() {
+        return None;
+    }
+
+    let decimal = if p == 0 {
+        shortest(x.abs())
+    } else {
+        exact(x.abs()).round(p)
+    };
+    Some((decimal.digits_string(), decimal.exponent))
+}
+
+fn zeros(count: i32) -> String {
+    "0".repeat(count.max(0) as usize)
+}
+
+/// A non-negative decimal `0.d1 d2 d3... * 10^(exponent + 1)`, that is with its first digit in the
+/// units place when `exponent` is zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Digits {
+    digits: Vec<u8>,
+    exponent: i32,
+}
+
+impl Digits {
+    /// Rounds to `count` significant digits, rounding halves up as JavaScript does.
+    fn round(&self, count: usize) -> Digits {
+        let mut digits = self.digits.clone();
+        let mut exponent = self.exponent;
+        let round_up = digits.get(count).is_some_and(|&digit| digit >= 5);
+        digits.resize(count, 0);
+
+        if round_up {
+            let mut i = count;
+            loop {
+                if i == 0 {
+                    digits.insert(0, 1);
+                    digits.truncate(count);
+                    exponent += 1;
+                    break;
+                }
+                i -= 1;
+                if digits[i] == 9 {
+                    digits[i] = 0;
+                } else {
+                    digits[i] += 1;
+                    break;
+                }
+            }
+        }
+
+        Digits { digits, exponent }
+    }
+
+    fn digits_string(&self) -> String {
+        self.digits.iter().map(|&d| char::from(b'0' + d)).collect()
+    }
+
+    /// The digits in fixed-point notation with `decimals` decimal places, assuming no digits
+    /// beyond them.
+    fn fixed(&self, decimals: usize) -> String {
+        let digit = |i: i32| -> char {
+            let value = if i >= 0 {
+                self.digits.get(i as usize).copied().unwrap_or(0)
+            } else {
+                0
+            };
+            char::from(b'0' + value)
+        };
+
+        let mut s = String::new();
+        if self.exponent < 0 {
+            s.push('0');
+        } else {
+            s.extend((0..=self.exponent).map(digit));
+        }
+        if decimals > 0 {
+            s.push('.');
+            s.extend((1..=decimals as i32).map(|place| digit(self.exponent + place)));
+        }
+        s
+    }
+
+    /// The digits in JavaScript's exponential notation, e.g. `1.50e+3`.
+    fn exponential(&self) -> String {
+        let digits = self.digits_string();
+        let (first, rest) = digits.split_at(1);
+        let point = if rest.is_empty() { "" } else { "." };
+        let sign = if self.exponent < 0 { '-' } else { '+' };
+        format!("{}{}{}e{}{}", first, point, rest, sign, self.exponent.abs())
+    }
+}
+
+/// The exact decimal value of a non-negative finite `x`.
+fn exact(x: f64) -> Digits {
+    // 767 significant digits are enough for any `f64`.
+    parse_exponential(&format!("{:.800e}", x))
+}
+
+/// The fewest significant digits that identify a non-negative finite `x`.
+fn shortest(x: f64) -> Digits {
+    parse_exponential(&format!("{:e}", x))
+}
+
+/// Parses Rust's `LowerExp` formatting of a non-negative float.
+fn parse_exponential(s: &str) -> Digits {
+    let (mantissa, exponent) = s.split_once('e').unwrap();
+    let mut digits: Vec<u8> = mantissa
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .map(|b| b - b'0')
+        .collect();
+    while digits.len() > 1 && digits.last() == Some(&0) {
+        digits.pop();
+    }
+
+    Digits {
+        digits,
+        exponent: exponent.parse().unwrap(),
+    }
+}
+
+/// JavaScript's `x.toFixed(p)` for a non-negative `x`.
+fn to_fixed(x: f64, p: usize) -> String {
+    if !x.is_finite() || x >= 1e21 {
+        return js_to_string(x);
+    }
+    if x == 0.0 {
+        return Digits {
+            digits: vec![0],
+            exponent: 0,
+        }
+        .fixed(p);
+    }
+
+    let decimal = exact(x);
+    let count = decimal.exponent + 1 + p as i32;
+    if count < 0 {
+        return Digits {
+            digits: vec![0],
+            exponent: 0,
+        }
+        .fixed(p);
+    }
+
+    // Round with a leading zero so that rounding up into a new digit, or from nothing, works.
+    let mut padded = Digits {
+        digits: decimal.digits,
+        exponent: decimal.exponent + 1,
+    };
+    padded.digits.insert(0, 0);
+    let mut rounded = padded.round(count as usize + 1);
+    if rounded.digits.len() > 1 && rounded.digits[0] == 0 {
+        rounded.digits.remove(0);
+        rounded.exponent -= 1;
+    }
+    rounded.fixed(p)
+}
+
+/// JavaScript's `x.toExponential(p)` for a non-negative `x`.
+fn to_exponential(x: f64, p: usize) -> String {
+    if !x.is_finite() {
+        return js_to_string(x);
+    }
+    if x == 0.0 {
+        return Digits {
+            digits: vec![0; p + 1],
+            exponent: 0,
+        }
+        .exponential();
+    }
+    exact(x).round(p + 1).exponential()
+}
+
+/// JavaScript's `x.toPrecision(p)` for a non-negative `x`.
+fn to_precision(x: f64, p: usize) -> String {
+    if !x.is_finite() {
+        return js_to_string(x);
+    }
+
+    let rounded = if x == 0.0 {
+        Digits {
+            digits: vec![0; p],
+            exponent: 0,
+        }
+    } else {
+        exact(x).round(p)
+    };
+    if rounded.exponent < -6 || rounded.exponent >= p as i32 {
+        rounded.exponential()
+    } else {
+        rounded.fixed((p as i32 - 1 - rounded.exponent) as usize)
+    }
+}
+
+/// JavaScript's `String(x)`.
+fn js_to_string(x: f64) -> String {
+    if x.is_nan() {
+        return "NaN".to_string();
+    }
+    if x.is_infinite() {
+        return if x > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+    }
+    if x == 0.0 {
+        return "0".to_string();
+    }
+
+    let sign = if x < 0.0 { "-" } else { "" };
+    let decimal = shortest(x.abs());
+    let digits = decimal.digits_string();
+    let (k, n) = (digits.len() as i32, decimal.exponent + 1);
+    let body = if k <= n && n <= 21 {
+        format!("{}{}", digits, zeros(n - k))
+    } else if 0 < n && n <= 21 {
+        let (integer, fraction) = digits.split_at(n as usize);
+        format!("{}.{}", integer, fraction)
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{}", zeros(-n), digits)
+    } else {
+        decimal.exponential()
+    };
+    format!("{}{}", sign, body)
+}
+
+/// JavaScript's `x.toString(2^bits)` for a non-negative integer `x`.
+fn to_radix(x: f64, bits: usize) -> String {
+    if !x.is_finite() {
+        return js_to_string(x);
+    }
+
+    let (mantissa, exponent, _) = x.integer_decode();
+    let binary = if exponent >= 0 {
+        format!("{:b}{}", mantissa, "0".repeat(exponent as usize))
+    } else {
+        format!("{:b}", mantissa >> (-exponent).min(63))
+    };
+
+    // Group the bits into digits from the right.
+    let bytes = binary.as_bytes();
+    let mut digits: Vec<char> = bytes
+        .rchunks(bits)
+        .map(|chunk| {
+            let value = chunk
+                .iter()
+                .fold(0, |value, &b| value * 2 + u32::from(b - b'0'));
+            core::char::from_digit(value, 1 << bits).unwrap()
+        })
+        .collect();
+    digits.reverse();
+    let digits: String = digits.into_iter().collect();
+    match digits.trim_start_matches('0') {
+        "" => "0".to_string(),
+        trimmed => trimmed.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(specifier: &str, value: f64) -> String {
+        number_format(specifier).unwrap().format(value)
+    }
+
+    #[test]
+    fn parses_the_specifier_mini_language() {
+        for specifier in &[
+            "",
+            ".2f",
+            "~s",
+            "$,.2f",
+            "%",
+            "e",
+            "*^+#012,.3~g",
+            "<<",
+            "(10d",
+        ] {
+            assert!(number_format(specifier).is_ok(), "{}", specifier);
+        }
+        for specifier in &[".f", "f2", "2.", "$$", "(+d", "1.2.3"] {
+            assert_eq!(
+                number_format(specifier),
+                Err(ParseSpecifierError),
+                "{}",
+                specifier
+            );
+        }
+    }
+
+    #[test]
+    fn formats_fixed_point() {
+        assert_eq!(f(".2f", 1.0), "1.00");
+        assert_eq!(f(".1f", 0.45), "0.5");
+        assert_eq!(f(".0f", 2.5), "3");
+        assert_eq!(f(".1f", 0.05), "0.1");
+        assert_eq!(f(",.2f", 1234567.891), "1,234,567.89");
+        assert_eq!(f("$,.2f", -1234.5), "\u{2212}$1,234.50");
+        assert_eq!(f("f", 0.000001), "0.000001");
+        assert_eq!(f(".2f", -0.001), "0.00");
+        assert_eq!(f("+.2f", -0.001), "\u{2212}0.00");
+    }
+
+    #[test]
+    fn formats_exponent_general_and_rounded() {
+        assert_eq!(f("e", 123.0), "1.230000e+2");
+        assert_eq!(f(".1e", 0.000123), "1.2e-4");
+        assert_eq!(f(".3g", 1234.0), "1.23e+3");
+        assert_eq!(f(".3g", 0.0001234), "0.000123");
+        assert_eq!(f(".2r", 1234.0), "1200");
+        assert_eq!(f(".3r", 0.012345), "0.0123");
+        assert_eq!(f("", 0.1 + 0.2), "0.3");
+        assert_eq!(f("", 1e21), "1e+21");
+        assert_eq!(f("~g", 1.5), "1.5");
+    }
+
+    #[test]
+    fn formats_si_prefixes_and_percentages() {
+        assert_eq!(f("s", 1500.0), "1.50000k");
+        assert_eq!(f("~s", 1500.0), "1.5k");
+        assert_eq!(f(".3s", 0.000042), "42.0\u{b5}");
+        assert_eq!(f("~s", 0.0), "0");
+        assert_eq!(f("%", 0.123), "12.300000%");
+        assert_eq!(f(".0%", 0.125), "13%");
+        assert_eq!(f(".2p", 0.1234), "12%");
+    }
+
+    #[test]
+    fn formats_integers_in_other_bases() {
+        assert_eq!(f("d", 42.4), "42");
+        assert_eq!(f("b", 5.0), "101");
+        assert_eq!(f("#o", 8.0), "0o10");
+        assert_eq!(f("#x", 255.0), "0xff");
+        assert_eq!(f("X", 48879.0), "BEEF");
+        assert_eq!(f("x", 1e30), "c9f2c9cd04675000000000000");
+        assert_eq!(f("c", -1.5), "-1.5");
+    }
+
+    #[test]
+    fn pads_and_aligns() {
+        assert_eq!(f("8d", 42.0), "      42");
+        assert_eq!(f("<8d", 42.0), "42      ");
+        assert_eq!(f("^8d", 42.0), "   42   ");
+        assert_eq!(f("*^9d", 42.0), "***42****");
+        assert_eq!(f("=+8d", 42.0), "+     42");
+        assert_eq!(f("08d", -42.0), "\u{2212}0000042");
+        assert_eq!(f("010,d", 1234.0), "00,001,234");
+        assert_eq!(f("(.1f", -1.5), "(1.5)");
+        assert_eq!(f(" d", 1.0), " 1");
+    }
+
+    #[test]
+    fn tick_format_infers_the_precision() {
+        let format = |start, stop, count, specifier| {
+            tick_format_specifier(start, stop, count, specifier).unwrap()
+        };
+
+        assert_eq!(format(0.0, 1.0, 10, ",f").format(0.3), "0.3");
+        assert_eq!(format(0.0, 1.0, 10, "").format(0.3), "0.3");
+        assert_eq!(format(0.0, 1.0, 10, "%").format(0.3), "30%");
+        assert_eq!(format(0.0, 1.0, 10, "e").format(0.3), "3e-1");
+        assert_eq!(format(0.0, 1e6, 5, "s").format(2e5), "0.2M");
+        assert_eq!(format(0.0, 1e6, 5, "s").format(1e6), "1.0M");
+        assert_eq!(format(0.0, 1.0, 10, ".3f").format(0.3), "0.300");
+        assert_eq!(format(0.0, 10000.0, 4, ",f").format(5000.0), "5,000");
+    }
+
+    #[test]
+    fn formats_with_a_locale() {
+        let de = FormatLocale::de_de();
+
+        assert_eq!(de.format(",.1f").unwrap().format(1234.5), "1.234,5");
+        assert_eq!(
+            de.format("$,.2f").unwrap().format(-1234.5),
+            "\u{2212}1.234,50\u{a0}\u{20ac}"
+        );
+        assert_eq!(
+            FormatLocale::fr_fr().format(".0%").unwrap().format(0.5),
+            "50\u{202f}%"
+        );
+        assert_eq!(
+            FormatLocale::en_gb().format("$,.0f").unwrap().format(1e6),
+            "\u{a3}1,000,000"
+        );
+        assert_eq!(
+            de.tick_format(0.0, 1.0, 10, ",f").unwrap().format(0.3),
+            "0,3"
+        );
+    }
+
+    #[test]
+    fn formats_with_custom_grouping_and_numerals() {
+        let locale = FormatLocale {
+            grouping: vec![3, 2],
+            numerals: Some(
+                "\u{660} \u{661} \u{662} \u{663} \u{664} \u{665} \u{666} \u{667} \u{668} \u{669}"
+                    .split(' ')
+                    .map(String::from)
+                    .collect(),
+            ),
+            ..FormatLocale::default()
+        };
+
+        assert_eq!(
+            locale.format(",d").unwrap().format(1234567.0),
+            "\u{661}\u{662},\u{663}\u{664},\u{665}\u{666}\u{667}"
+        );
+        let ungrouped = FormatLocale {
+            grouping: vec![],
+            ..FormatLocale::default()
+        };
+        assert_eq!(ungrouped.format(",d").unwrap().format(1234567.0), "1234567");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserializes_d3_locale_files() {
+        let locale: FormatLocale = serde_json::from_str(
+            r#"{
+                "decimal": ",",
+                "thousands": ".",
+                "grouping": [3],
+                "currency": ["", "\u00a0€"]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(locale, FormatLocale::de_de());
+        let bare: FormatLocale = serde_json::from_str("{}").unwrap();
+        assert_eq!(
+            bare.format(",.1f").unwrap().format(-1234.5),
+            "\u{2212}1234.5"
+        );
+    }
+}
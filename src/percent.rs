@@ -0,0 +1,121 @@
+use crate::{TickRange, MAX_TICKS};
+use alloc::vec::Vec;
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// The multiples of powers of ten that percentage steps are chosen from, so that quarters such
+/// as 25% and 75% are nice.
+const PERCENT_MULTIPLES: [f64; 4] = [1.0, 2.0, 2.5, 5.0];
+
+/// Like [`ticks`](crate::ticks) but for a domain of fractions, such as 0 to 1, choosing ticks that
+/// are nice percentages rather than nice fractions.
+///
+/// The step is 1, 2, 2.5 or 5 times a power of ten percent, nearest the exact step on a log scale
+/// as [`ticks_with_multiples`](crate::ticks_with_multiples) chooses it, so that 0 to 1 with a
+/// `count` of 4 gives quarters. Each tick is the fraction nearest its exact percentage divided by
+/// 100, so `0.07` rather than `7.000000000000001 / 100`. Format the ticks with
+/// [`TickNotation::Percent`](crate::TickNotation::Percent).
+///
+/// ```
+/// use axis_ticks::percent_ticks;
+///
+/// assert_eq!(percent_ticks(0.0, 1.0, 4), [0.0, 0.25, 0.5, 0.75, 1.0]);
+/// assert_eq!(percent_ticks(0.0, 0.1, 10)[7], 0.07);
+/// ```
+pub fn percent_ticks<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Vec<T> {
+    let range = percent_range(start, stop, count);
+    (0..range.len()).map(|i| range.get(i)).collect()
+}
+
+/// The range of [`percent_ticks`], whose step is a fraction rather than a percentage.
+pub(crate) fn percent_range<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> TickRange<T> {
+    let hundred = T::from_f64(100.0).unwrap();
+    let multiples = PERCENT_MULTIPLES.map(|multiple| T::from_f64(multiple).unwrap());
+    let range = TickRange::with_multiples(
+        start * hundred,
+        stop * hundred,
+        count,
+        MAX_TICKS,
+        Some(&multiples),
+    );
+
+    match range.step {
+        Some((mantissa, exponent)) => TickRange {
+            step: Some((mantissa, exponent - 2)),
+            ..range
+        },
+        // A point domain has its single tick at `start`, not at its percentage.
+        None => TickRange {
+            first: start,
+            ..range
+        },
+    }
+}
+
+/// The decimal places the percentages of [`percent_ticks`] need to be told apart.
+pub(crate) fn percent_decimals(start: f64, stop: f64, count: usize) -> usize {
+    let (mut mantissa, mut exponent) = match percent_range(start, stop, count).step {
+        Some(step) => step,
+        None => return 0,
+    };
+
+    // Back in percent, as the fewest digits of an integer mantissa.
+    exponent += 2;
+    while mantissa >= 10.0 && mantissa % 10.0 == 0.0 {
+        mantissa /= 10.0;
+        exponent += 1;
+    }
+    (-exponent).max(0) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ticks;
+
+    #[test]
+    fn chooses_nice_percentages() {
+        assert_eq!(ticks(0.0, 1.0, 4), [0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+        assert_eq!(percent_ticks(0.0, 1.0, 4), [0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(percent_ticks(0.0, 1.0, 10).len(), 11);
+        assert_eq!(percent_ticks(1.0, 0.0, 2), [1.0, 0.5, 0.0]);
+        assert_eq!(percent_ticks(-0.05, 0.05, 4)[1], -0.025);
+    }
+
+    #[test]
+    fn ticks_are_the_nearest_fractions() {
+        let ticks = percent_ticks(0.0, 0.1, 10);
+
+        assert_eq!(ticks.len(), 11);
+        for (i, &tick) in ticks.iter().enumerate() {
+            assert_eq!(tick, i as f64 / 100.0);
+        }
+        assert_eq!(percent_ticks(0.0, 0.001f32, 4)[1], 0.00025);
+    }
+
+    #[test]
+    fn counts_the_decimals_of_the_percentages() {
+        assert_eq!(percent_decimals(0.0, 1.0, 4), 0);
+        assert_eq!(percent_decimals(0.0, 0.1, 4), 1);
+        assert_eq!(percent_decimals(0.0, 0.001, 10), 2);
+        assert_eq!(percent_decimals(0.0, 10.0, 5), 0);
+    }
+
+    #[test]
+    fn handles_degenerate_arguments() {
+        assert_eq!(percent_ticks(0.3, 0.3, 10), [0.3]);
+        assert!(percent_ticks(0.0, 1.0, 0).is_empty());
+        assert!(percent_ticks(f64::NAN, 1.0, 10).is_empty());
+        assert_eq!(percent_decimals(0.3, 0.3, 10), 0);
+    }
+}
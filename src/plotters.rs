@@ -0,0 +1,155 @@
+use crate::{minor::range_minor_ticks, tick_format, TickNotation, TickRange};
+use ::plotters::coord::{
+    ranged1d::{KeyPointHint, NoDefaultFormatting, Ranged, ReversibleRanged, ValueFormatter},
+    types::RangedCoordf64,
+};
+use alloc::{string::String, vec::Vec};
+use core::ops::Range;
+
+/// A `plotters` coordinate for `f64` values whose grid lines and labels are placed by
+/// [`ticks`](crate::ticks) rather than by plotters' own key points, and labeled by
+/// [`tick_format`].
+///
+/// The bold grid lines are the ticks for the range's count, using a coarser step if there would
+/// be more of them than the chart asks for with `x_labels` or `y_labels`. The light grid lines
+/// add the conventional [`minor_ticks`](crate::minor_ticks) of that step, if as many fit.
+///
+/// ```
+/// use axis_ticks::TickedRange;
+/// use plotters::coord::ranged1d::{BoldPoints, Ranged, ValueFormatter};
+///
+/// let x = TickedRange::new(0.0..2e-3).count(4);
+///
+/// assert_eq!(x.key_points(BoldPoints(10)), [0.0, 5e-4, 1e-3, 1.5e-3, 2e-3]);
+/// assert_eq!(x.format_ext(&5e-4), "0.0005");
+/// ```
+///
+/// Pass the range to `ChartBuilder::build_cartesian_2d` in place of a `Range<f64>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TickedRange {
+    range: Range<f64>,
+    count: usize,
+    notation: TickNotation,
+}
+
+impl TickedRange {
+    /// Creates a coordinate over `range` with a count of 10 as in d3, labeled in fixed-point.
+    pub fn new(range: Range<f64>) -> Self {
+        TickedRange {
+            range,
+            count: 10,
+            notation: TickNotation::Fixed,
+        }
+    }
+
+    /// Sets the approximate number of bold grid lines, as for [`ticks`](crate::ticks).
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Sets the notation the labels are written in.
+    pub fn notation(mut self, notation: TickNotation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    fn coord(&self) -> RangedCoordf64 {
+        self.range.clone().into()
+    }
+
+    /// The ascending ends of the range.
+    fn bounds(&self) -> (f64, f64) {
+        let Range { start, end } = self.range;
+        if end < start {
+            (end, start)
+        } else {
+            (start, end)
+        }
+    }
+}
+
+impl From<Range<f64>> for TickedRange {
+    fn from(range: Range<f64>) -> Self {
+        TickedRange::new(range)
+    }
+}
+
+impl Ranged for TickedRange {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        self.coord().map(value, limit)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<f64> {
+        let (lo, hi) = self.bounds();
+        let majors = TickRange::with_limit(lo, hi, self.count, hint.bold_points());
+        let mut points: Vec<f64> = (0..majors.len()).map(|i| majors.get(i)).collect();
+
+        if hint.max_num_points() > points.len() {
+            let minors = range_minor_ticks(&majors, lo, hi, None);
+            if points.len() + minors.len() <= hint.max_num_points() {
+                points.extend(minors);
+                points.sort_by(f64::total_cmp);
+            }
+        }
+        points.truncate(hint.max_num_points());
+
+        points
+    }
+
+    fn range(&self) -> Range<f64> {
+        self.range.clone()
+    }
+}
+
+impl ReversibleRanged for TickedRange {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<f64> {
+        self.coord().unmap(input, limit)
+    }
+}
+
+impl ValueFormatter<f64> for TickedRange {
+    fn format_ext(&self, value: &f64) -> String {
+        let Range { start, end } = self.range;
+        tick_format(start, end, self.count, self.notation).format(*value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::plotters::coord::ranged1d::{BoldPoints, LightPoints};
+
+    #[test]
+    fn uses_the_ticks_as_key_points() {
+        let x = TickedRange::new(0.0..1.0).count(5);
+
+        assert_eq!(x.key_points(BoldPoints(10)), [0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+        assert_eq!(x.key_points(BoldPoints(3)), [0.0, 0.5, 1.0]);
+        assert_eq!(
+            TickedRange::new(1.0..0.0)
+                .count(5)
+                .key_points(BoldPoints(10)),
+            [0.0, 0.2, 0.4, 0.6, 0.8, 1.0]
+        );
+        assert!(x.key_points(BoldPoints(0)).is_empty());
+        assert_eq!(x.key_points(LightPoints::new(10, 100)).len(), 21);
+        assert_eq!(x.key_points(LightPoints::new(10, 10)).len(), 6);
+    }
+
+    #[test]
+    fn maps_and_labels_values() {
+        let x = TickedRange::new(0.0..2500.0).count(5);
+
+        assert_eq!(x.map(&1250.0, (0, 100)), 50);
+        assert_eq!(x.unmap(50, (0, 100)), Some(1250.0));
+        assert_eq!(x.format_ext(&500.0), "500");
+        assert_eq!(
+            x.notation(TickNotation::SiPrefix).format_ext(&500.0),
+            "0.5k"
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use crate::ticks;
+use alloc::{vec, vec::Vec};
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// Generates ticks for a power scale, as used for area-proportional bubble axes.
+///
+/// The ticks are nicely rounded in the transformed space `x^exponent`, applied symmetrically to
+/// negative values, and returned in data space. An `exponent` of 0.5 gives a square root scale, so
+/// `pow_ticks(0.0, 100.0, 10, 0.5)` is the squares `[0.0, 1.0, 4.0, ..., 100.0]`. Note that d3's
+/// `scalePow` ticks the untransformed domain, which [`ticks`] already does. Returns an empty `Vec`
+/// if `exponent` is zero or not finite.
+pub fn pow_ticks<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+    exponent: T,
+) -> Vec<T> {
+    if exponent.is_zero() || !exponent.is_finite() {
+        return vec![];
+    }
+
+    let inverse = T::one() / exponent;
+    ticks(
+        signed_pow(start, exponent),
+        signed_pow(stop, exponent),
+        count,
+    )
+    .into_iter()
+    .map(|tick| signed_pow(tick, inverse))
+    .collect()
+}
+
+fn signed_pow<T: Float>(x: T, exponent: T) -> T {
+    if x < T::zero() {
+        -(-x).powf(exponent)
+    } else {
+        x.powf(exponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticks_the_transformed_domain() {
+        let squares: Vec<f64> = (0..=10).map(|i| f64::from(i * i)).collect();
+        let ticks = pow_ticks(0.0, 100.0, 10, 0.5);
+
+        assert_eq!(ticks.len(), squares.len());
+        for (tick, square) in ticks.iter().zip(&squares) {
+            assert!((tick - square).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn ticks_negative_and_reversed_domains_symmetrically() {
+        let ticks = pow_ticks(4.0, -4.0, 4, 0.5);
+        let expected = [4.0, 1.0, 0.0, -1.0, -4.0];
+
+        assert_eq!(ticks.len(), expected.len());
+        for (tick, expected) in ticks.iter().zip(&expected) {
+            assert!((tick - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn matches_ticks_for_an_exponent_of_one() {
+        assert_eq!(pow_ticks(-0.125, 0.25, 10, 1.0), ticks(-0.125, 0.25, 10));
+    }
+
+    #[test]
+    fn returns_empty_vec_for_a_zero_exponent() {
+        assert!(pow_ticks(0.0, 1.0, 10, 0.0).is_empty());
+        assert!(pow_ticks(0.0, 1.0, 10, f64::NAN).is_empty());
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{ticks, TickRange};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// The number of significant decimal digits an `f32` can represent.
+const F32_SIGNIFICANT_DIGITS: i32 = 7;
+
+/// Rounds `value` to `decimals` decimal places, or returns it unchanged if scaling it by
+/// `10^decimals` overflows, as it is then already exact to that many places.
+pub(crate) fn round_to_decimals(value: f64, decimals: usize) -> f64 {
+    let scale = 10f64.powi(decimals.min(i32::MAX as usize) as i32);
+    if !scale.is_finite() || !(value * scale).is_finite() {
+        return value;
+    }
+    (value * scale).round() / scale
+}
+
+/// Like [`ticks`] but rounds only the first and last ticks to `endpoint_decimals` decimal places,
+/// for labelling the ends of an axis at a coarser display precision. Interior ticks are left
+/// untouched.
+pub fn ticks_round_endpoints(
+    start: f64,
+    stop: f64,
+    count: usize,
+    endpoint_decimals: usize,
+) -> Vec<f64> {
+    let mut ticks = ticks(start, stop, count);
+
+    if let Some(first) = ticks.first_mut() {
+        *first = round_to_decimals(*first, endpoint_decimals);
+    }
+    if let Some(last) = ticks.last_mut() {
+        *last = round_to_decimals(*last, endpoint_decimals);
+    }
+
+    ticks
+}
+
+/// The number of decimal places needed to label the ticks between `start` and `stop` without
+/// losing precision, derived from the step of [`ticks`] like d3-format's `precisionFixed`.
+///
+/// Returns 0 for steps of 1 or more and for domains that produce no ticks.
+pub fn tick_decimals<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> usize {
+    // The step is 1, 2 or 5 times `10^power`, so a negative power is the decimals needed.
+    TickRange::new(start, stop, count)
+        .power()
+        .and_then(|power| usize::try_from(-power).ok())
+        .unwrap_or(0)
+}
+
+/// Like [`tick_decimals`] but capped at the roughly 7 significant digits an `f32` can hold, so
+/// labels for domains far from zero don't show spurious trailing digits.
+pub fn tick_decimals_f32(start: f32, stop: f32, count: usize) -> usize {
+    let decimals = tick_decimals(start, stop, count);
+
+    let magnitude = start.abs().max(stop.abs());
+    if magnitude == 0.0 || !magnitude.is_finite() {
+        return decimals;
+    }
+
+    let exponent = magnitude.log10().floor() as i32;
+    let max_decimals = (F32_SIGNIFICANT_DIGITS - 1 - exponent).max(0) as usize;
+    decimals.min(max_decimals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticks_round_endpoints_rounds_only_the_endpoints() {
+        let exact = ticks(0.125, 0.25, 10);
+        let rounded = ticks_round_endpoints(0.125, 0.25, 10, 1);
+
+        assert_eq!(rounded.len(), exact.len());
+        assert_eq!(rounded[0], 0.1);
+        assert_eq!(rounded[rounded.len() - 1], 0.3);
+        assert_eq!(rounded[1..rounded.len() - 1], exact[1..exact.len() - 1]);
+    }
+
+    #[test]
+    fn ticks_round_endpoints_handles_short_tick_sets() {
+        assert_eq!(ticks_round_endpoints(1.23, 1.23, 1, 1), [1.2]);
+        assert!(ticks_round_endpoints(0.0, 1.0, 0, 1).is_empty());
+    }
+
+    #[test]
+    fn ticks_round_endpoints_keeps_endpoints_that_cannot_be_scaled() {
+        assert_eq!(ticks_round_endpoints(0.0, 1.0, 2, 400), [0.0, 0.5, 1.0]);
+        assert_eq!(
+            ticks_round_endpoints(1e300, 2e300, 2, 10),
+            [1e300, 1.5e300, 2e300]
+        );
+    }
+
+    #[test]
+    fn tick_decimals_is_derived_from_the_step() {
+        assert_eq!(tick_decimals(0.0, 1.0, 10), 1);
+        assert_eq!(tick_decimals(0.0, 1.0, 20), 2);
+        assert_eq!(tick_decimals(0.0, 1.0, 5), 1);
+        assert_eq!(tick_decimals(0.0, 1.0, 1), 0);
+        assert_eq!(tick_decimals(0.125, 0.25, 10), 2);
+        assert_eq!(tick_decimals(1.0, 0.0, 10), 1);
+        assert_eq!(tick_decimals(0.0, 100.0, 10), 0);
+        assert_eq!(tick_decimals(0.0, 1e-6, 10), 7);
+    }
+
+    #[test]
+    fn tick_decimals_follows_the_capped_step() {
+        assert_eq!(tick_decimals(0.0, 1.0, 1_000_000), 4);
+        assert_eq!(tick_decimals(0.0, 1.0, usize::MAX), 4);
+    }
+
+    #[test]
+    fn tick_decimals_is_zero_without_ticks() {
+        assert_eq!(tick_decimals(0.0, 1.0, 0), 0);
+        assert_eq!(tick_decimals(1.0, 1.0, 10), 0);
+        assert_eq!(tick_decimals(f64::NAN, 1.0, 10), 0);
+    }
+
+    #[test]
+    fn tick_decimals_f32_is_capped_at_f32_precision() {
+        assert_eq!(tick_decimals(1000.0f64, 1000.001, 10), 4);
+        assert_eq!(tick_decimals_f32(1000.0, 1000.001, 10), 3);
+        assert_eq!(tick_decimals_f32(0.0, 1.0, 10), 1);
+        assert_eq!(tick_decimals_f32(0.0, 1e-6, 10), 7);
+    }
+}
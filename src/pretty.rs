@@ -0,0 +1,200 @@
+use crate::{lit, MAX_TICKS};
+use alloc::{vec, vec::Vec};
+use num_traits::{cast::FromPrimitive, float::Float};
+
+/// How far the ends may stray from a multiple of the unit and still count as on it.
+const ROUNDING_EPS: f64 = 1e-10;
+/// R's `high.u.bias`, favouring larger units.
+const HIGH_U_BIAS: f64 = 1.5;
+/// R's `u5.bias`, favouring a unit of 5 over 2.
+const U5_BIAS: f64 = 0.5 + 1.5 * HIGH_U_BIAS;
+/// R's `shrink.sml`, the factor a tiny domain's scale is shrunk by.
+const SHRINK_SML: f64 = 0.75;
+
+/// Generates ticks identical to R's `pretty(c(start, stop), n = count)`, for porting R graphics.
+///
+/// Unlike [`ticks`](crate::ticks), the ticks always cover the domain, so the first is at or below
+/// `start` and the last at or above `stop`, and R's tie-breaking favours larger steps. As in R,
+/// the ticks are ascending even if `stop < start`, and a point domain gets ticks around it.
+/// Returns an empty `Vec` if `count` is zero or the domain is not finite.
+///
+/// The one place this differs from R is that `count` is capped at half of [`MAX_TICKS`], as R's
+/// unit can be as fine as the exact step divided by 1.4, so that there are never more than
+/// [`MAX_TICKS`] ticks. R goes on to allocate as many as asked for.
+///
+/// ```
+/// use axis_ticks::pretty;
+///
+/// assert_eq!(pretty(1.0, 9.0, 5), [0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+/// ```
+pub fn pretty<T: Float + FromPrimitive>(start: T, stop: T, count: usize) -> Vec<T> {
+    if count == 0 || !start.is_finite() || !stop.is_finite() {
+        return vec![];
+    }
+    let count = count.min(MAX_TICKS / 2);
+
+    let (lo, up) = (start.min(stop), start.max(stop));
+    let min_n = count / 3;
+    let eps: T = lit(ROUNDING_EPS);
+    let (h, h5): (T, T) = (lit(HIGH_U_BIAS), lit(U5_BIAS));
+    let n = lit::<T>(count as f64);
+
+    let dx = up - lo;
+    let (mut cell, small) = if dx.is_zero() && up.is_zero() {
+        (T::one(), true)
+    } else {
+        let cell = lo.abs().max(up.abs());
+        let bound = if h5 >= lit::<T>(1.5) * h + lit(0.5) {
+            T::one() + T::one() / (T::one() + h)
+        } else {
+            T::one() + lit::<T>(1.5) / (T::one() + h5)
+        };
+        let bound = bound * n.max(T::one()) * T::epsilon();
+        (cell, dx < cell * bound * lit(3.0))
+    };
+
+    if small {
+        if cell > lit(10.0) {
+            cell = lit::<T>(9.0) + cell / lit(10.0);
+        }
+        cell = cell * lit(SHRINK_SML);
+        if min_n > 1 {
+            cell = cell / lit(min_n as f64);
+        }
+    } else {
+        cell = dx;
+        if count > 1 {
+            cell = cell / n;
+        }
+    }
+    let subsmall = T::min_positive_value() * lit(2f64.powi(-20));
+    let subsmall = if subsmall.is_zero() {
+        T::min_positive_value()
+    } else {
+        subsmall
+    };
+    if cell < subsmall {
+        cell = subsmall;
+    } else if cell > T::max_value() / lit(1.25) {
+        cell = T::max_value() * lit(0.1);
+    }
+
+    // The unit is 1, 2, 5 or 10 times the base, biased towards the larger units.
+    let base = lit::<T>(10.0).powf(cell.log10().floor());
+    let mut unit = base;
+    let candidate = base * lit(2.0);
+    if candidate - cell < h * (cell - unit) {
+        unit = candidate;
+        let candidate = base * lit(5.0);
+        if candidate - cell < h5 * (cell - unit) {
+            unit = candidate;
+            let candidate = base * lit(10.0);
+            if candidate - cell < h * (cell - unit) {
+                unit = candidate;
+            }
+        }
+    }
+
+    let mut ns = (lo / unit + eps).floor();
+    let mut nu = (up / unit - eps).ceil();
+    while ns * unit > lo + eps * unit {
+        ns = ns - T::one();
+    }
+    while nu * unit < up - eps * unit {
+        nu = nu + T::one();
+    }
+
+    let k = (lit::<T>(0.5) + nu - ns).floor();
+    let min = lit::<T>(min_n as f64);
+    let divisions = if k < min {
+        let missing = min - k;
+        let half = (missing / lit(2.0)).floor();
+        let rest = missing - half;
+        if ns >= T::zero() {
+            nu = nu + half;
+            ns = ns - rest;
+        } else {
+            ns = ns - half;
+            nu = nu + rest;
+        }
+        min
+    } else {
+        k
+    };
+
+    let first = (ns * unit).min(lo);
+    let last = (nu * unit).max(up);
+    let divisions = match divisions.to_usize() {
+        Some(0) | None => return vec![first],
+        Some(divisions) => divisions,
+    };
+
+    // As R's `seq.int`, then snapping values that should be zero.
+    let by = (last - first) / lit(divisions as f64);
+    let zero_below = by * lit(1e-14);
+    let mut values: Vec<T> = (0..divisions)
+        .map(|i| first + by * lit(i as f64))
+        .map(|value| {
+            if value.abs() < zero_below {
+                T::zero()
+            } else {
+                value
+            }
+        })
+        .collect();
+    values.push(last);
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_r() {
+        // R computes the same rounding error, e.g. `pretty(c(0, 1))[4] == 0.6` is `FALSE`.
+        assert_eq!(
+            pretty(0.0, 1.0, 5),
+            [0.0, 0.2, 0.4, 0.6000000000000001, 0.8, 1.0]
+        );
+        assert_eq!(pretty(1.0, 9.0, 5), [0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+        assert_eq!(pretty(1.0, 100.0, 5), [0.0, 20.0, 40.0, 60.0, 80.0, 100.0]);
+        assert_eq!(
+            pretty(-0.125, 0.25, 5),
+            [
+                -0.2,
+                -0.1,
+                0.0,
+                0.10000000000000003,
+                0.2,
+                0.30000000000000004
+            ]
+        );
+        assert_eq!(pretty(0.0, 1e6, 5), [0.0, 2e5, 4e5, 6e5, 8e5, 1e6]);
+    }
+
+    #[test]
+    fn covers_the_domain_in_ascending_order() {
+        assert_eq!(pretty(9.0, 1.0, 5), [0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+        assert_eq!(pretty(0.5, 9.5, 2), [0.0, 5.0, 10.0]);
+    }
+
+    #[test]
+    fn ticks_around_a_point() {
+        assert_eq!(pretty(0.0, 0.0, 5), [-1.0, 0.0]);
+        assert_eq!(pretty(5.0, 5.0, 5), [0.0, 5.0]);
+    }
+
+    #[test]
+    fn returns_empty_vec_without_a_count_or_finite_domain() {
+        assert!(pretty(0.0, 1.0, 0).is_empty());
+        assert!(pretty(f64::NAN, 1.0, 5).is_empty());
+    }
+
+    #[test]
+    fn caps_the_count() {
+        assert!(pretty(0.0, 1.0, 1_000_000).len() <= MAX_TICKS);
+        assert!(pretty(0.0, 1.0, usize::MAX).len() <= MAX_TICKS);
+        assert!((1..100).all(|i| pretty(0.0, 1.0 + i as f64 * 0.137, MAX_TICKS).len() <= MAX_TICKS));
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{tick_values, TickValue};
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::{checked_pow, CheckedMul, FromPrimitive, ToPrimitive};
+
+/// Like [`ticks`](crate::ticks) but for [`Ratio`] values, such as `Ratio<i64>` or `BigRational`,
+/// so that ticks are exact rationals that can be formatted as fractions.
+///
+/// The step and the range of ticks are chosen from the `f64` approximations of `start` and
+/// `stop`, exactly as [`ticks`](crate::ticks) chooses them, and each tick is then computed as an
+/// integer multiple of the step in rational arithmetic, so a step of `0.2` gives ticks of `1/5`,
+/// `2/5` and so on. Ticks that would overflow the integer type are left out.
+///
+/// ```
+/// use axis_ticks::rational_ticks;
+/// use num_rational::Ratio;
+///
+/// let ticks = rational_ticks(Ratio::new(0, 1), Ratio::new(1, 1), 2);
+///
+/// assert_eq!(ticks, [Ratio::new(0, 1), Ratio::new(1, 2), Ratio::new(1, 1)]);
+/// assert_eq!(ticks[1].to_string(), "1/2");
+/// ```
+pub fn rational_ticks<I>(start: Ratio<I>, stop: Ratio<I>, count: usize) -> Vec<Ratio<I>>
+where
+    I: Clone + Integer + CheckedMul + FromPrimitive,
+    Ratio<I>: ToPrimitive,
+{
+    tick_values(start, stop, count)
+}
+
+impl<I> TickValue for Ratio<I>
+where
+    I: Clone + Integer + CheckedMul + FromPrimitive,
+    Ratio<I>: ToPrimitive,
+{
+    fn position(&self) -> Option<f64> {
+        self.to_f64()
+    }
+
+    fn step_multiple(index: i128, mantissa: u32, exponent: i32) -> Option<Self> {
+        let multiple = I::from_i128(index)?.checked_mul(&I::from_u32(mantissa)?)?;
+        let scale = checked_pow(I::from_u8(10)?, exponent.unsigned_abs() as usize)?;
+        Some(if exponent < 0 {
+            Ratio::new(multiple, scale)
+        } else {
+            Ratio::from_integer(multiple.checked_mul(&scale)?)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ticks;
+    use num_rational::BigRational;
+
+    #[test]
+    fn ticks_are_exact_fractions() {
+        let ticks = rational_ticks(Ratio::new(0i64, 1), Ratio::new(1, 1), 5);
+        let labels: Vec<String> = ticks.iter().map(|tick| tick.to_string()).collect();
+
+        assert_eq!(labels, ["0", "1/5", "2/5", "3/5", "4/5", "1"]);
+        assert_eq!(
+            rational_ticks(Ratio::new(-1i64, 8), Ratio::new(1, 4), 10),
+            [
+                Ratio::new(-3, 20),
+                Ratio::new(-1, 10),
+                Ratio::new(-1, 20),
+                Ratio::new(0, 1),
+                Ratio::new(1, 20),
+                Ratio::new(1, 10),
+                Ratio::new(3, 20),
+                Ratio::new(1, 5),
+                Ratio::new(1, 4)
+            ]
+        );
+    }
+
+    #[test]
+    fn matches_float_ticks() {
+        for &(start, stop, count) in &[(0, 10, 5), (-37, 91, 10), (1_000_000, 1_000_000_000, 5)] {
+            let expected: Vec<f64> = ticks(start as f64, stop as f64, count);
+            let ticks: Vec<f64> = rational_ticks(Ratio::from(start), Ratio::from(stop), count)
+                .iter()
+                .map(|tick: &Ratio<i64>| tick.to_f64().unwrap())
+                .collect();
+
+            assert_eq!(ticks, expected);
+        }
+    }
+
+    #[test]
+    fn supports_big_rationals() {
+        let start = BigRational::new(1.into(), 3.into());
+        let stop = BigRational::new(2.into(), 3.into());
+        let ticks = rational_ticks(start, stop, 4);
+
+        assert_eq!(ticks.first(), Some(&BigRational::new(3.into(), 10.into())));
+        assert_eq!(ticks.last(), Some(&BigRational::new(7.into(), 10.into())));
+        assert_eq!(ticks.len(), 5);
+    }
+
+    #[test]
+    fn runs_in_the_direction_of_the_domain() {
+        assert_eq!(
+            rational_ticks(Ratio::new(1i64, 1), Ratio::new(0, 1), 2),
+            [Ratio::new(1, 1), Ratio::new(1, 2), Ratio::new(0, 1)]
+        );
+    }
+
+    #[test]
+    fn handles_degenerate_arguments() {
+        let one = Ratio::new(1i64, 1);
+
+        assert_eq!(rational_ticks(one, one, 10), [one]);
+        assert!(rational_ticks(Ratio::new(0i64, 1), one, 0).is_empty());
+        assert!(rational_ticks(Ratio::new(0i8, 1), Ratio::new(1, 1), 1000).is_empty());
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(feature = "alloc")]
+use crate::ticks;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// A linear mapping from a domain of data values to a range of output values, such as pixels, as
+/// d3's `scaleLinear`.
+///
+/// Either interval may be reversed. Values outside the domain are extrapolated rather than
+/// clamped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearScale<T> {
+    domain: (T, T),
+    range: (T, T),
+}
+
+impl<T: Float + FloatConst + FromPrimitive> LinearScale<T> {
+    /// Creates a scale mapping `domain.0` to `range.0` and `domain.1` to `range.1`.
+    pub fn new(domain: (T, T), range: (T, T)) -> Self {
+        LinearScale { domain, range }
+    }
+
+    /// The data interval mapped from.
+    pub fn domain(&self) -> (T, T) {
+        self.domain
+    }
+
+    /// The output interval mapped to.
+    pub fn range(&self) -> (T, T) {
+        self.range
+    }
+
+    /// Maps a value from the domain to the range. If the domain is a point, every value maps to
+    /// the middle of the range.
+    pub fn map(&self, value: T) -> T {
+        interpolate(self.range, normalize(self.domain, value))
+    }
+
+    /// Maps a value from the range back to the domain. If the range is a point, every value maps
+    /// to the middle of the domain.
+    pub fn invert(&self, value: T) -> T {
+        interpolate(self.domain, normalize(self.range, value))
+    }
+
+    /// Generates approximately `count + 1` ticks over the domain, as [`ticks`].
+    #[cfg(feature = "alloc")]
+    pub fn ticks(&self, count: usize) -> Vec<T> {
+        ticks(self.domain.0, self.domain.1, count)
+    }
+}
+
+/// The position of `value` within `(a, b)`, where `a` is 0 and `b` is 1.
+fn normalize<T: Float + FromPrimitive>((a, b): (T, T), value: T) -> T {
+    let span = b - a;
+    if span.is_zero() {
+        T::from_f64(0.5).unwrap()
+    } else {
+        (value - a) / span
+    }
+}
+
+fn interpolate<T: Float>((a, b): (T, T), t: T) -> T {
+    a + (b - a) * t
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_and_inverts_values() {
+        let scale = LinearScale::new((0.0, 10.0), (100.0, 600.0));
+
+        assert_eq!(scale.domain(), (0.0, 10.0));
+        assert_eq!(scale.range(), (100.0, 600.0));
+        assert_eq!(scale.map(0.0), 100.0);
+        assert_eq!(scale.map(2.5), 225.0);
+        assert_eq!(scale.map(-2.0), 0.0);
+        assert_eq!(scale.invert(225.0), 2.5);
+        assert_eq!(scale.invert(600.0), 10.0);
+    }
+
+    #[test]
+    fn maps_reversed_ranges() {
+        let scale = LinearScale::new((0.0, 1.0), (300.0, 0.0));
+
+        assert_eq!(scale.map(0.25), 225.0);
+        assert_eq!(scale.invert(75.0), 0.75);
+    }
+
+    #[test]
+    fn maps_point_intervals_to_the_middle() {
+        assert_eq!(LinearScale::new((1.0, 1.0), (0.0, 10.0)).map(3.0), 5.0);
+        assert_eq!(LinearScale::new((0.0, 10.0), (4.0, 4.0)).invert(3.0), 5.0);
+    }
+
+    #[test]
+    fn ticks_the_domain() {
+        let scale = LinearScale::new((-0.125, 0.25), (0.0, 960.0));
+
+        assert_eq!(scale.ticks(10), ticks(-0.125, 0.25, 10));
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{
+    angle::{nearest_step, step_multiples},
+    format::format_fixed,
+    ticks,
+};
+use alloc::{format, string::String, vec, vec::Vec};
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// The steps in seconds [`sexagesimal_ticks`] chooses from: 1, 5, 15 and 30 seconds and minutes,
+/// and 1, 3, 6 and 12 hours.
+const SEXAGESIMAL_STEPS: [f64; 12] = [
+    1.0, 5.0, 15.0, 30.0, 60.0, 300.0, 900.0, 1800.0, 3600.0, 10800.0, 21600.0, 43200.0,
+];
+
+const DAY: f64 = 86400.0;
+
+/// Like [`ticks`] but for a domain in seconds, such as seconds of the day, with steps of 1, 5, 15
+/// or 30 seconds or minutes or 1, 3, 6 or 12 hours rather than decimal steps like 400 s, without
+/// the calendar of [`time_ticks`](crate::time_ticks). Label them with [`format_hms`].
+///
+/// The step is the one nearest the exact step on a log scale. Steps finer than a second are those
+/// of [`ticks`], and steps coarser than 12 hours those of [`ticks`] in whole days.
+///
+/// ```
+/// use axis_ticks::sexagesimal_ticks;
+///
+/// assert_eq!(sexagesimal_ticks(0.0, 3600.0, 4), [0.0, 900.0, 1800.0, 2700.0, 3600.0]);
+/// assert_eq!(sexagesimal_ticks(0.0, 86400.0, 4), [0.0, 21600.0, 43200.0, 64800.0, 86400.0]);
+/// ```
+pub fn sexagesimal_ticks<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Vec<T> {
+    if count == 0 || !start.is_finite() || !stop.is_finite() {
+        return vec![];
+    }
+    if start == stop {
+        return vec![start];
+    }
+
+    let day = T::from_f64(DAY).unwrap();
+    let exact = (stop - start).abs() / T::from_usize(count).unwrap();
+    // Halfway on a log scale from a second to the half second of `ticks`, and from 12 hours to a
+    // day.
+    let finest = T::from_f64(core::f64::consts::FRAC_1_SQRT_2).unwrap();
+    let coarsest = T::from_f64(43200.0 * core::f64::consts::SQRT_2).unwrap();
+
+    let days = || {
+        ticks(start / day, stop / day, count)
+            .into_iter()
+            .map(|tick| tick * day)
+            .collect()
+    };
+
+    // An overflowing span, as in `angle_ticks`, is ticked in whole days.
+    if exact < finest {
+        ticks(start, stop, count)
+    } else if exact > coarsest {
+        days()
+    } else {
+        let steps = SEXAGESIMAL_STEPS.map(|step| T::from_f64(step).unwrap());
+        steps[nearest_step(steps.iter().copied(), exact)..]
+            .iter()
+            .find_map(|&step| step_multiples(start, stop, step, |index| index * step))
+            .unwrap_or_else(days)
+    }
+}
+
+/// Formats a number of seconds as hours, minutes and seconds, such as `7:05:00` or `-0:00:02.5`,
+/// with `decimals` decimal places of seconds. Hours are not wrapped at a day.
+///
+/// ```
+/// use axis_ticks::format_hms;
+///
+/// assert_eq!(format_hms(25500.0, 0), "7:05:00");
+/// assert_eq!(format_hms(90000.0, 0), "25:00:00");
+/// assert_eq!(format_hms(0.25, 2), "0:00:00.25");
+/// ```
+pub fn format_hms(seconds: f64, decimals: usize) -> String {
+    if !seconds.is_finite() {
+        return format!("{}", seconds);
+    }
+
+    let scale = 10f64.powi(decimals as i32);
+    let total = (seconds.abs() * scale).round() / scale;
+    let hours = (total / 3600.0).floor();
+    let minutes = ((total - hours * 3600.0) / 60.0).floor();
+    let seconds_part = total - hours * 3600.0 - minutes * 60.0;
+
+    let sign = if seconds < 0.0 && total != 0.0 {
+        "-"
+    } else {
+        ""
+    };
+    let width = if decimals == 0 { 2 } else { decimals + 3 };
+    format!(
+        "{}{}:{:02}:{:0>width$}",
+        sign,
+        hours,
+        minutes,
+        format_fixed(seconds_part, decimals),
+        width = width
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MAX_TICKS;
+
+    #[test]
+    fn steps_on_the_sexagesimal_ladder() {
+        assert_eq!(
+            sexagesimal_ticks(0.0, 2000.0, 5),
+            [0.0, 300.0, 600.0, 900.0, 1200.0, 1500.0, 1800.0]
+        );
+        assert_eq!(
+            sexagesimal_ticks(0.0, 60.0, 4),
+            [0.0, 15.0, 30.0, 45.0, 60.0]
+        );
+        assert_eq!(
+            sexagesimal_ticks(0.0, 36000.0, 3),
+            [0.0, 10800.0, 21600.0, 32400.0]
+        );
+        assert_eq!(sexagesimal_ticks(0.0, 7.0 * DAY, 7)[1], DAY);
+        assert_eq!(sexagesimal_ticks(0.0, 2.0, 4), [0.0, 0.5, 1.0, 1.5, 2.0]);
+        assert_eq!(sexagesimal_ticks(120.0f32, 0.0, 2), [120.0, 60.0, 0.0]);
+        assert!(sexagesimal_ticks(0.0, 60.0, 0).is_empty());
+        assert!(sexagesimal_ticks(f64::INFINITY, 60.0, 4).is_empty());
+        assert_eq!(sexagesimal_ticks(60.0, 60.0, 4), [60.0]);
+        assert_eq!(sexagesimal_ticks(-f64::MAX, f64::MAX, 4).len(), 5);
+        assert!(sexagesimal_ticks(0.0, 1e8, usize::MAX).len() <= MAX_TICKS);
+        assert!(sexagesimal_ticks(0.0, 1e8, 1_000_000).len() <= MAX_TICKS);
+    }
+
+    #[test]
+    fn formats_hours_minutes_and_seconds() {
+        assert_eq!(format_hms(0.0, 0), "0:00:00");
+        assert_eq!(format_hms(59.6, 0), "0:01:00");
+        assert_eq!(format_hms(-150.0, 0), "-0:02:30");
+        assert_eq!(format_hms(-0.001, 1), "0:00:00.0");
+        assert_eq!(format_hms(3725.5, 1), "1:02:05.5");
+        assert_eq!(format_hms(f64::NAN, 0), "NaN");
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{
+    data::{extent, quantile_sorted, sorted_finite},
+    nice, ticks,
+};
+use alloc::{vec, vec::Vec};
+use num_traits::float::Float;
+
+/// The number of bins for a histogram of `data` by Sturges' formula, `ceil(log2(n)) + 1` for `n`
+/// finite values, as d3's `thresholdSturges`.
+///
+/// It depends only on the number of values; `min` and `max` are taken for the same signature as
+/// the other rules, so that any of them can be passed to [`bin_thresholds`].
+pub fn threshold_sturges(data: &[f64], _min: f64, _max: f64) -> usize {
+    let n = finite(data).count();
+    if n == 0 {
+        return 1;
+    }
+    Float::ceil(Float::log2(n as f64)) as usize + 1
+}
+
+/// The number of bins for a histogram of `data` over `[min, max]` by Scott's normal reference
+/// rule, with bins `3.49 σ n^(-1/3)` wide for the sample standard deviation `σ` of `n` finite
+/// values, as d3's `thresholdScott`.
+///
+/// Returns 1 if there are fewer than two values or they are all equal.
+pub fn threshold_scott(data: &[f64], min: f64, max: f64) -> usize {
+    let n = finite(data).count();
+    if n < 2 {
+        return 1;
+    }
+    let mean = finite(data).sum::<f64>() / n as f64;
+    let variance = finite(data)
+        .map(|value| Float::powi(value - mean, 2))
+        .sum::<f64>()
+        / (n - 1) as f64;
+    bins(
+        max - min,
+        3.49 * Float::sqrt(variance) / Float::cbrt(n as f64),
+    )
+}
+
+/// The number of bins for a histogram of `data` over `[min, max]` by the Freedman–Diaconis rule,
+/// with bins `2 IQR n^(-1/3)` wide for the interquartile range of `n` finite values, as d3's
+/// `thresholdFreedmanDiaconis`. It is less swayed by outliers than [`threshold_scott`].
+///
+/// Returns 1 if there are no values or the interquartile range is zero.
+pub fn threshold_freedman_diaconis(data: &[f64], min: f64, max: f64) -> usize {
+    let sorted = sorted_finite(data);
+    if sorted.is_empty() {
+        return 1;
+    }
+
+    let iqr = quantile_sorted(&sorted, 0.75) - quantile_sorted(&sorted, 0.25);
+    bins(max - min, 2.0 * iqr / Float::cbrt(sorted.len() as f64))
+}
+
+/// Generates nicely rounded bin edges for a histogram of `data`, with about as many bins as
+/// `threshold` chooses for its extent, as d3's `bin` does.
+///
+/// The extent is widened by [`nice`] for that many bins and the edges are its [`ticks`], so the
+/// first and last edges enclose every finite value. Non-finite data is skipped.
+///
+/// ```
+/// use axis_ticks::{bin_thresholds, threshold_sturges};
+///
+/// let data: Vec<f64> = (0..100).map(|i| (i as f64 * 0.37).sin() * 4.3).collect();
+///
+/// assert_eq!(threshold_sturges(&data, -4.3, 4.3), 8);
+/// assert_eq!(
+///     bin_thresholds(&data, threshold_sturges),
+///     [-5.0, -4.0, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0, 5.0]
+/// );
+/// ```
+pub fn bin_thresholds<F: Fn(&[f64], f64, f64) -> usize>(data: &[f64], threshold: F) -> Vec<f64> {
+    let (lo, hi) = match extent(data.iter().copied()) {
+        Some(extent) => extent,
+        None => return vec![],
+    };
+    if lo == hi {
+        return vec![lo];
+    }
+
+    let count = threshold(data, lo, hi).max(1);
+    let (lo, hi) = nice(lo, hi, count);
+    ticks(lo, hi, count)
+}
+
+fn finite(data: &[f64]) -> impl Iterator<Item = f64> + '_ {
+    data.iter().copied().filter(|value| value.is_finite())
+}
+
+/// The number of bins `width` wide that cover `span`, or 1 if the width is zero.
+fn bins(span: f64, width: f64) -> usize {
+    if width > 0.0 {
+        (Float::ceil(span / width) as usize).max(1)
+    } else {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_match_d3() {
+        let data = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+
+        assert_eq!(threshold_sturges(&data, 0.0, 10.0), 5);
+        assert_eq!(threshold_scott(&data, 0.0, 10.0), 2);
+        assert_eq!(threshold_freedman_diaconis(&data, 0.0, 10.0), 3);
+        assert_eq!(threshold_sturges(&[1.0], 1.0, 1.0), 1);
+        assert_eq!(threshold_sturges(&[1.0, 2.0, f64::NAN], 1.0, 2.0), 2);
+    }
+
+    #[test]
+    fn rules_handle_degenerate_data() {
+        assert_eq!(threshold_sturges(&[], 0.0, 1.0), 1);
+        assert_eq!(threshold_scott(&[3.0], 3.0, 3.0), 1);
+        assert_eq!(threshold_scott(&[3.0, 3.0], 3.0, 3.0), 1);
+        assert_eq!(threshold_freedman_diaconis(&[], 0.0, 1.0), 1);
+        assert_eq!(
+            threshold_freedman_diaconis(&[0.0, 1.0, 1.0, 1.0, 9.0], 0.0, 9.0),
+            1
+        );
+    }
+
+    #[test]
+    fn bin_thresholds_enclose_the_data() {
+        let data = [0.3, 1.7, 2.2, 2.4, 2.9, 3.1, 9.6];
+
+        let edges = bin_thresholds(&data, threshold_sturges);
+        assert_eq!(edges, [0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+
+        let edges = bin_thresholds(&data, threshold_freedman_diaconis);
+        assert!(edges[0] <= 0.3 && edges[edges.len() - 1] >= 9.6);
+        assert_eq!(bin_thresholds(&data, |_: &[f64], _, _| 2), [0.0, 5.0, 10.0]);
+
+        assert!(bin_thresholds(&[], threshold_scott).is_empty());
+        assert_eq!(bin_thresholds(&[4.0, 4.0], threshold_scott), [4.0]);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{format::format_fixed, minor_ticks, tick_decimals, ticks};
+use alloc::{string::String, vec::Vec};
+use core::cmp::Ordering;
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// Whether a [`Tick`] is a major tick or a minor tick between the majors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TickKind {
+    /// A tick of [`ticks`], which is labeled.
+    Major,
+    /// A tick of [`minor_ticks`], between the majors.
+    Minor,
+}
+
+/// A tick with its label, from [`labeled_ticks`] or [`labeled_ticks_with_minor`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tick<T> {
+    /// The position of the tick.
+    pub value: T,
+    /// The value formatted with the decimal places the major step needs, or empty for a minor
+    /// tick.
+    pub label: String,
+    /// Whether the tick is major or minor.
+    pub kind: TickKind,
+}
+
+/// Like [`ticks`] but returns each tick with a label formatted with just enough decimal places
+/// for the step, as by [`tick_decimals`].
+///
+/// ```
+/// use axis_ticks::labeled_ticks;
+///
+/// let labels: Vec<String> = labeled_ticks(0.0, 1.0, 4)
+///     .into_iter()
+///     .map(|tick| tick.label)
+///     .collect();
+///
+/// assert_eq!(labels, ["0.0", "0.2", "0.4", "0.6", "0.8", "1.0"]);
+/// ```
+pub fn labeled_ticks<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+) -> Vec<Tick<T>> {
+    let decimals = tick_decimals(start, stop, count);
+
+    ticks(start, stop, count)
+        .into_iter()
+        .map(|value| Tick {
+            value,
+            label: format_fixed(value.to_f64().unwrap(), decimals),
+            kind: TickKind::Major,
+        })
+        .collect()
+}
+
+/// Like [`labeled_ticks`] but with the minor ticks of [`minor_ticks`] for the same `subdivisions`
+/// interleaved, in the direction from `start` to `stop`.
+///
+/// Minor ticks are left unlabeled so that they can be drawn without text.
+pub fn labeled_ticks_with_minor<T: Float + FloatConst + FromPrimitive>(
+    start: T,
+    stop: T,
+    count: usize,
+    subdivisions: Option<usize>,
+) -> Vec<Tick<T>> {
+    let mut ticks = labeled_ticks(start, stop, count);
+    ticks.extend(
+        minor_ticks(start, stop, count, subdivisions)
+            .into_iter()
+            .map(|value| Tick {
+                value,
+                label: String::new(),
+                kind: TickKind::Minor,
+            }),
+    );
+
+    ticks.sort_by(|a, b| {
+        let order = a.value.partial_cmp(&b.value).unwrap_or(Ordering::Equal);
+        if stop < start {
+            order.reverse()
+        } else {
+            order
+        }
+    });
+    ticks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MAX_TICKS;
+
+    #[test]
+    fn labels_ticks_with_the_precision_of_the_step() {
+        let ticks = labeled_ticks(-0.15, 0.25, 10);
+
+        assert_eq!(ticks[0].value, -0.15);
+        assert_eq!(ticks[0].label, "-0.15");
+        assert_eq!(ticks[3].label, "0.00");
+        assert!(ticks.iter().all(|tick| tick.kind == TickKind::Major));
+        assert_eq!(labeled_ticks(0.0, 1000.0, 2)[1].label, "500");
+        assert_eq!(labeled_ticks(0.0, 1.0, 1_000_000)[1].label, "0.0002");
+    }
+
+    #[test]
+    fn interleaves_unlabeled_minor_ticks() {
+        let ticks = labeled_ticks_with_minor(2.0, 0.0, 2, Some(2));
+        let values: Vec<f64> = ticks.iter().map(|tick| tick.value).collect();
+        let labels: Vec<&str> = ticks.iter().map(|tick| tick.label.as_str()).collect();
+
+        assert_eq!(values, [2.0, 1.5, 1.0, 0.5, 0.0]);
+        assert_eq!(labels, ["2", "", "1", "", "0"]);
+        assert_eq!(ticks[1].kind, TickKind::Minor);
+    }
+
+    #[test]
+    fn caps_the_number_of_ticks() {
+        for &count in &[1_000_000, usize::MAX] {
+            assert!(labeled_ticks_with_minor(0.0, 1.0, 5, Some(count)).len() <= MAX_TICKS);
+        }
+    }
+
+    #[test]
+    fn returns_empty_vec_without_ticks() {
+        assert!(labeled_ticks(0.0, 1.0, 0).is_empty());
+        assert!(labeled_ticks_with_minor(f64::NAN, 1.0, 5, None).is_empty());
+    }
+}
@@ -0,0 +1,508 @@
+use crate::{tick_increment, tick_step, MAX_TICKS};
+use std::{convert::TryFrom, time::Duration};
+
+const SECOND: i64 = 1000;
+const MINUTE: i64 = 60 * SECOND;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+const WEEK: i64 = 7 * DAY;
+const MONTH: i64 = 30 * DAY;
+const YEAR: i64 = 365 * DAY;
+
+/// The largest `count` the tick generators pass to [`time_tick_interval`]. Neighbouring intervals
+/// are at most five times apart, so the nearest one gives under `2.3 * count` ticks and this keeps
+/// them within [`MAX_TICKS`].
+const MAX_COUNT: usize = MAX_TICKS / 3;
+
+/// Days from 1970-01-01 (a Thursday) to the following Sunday.
+const FIRST_SUNDAY: i64 = 3;
+
+/// The calendar unit a [`TimeInterval`] steps by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// A calendar interval of `step` [`TimeUnit`]s, as chosen by [`time_tick_interval`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeInterval {
+    pub unit: TimeUnit,
+    pub step: u64,
+}
+
+const TICK_INTERVALS: [(TimeUnit, u64, i64); 18] = [
+    (TimeUnit::Second, 1, SECOND),
+    (TimeUnit::Second, 5, 5 * SECOND),
+    (TimeUnit::Second, 15, 15 * SECOND),
+    (TimeUnit::Second, 30, 30 * SECOND),
+    (TimeUnit::Minute, 1, MINUTE),
+    (TimeUnit::Minute, 5, 5 * MINUTE),
+    (TimeUnit::Minute, 15, 15 * MINUTE),
+    (TimeUnit::Minute, 30, 30 * MINUTE),
+    (TimeUnit::Hour, 1, HOUR),
+    (TimeUnit::Hour, 3, 3 * HOUR),
+    (TimeUnit::Hour, 6, 6 * HOUR),
+    (TimeUnit::Hour, 12, 12 * HOUR),
+    (TimeUnit::Day, 1, DAY),
+    (TimeUnit::Day, 2, 2 * DAY),
+    (TimeUnit::Week, 1, WEEK),
+    (TimeUnit::Month, 1, MONTH),
+    (TimeUnit::Month, 3, 3 * MONTH),
+    (TimeUnit::Year, 1, YEAR),
+];
+
+/// Chooses the calendar interval that gives approximately `count` ticks between two UTC
+/// timestamps in milliseconds, following d3-time's `tickInterval`.
+///
+/// Spans too short for whole seconds fall back to nicely rounded milliseconds, and spans longer
+/// than a few years to nicely rounded multiples of years. Returns `None` if either bound is not
+/// finite or `count` is zero.
+pub fn time_tick_interval(start: f64, stop: f64, count: usize) -> Option<TimeInterval> {
+    if !start.is_finite() || !stop.is_finite() || count == 0 {
+        return None;
+    }
+
+    let target = (stop - start).abs() / count as f64;
+    let i = TICK_INTERVALS
+        .iter()
+        .position(|&(_, _, duration)| duration as f64 > target)
+        .unwrap_or(TICK_INTERVALS.len());
+
+    let interval = if i == TICK_INTERVALS.len() {
+        let years = YEAR as f64;
+        TimeInterval {
+            unit: TimeUnit::Year,
+            step: nice_step(start / years, stop / years, count),
+        }
+    } else if i == 0 {
+        TimeInterval {
+            unit: TimeUnit::Millisecond,
+            step: nice_step(start, stop, count),
+        }
+    } else {
+        let (_, _, below) = TICK_INTERVALS[i - 1];
+        let (_, _, above) = TICK_INTERVALS[i];
+        let (unit, step, _) = if target / (below as f64) < (above as f64) / target {
+            TICK_INTERVALS[i - 1]
+        } else {
+            TICK_INTERVALS[i]
+        };
+        TimeInterval { unit, step }
+    };
+
+    Some(interval)
+}
+
+/// Generates ticks between two UTC timestamps in milliseconds that land on natural calendar
+/// boundaries, such as every 15 minutes, every midnight or the first of every quarter.
+///
+/// The interval is chosen by [`time_tick_interval`]. If `stop < start` the ticks are returned in
+/// descending order. At most [`MAX_TICKS`] ticks are returned.
+pub fn time_ticks(start: f64, stop: f64, count: usize) -> Vec<f64> {
+    let count = count.min(MAX_COUNT);
+    let interval = match time_tick_interval(start, stop, count) {
+        Some(interval) => interval,
+        None => return vec![],
+    };
+
+    let reverse = stop < start;
+    let (lo, hi) = if reverse {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+
+    let mut ticks: Vec<f64> = interval_range(interval, lo.ceil() as i64, hi.floor() as i64)
+        .into_iter()
+        .map(|ms| ms as f64)
+        .collect();
+
+    if reverse {
+        ticks.reverse();
+    }
+
+    ticks
+}
+
+/// Like [`time_ticks`] but for UTC timestamps in seconds, such as Unix time, so steps snap to 1, 5,
+/// 15 or 30 seconds, minutes, hours, days and so on rather than decimal steps.
+///
+/// Boundaries are found to the millisecond.
+pub fn timestamp_ticks(start_secs: f64, stop_secs: f64, count: usize) -> Vec<f64> {
+    time_ticks(start_secs * 1000.0, stop_secs * 1000.0, count)
+        .into_iter()
+        .map(|ms| ms / 1000.0)
+        .collect()
+}
+
+/// Generates ticks between two [`Duration`]s at human-friendly steps such as 1, 5, 15 or 30
+/// seconds, minutes or hours, for latency and runtime axes.
+///
+/// The step is chosen as by [`time_tick_interval`] with days and weeks taken as fixed lengths.
+/// Spans long enough for months or years use nicely rounded multiples of days, and spans too
+/// short for whole milliseconds use nicely rounded fractions of one. Ticks are multiples of the
+/// step. If `stop < start` the ticks are returned in descending order. At most [`MAX_TICKS`] ticks
+/// are returned.
+pub fn duration_ticks(start: Duration, stop: Duration, count: usize) -> Vec<Duration> {
+    const NANOS_PER_MILLI: u128 = 1_000_000;
+
+    let reverse = stop < start;
+    let (lo, hi) = if reverse {
+        (stop.as_nanos(), start.as_nanos())
+    } else {
+        (start.as_nanos(), stop.as_nanos())
+    };
+    let millis = |nanos: u128| nanos as f64 / NANOS_PER_MILLI as f64;
+
+    let count = count.min(MAX_COUNT);
+    let interval = match time_tick_interval(millis(lo), millis(hi), count) {
+        Some(interval) => interval,
+        None => return vec![],
+    };
+    let step = u128::from(interval.step);
+    let step = match interval.unit {
+        TimeUnit::Millisecond => tick_step(lo as f64, hi as f64, count).round().max(1.0) as u128,
+        TimeUnit::Second => step * SECOND as u128 * NANOS_PER_MILLI,
+        TimeUnit::Minute => step * MINUTE as u128 * NANOS_PER_MILLI,
+        TimeUnit::Hour => step * HOUR as u128 * NANOS_PER_MILLI,
+        TimeUnit::Day => step * DAY as u128 * NANOS_PER_MILLI,
+        TimeUnit::Week => step * WEEK as u128 * NANOS_PER_MILLI,
+        TimeUnit::Month | TimeUnit::Year => {
+            let days = |nanos: u128| millis(nanos) / DAY as f64;
+            u128::from(nice_step(days(lo), days(hi), count)) * DAY as u128 * NANOS_PER_MILLI
+        }
+    };
+
+    let first = lo.div_ceil(step);
+    let mut ticks: Vec<Duration> = (first..)
+        .map(|k| k * step)
+        .take_while(|&nanos| nanos <= hi)
+        .map(|nanos| {
+            let secs = u64::try_from(nanos / 1_000_000_000).unwrap_or(u64::MAX);
+            Duration::new(secs, (nanos % 1_000_000_000) as u32)
+        })
+        .collect();
+
+    if reverse {
+        ticks.reverse();
+    }
+
+    ticks
+}
+
+/// The boundaries of `interval` within `[lo, hi]` in milliseconds.
+fn interval_range(interval: TimeInterval, lo: i64, hi: i64) -> Vec<i64> {
+    let step = interval.step as i64;
+
+    let fixed = |size: i64| {
+        let size = size * step;
+        let first = lo.div_euclid(size) * size;
+        let first = if first < lo { first + size } else { first };
+        (0..)
+            .map(|i| first + i * size)
+            .take_while(|&t| t <= hi)
+            .collect()
+    };
+
+    let days = || lo.div_euclid(DAY)..=hi.div_euclid(DAY);
+
+    match interval.unit {
+        TimeUnit::Millisecond => fixed(1),
+        TimeUnit::Second => fixed(SECOND),
+        TimeUnit::Minute => fixed(MINUTE),
+        TimeUnit::Hour => fixed(HOUR),
+        TimeUnit::Day => days()
+            .filter(|&d| {
+                let (_, _, day) = civil_from_days(d);
+                (i64::from(day) - 1) % step == 0
+            })
+            .map(|d| d * DAY)
+            .filter(|&t| t >= lo)
+            .collect(),
+        TimeUnit::Week => days()
+            .filter(|&d| {
+                let weeks = d - FIRST_SUNDAY;
+                weeks.rem_euclid(7) == 0 && (weeks / 7).rem_euclid(step) == 0
+            })
+            .map(|d| d * DAY)
+            .filter(|&t| t >= lo)
+            .collect(),
+        TimeUnit::Month => {
+            let (first_year, first_month, _) = civil_from_days(lo.div_euclid(DAY));
+            let (last_year, last_month, _) = civil_from_days(hi.div_euclid(DAY));
+            let first = first_year * 12 + i64::from(first_month) - 1;
+            let last = last_year * 12 + i64::from(last_month) - 1;
+            (first..=last)
+                .filter(|&m| m.rem_euclid(12) % step == 0)
+                .map(|m| days_from_civil(m.div_euclid(12), (m.rem_euclid(12) + 1) as u32, 1) * DAY)
+                .filter(|&t| t >= lo && t <= hi)
+                .collect()
+        }
+        TimeUnit::Year => {
+            let (first, _, _) = civil_from_days(lo.div_euclid(DAY));
+            let (last, _, _) = civil_from_days(hi.div_euclid(DAY));
+            (first..=last)
+                .filter(|&y| y.rem_euclid(step) == 0)
+                .map(|y| days_from_civil(y, 1, 1) * DAY)
+                .filter(|&t| t >= lo && t <= hi)
+                .collect()
+        }
+    }
+}
+
+/// The nicely rounded positive step between `start` and `stop`, at least 1.
+fn nice_step(start: f64, stop: f64, count: usize) -> u64 {
+    let (lo, hi) = if stop < start {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+    let increment = tick_increment(lo, hi, count);
+    let step = if increment > 0.0 {
+        increment
+    } else {
+        -1.0 / increment
+    };
+
+    if step >= 1.0 {
+        step.round() as u64
+    } else {
+        1
+    }
+}
+
+/// Converts days since 1970-01-01 to a proleptic Gregorian `(year, month, day)`.
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month as u32, day as u32)
+}
+
+/// Converts a proleptic Gregorian date to days since 1970-01-01.
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let month = i64::from(month);
+    let doy = (153 * if month > 2 { month - 3 } else { month + 9 } + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(year: i64, month: u32, day: u32, hour: i64, minute: i64) -> f64 {
+        (days_from_civil(year, month, day) * DAY + hour * HOUR + minute * MINUTE) as f64
+    }
+
+    #[test]
+    fn converts_between_days_and_civil_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(11016), (2000, 2, 29));
+        assert_eq!(days_from_civil(2000, 2, 29), 11016);
+        assert_eq!(days_from_civil(2020, 1, 1), 18262);
+    }
+
+    #[test]
+    fn returns_hourly_ticks_over_a_day() {
+        let ticks = time_ticks(utc(2020, 1, 1, 0, 0), utc(2020, 1, 2, 0, 0), 24);
+
+        assert_eq!(ticks.len(), 25);
+        assert_eq!(ticks[0], utc(2020, 1, 1, 0, 0));
+        assert_eq!(ticks[1], utc(2020, 1, 1, 1, 0));
+        assert_eq!(ticks[24], utc(2020, 1, 2, 0, 0));
+    }
+
+    #[test]
+    fn lands_ticks_on_interval_boundaries() {
+        assert_eq!(
+            time_ticks(utc(2020, 1, 1, 10, 7), utc(2020, 1, 1, 11, 7), 4),
+            [
+                utc(2020, 1, 1, 10, 15),
+                utc(2020, 1, 1, 10, 30),
+                utc(2020, 1, 1, 10, 45),
+                utc(2020, 1, 1, 11, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn returns_millisecond_ticks_for_spans_under_a_second() {
+        assert_eq!(
+            time_tick_interval(1000.0, 1500.0, 5),
+            Some(TimeInterval {
+                unit: TimeUnit::Millisecond,
+                step: 100
+            })
+        );
+        assert_eq!(
+            time_ticks(1000.0, 1500.0, 5),
+            [1000.0, 1100.0, 1200.0, 1300.0, 1400.0, 1500.0]
+        );
+        assert_eq!(time_ticks(0.0, 3.0, 10), [0.0, 1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn restarts_multi_day_ticks_each_month() {
+        assert_eq!(
+            time_ticks(utc(2020, 1, 28, 0, 0), utc(2020, 2, 4, 0, 0), 4),
+            [
+                utc(2020, 1, 29, 0, 0),
+                utc(2020, 1, 31, 0, 0),
+                utc(2020, 2, 1, 0, 0),
+                utc(2020, 2, 3, 0, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn returns_weekly_ticks_on_sundays() {
+        assert_eq!(
+            time_ticks(utc(2020, 1, 1, 0, 0), utc(2020, 1, 31, 0, 0), 4),
+            [
+                utc(2020, 1, 5, 0, 0),
+                utc(2020, 1, 12, 0, 0),
+                utc(2020, 1, 19, 0, 0),
+                utc(2020, 1, 26, 0, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn returns_month_and_quarter_ticks() {
+        assert_eq!(
+            time_ticks(utc(2020, 1, 15, 0, 0), utc(2020, 5, 15, 0, 0), 4),
+            [
+                utc(2020, 2, 1, 0, 0),
+                utc(2020, 3, 1, 0, 0),
+                utc(2020, 4, 1, 0, 0),
+                utc(2020, 5, 1, 0, 0)
+            ]
+        );
+        assert_eq!(
+            time_ticks(utc(2020, 1, 1, 0, 0), utc(2021, 1, 1, 0, 0), 4),
+            [
+                utc(2020, 1, 1, 0, 0),
+                utc(2020, 4, 1, 0, 0),
+                utc(2020, 7, 1, 0, 0),
+                utc(2020, 10, 1, 0, 0),
+                utc(2021, 1, 1, 0, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn returns_multi_year_ticks_over_long_spans() {
+        assert_eq!(
+            time_ticks(utc(1990, 6, 1, 0, 0), utc(2031, 1, 1, 0, 0), 4),
+            [
+                utc(2000, 1, 1, 0, 0),
+                utc(2010, 1, 1, 0, 0),
+                utc(2020, 1, 1, 0, 0),
+                utc(2030, 1, 1, 0, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn timestamp_ticks_snap_to_time_steps_in_seconds() {
+        // 2021-01-01T00:00:00Z
+        let start = 1_609_459_200.0;
+
+        assert_eq!(
+            timestamp_ticks(start + 7.0, start + 140.0, 5),
+            [start + 30.0, start + 60.0, start + 90.0, start + 120.0]
+        );
+        assert_eq!(
+            timestamp_ticks(start, start + 3.0 * 3600.0, 3),
+            [start, start + 3600.0, start + 7200.0, start + 10800.0]
+        );
+        assert_eq!(timestamp_ticks(0.0, 0.5, 5), [0.0, 0.1, 0.2, 0.3, 0.4, 0.5]);
+    }
+
+    #[test]
+    fn duration_ticks_use_time_steps() {
+        let secs = Duration::from_secs;
+
+        assert_eq!(
+            duration_ticks(secs(0), secs(120), 4),
+            [secs(0), secs(30), secs(60), secs(90), secs(120)]
+        );
+        assert_eq!(
+            duration_ticks(secs(100), secs(3 * 3600), 3),
+            [secs(3600), secs(7200), secs(10800)]
+        );
+        assert_eq!(
+            duration_ticks(Duration::from_millis(1500), Duration::from_millis(0), 3),
+            [
+                Duration::from_millis(1500),
+                Duration::from_millis(1000),
+                Duration::from_millis(500),
+                Duration::from_millis(0)
+            ]
+        );
+    }
+
+    #[test]
+    fn duration_ticks_handle_sub_millisecond_and_long_spans() {
+        assert_eq!(
+            duration_ticks(Duration::from_micros(0), Duration::from_micros(500), 5),
+            [0, 100, 200, 300, 400, 500].map(Duration::from_micros)
+        );
+
+        let day = 86_400;
+        assert_eq!(
+            duration_ticks(Duration::from_secs(0), Duration::from_secs(1000 * day), 5),
+            [0, 200, 400, 600, 800, 1000].map(|d| Duration::from_secs(d * day))
+        );
+        assert!(duration_ticks(Duration::from_secs(1), Duration::from_secs(1), 0).is_empty());
+    }
+
+    #[test]
+    fn reverses_ticks_for_reversed_domain() {
+        assert_eq!(
+            time_ticks(1500.0, 1000.0, 5),
+            [1500.0, 1400.0, 1300.0, 1200.0, 1100.0, 1000.0]
+        );
+    }
+
+    #[test]
+    fn caps_the_number_of_ticks() {
+        for &count in &[1_000_000, usize::MAX] {
+            let ticks = time_ticks(0.0, 1e12, count);
+            assert!(ticks.len() <= MAX_TICKS);
+            let step = ticks[1] - ticks[0];
+            assert!(ticks[ticks.len() - 1] > 1e12 - step);
+
+            let stop = Duration::from_secs(1_000_000_000);
+            let ticks = duration_ticks(Duration::from_secs(0), stop, count);
+            assert!(ticks.len() <= MAX_TICKS);
+            let step = ticks[1] - ticks[0];
+            assert!(ticks[ticks.len() - 1] > stop - step);
+        }
+    }
+
+    #[test]
+    fn returns_empty_vec_for_invalid_arguments() {
+        assert!(time_ticks(f64::NAN, 1000.0, 5).is_empty());
+        assert!(time_ticks(0.0, f64::INFINITY, 5).is_empty());
+        assert!(time_ticks(0.0, 1000.0, 0).is_empty());
+    }
+}
@@ -0,0 +1,107 @@
+use crate::time::time_ticks;
+use ::time::{OffsetDateTime, PrimitiveDateTime};
+
+/// Generates ticks between two [`OffsetDateTime`]s that land on natural calendar boundaries of
+/// the local time in the offset of `start`.
+///
+/// This is the `time` crate counterpart of `datetime_ticks_tz` for a fixed offset, so daily ticks
+/// fall on local midnight rather than on UTC midnight. The ticks are returned in the offset of
+/// `start`. Sub-millisecond precision is discarded.
+pub fn offset_datetime_ticks(
+    start: OffsetDateTime,
+    stop: OffsetDateTime,
+    count: usize,
+) -> Vec<OffsetDateTime> {
+    let offset = start.offset();
+    let offset_millis = offset.whole_seconds() as f64 * 1000.0;
+    time_ticks(
+        millis(start) + offset_millis,
+        millis(stop) + offset_millis,
+        count,
+    )
+    .into_iter()
+    .filter_map(|local| {
+        let ms = local - offset_millis;
+        OffsetDateTime::from_unix_timestamp_nanos(ms as i128 * 1_000_000)
+            .ok()?
+            .checked_to_offset(offset)
+    })
+    .collect()
+}
+
+/// Generates ticks between two [`PrimitiveDateTime`]s, treating them as UTC, that land on natural
+/// calendar boundaries.
+pub fn primitive_datetime_ticks(
+    start: PrimitiveDateTime,
+    stop: PrimitiveDateTime,
+    count: usize,
+) -> Vec<PrimitiveDateTime> {
+    offset_datetime_ticks(start.assume_utc(), stop.assume_utc(), count)
+        .into_iter()
+        .map(|tick| PrimitiveDateTime::new(tick.date(), tick.time()))
+        .collect()
+}
+
+fn millis(datetime: OffsetDateTime) -> f64 {
+    (datetime.unix_timestamp_nanos() / 1_000_000) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::time::{Date, Month, Time, UtcOffset};
+
+    fn datetime(year: i32, month: Month, day: u8, hour: u8) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(year, month, day).unwrap(),
+            Time::from_hms(hour, 0, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn returns_hourly_ticks_over_a_day() {
+        let start = datetime(2020, Month::January, 1, 0);
+        let stop = datetime(2020, Month::January, 2, 0);
+        let ticks = primitive_datetime_ticks(start, stop, 24);
+
+        assert_eq!(ticks.len(), 25);
+        assert_eq!(ticks[0], start);
+        assert_eq!(ticks[1], datetime(2020, Month::January, 1, 1));
+        assert_eq!(ticks[24], stop);
+    }
+
+    #[test]
+    fn returns_ticks_in_the_offset_of_start() {
+        let offset = UtcOffset::from_hms(2, 0, 0).unwrap();
+        let start = datetime(2001, Month::June, 1, 0).assume_offset(offset);
+        let stop = datetime(2006, Month::June, 1, 0).assume_offset(offset);
+        let ticks = offset_datetime_ticks(start, stop, 5);
+
+        assert_eq!(ticks.len(), 5);
+        assert_eq!(
+            ticks[0],
+            datetime(2002, Month::January, 1, 0).assume_offset(offset)
+        );
+        assert_eq!(ticks[0].offset(), offset);
+    }
+
+    #[test]
+    fn returns_ticks_on_local_midnight() {
+        let offset = UtcOffset::from_hms(-5, -30, 0).unwrap();
+        let start = datetime(2020, Month::March, 1, 9).assume_offset(offset);
+        let stop = datetime(2020, Month::March, 8, 9).assume_offset(offset);
+        let ticks = offset_datetime_ticks(start, stop, 7);
+
+        assert_eq!(ticks.len(), 7);
+        for (tick, day) in ticks.iter().zip(2..) {
+            assert_eq!(
+                *tick,
+                datetime(2020, Month::March, day, 0).assume_offset(offset)
+            );
+        }
+        assert_eq!(
+            offset_datetime_ticks(start, stop.to_offset(UtcOffset::UTC), 7),
+            ticks
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use crate::{multiple, nice, snap, ticks, TickRange, MAX_TICKS};
+use alloc::{vec, vec::Vec};
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// Tracks the extent of a stream of values, such as the samples of a live chart, and keeps a
+/// nicely rounded domain and its ticks that change only when a value escapes the domain.
+///
+/// Values inside the current domain leave it and the ticks untouched, so an axis does not jitter
+/// with every sample and the ticks are not recomputed. The domain only grows; call
+/// [`reset`](ExtentTracker::reset) to shrink it, as when old samples scroll out of view. NaN and
+/// infinities are skipped.
+///
+/// ```
+/// use axis_ticks::ExtentTracker;
+///
+/// let mut tracker = ExtentTracker::new(4);
+/// tracker.extend([1.2, 3.4, 7.9]);
+/// assert_eq!(tracker.domain(), Some((0.0, 8.0)));
+/// assert_eq!(tracker.ticks(), [0.0, 2.0, 4.0, 6.0, 8.0]);
+///
+/// // Inside the domain, so nothing changes.
+/// assert!(!tracker.push(5.5));
+///
+/// // Escapes it, so the domain is widened and re-ticked.
+/// assert!(tracker.push(12.0));
+/// assert_eq!(tracker.domain(), Some((0.0, 12.0)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtentTracker<T> {
+    count: usize,
+    extent: Option<(T, T)>,
+    domain: Option<(T, T)>,
+    ticks: Vec<T>,
+}
+
+impl<T: Float + FloatConst + FromPrimitive> ExtentTracker<T> {
+    /// Creates a tracker with no values yet, aiming for about `count + 1` ticks.
+    pub fn new(count: usize) -> Self {
+        ExtentTracker {
+            count,
+            extent: None,
+            domain: None,
+            ticks: Vec::new(),
+        }
+    }
+
+    /// Adds a value, returning whether it escaped the domain and so changed the domain and the
+    /// ticks.
+    pub fn push(&mut self, value: T) -> bool {
+        if !value.is_finite() {
+            return false;
+        }
+
+        let (lo, hi) = match self.extent {
+            Some((lo, hi)) => (lo.min(value), hi.max(value)),
+            None => (value, value),
+        };
+        self.extent = Some((lo, hi));
+
+        match self.domain {
+            Some((start, stop)) if start <= value && value <= stop => false,
+            _ => {
+                let domain = nice(lo, hi, self.count);
+                self.domain = Some(domain);
+                self.ticks = ticks(domain.0, domain.1, self.count);
+                true
+            }
+        }
+    }
+
+    /// Adds every value, returning whether any of them changed the domain and the ticks.
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, values: I) -> bool {
+        let mut changed = false;
+        for value in values {
+            changed |= self.push(value);
+        }
+        changed
+    }
+
+    /// Forgets every value, leaving no domain or ticks until the next one.
+    pub fn reset(&mut self) {
+        self.extent = None;
+        self.domain = None;
+        self.ticks.clear();
+    }
+
+    /// The finite minimum and maximum of the values so far, or `None` if there are none.
+    pub fn extent(&self) -> Option<(T, T)> {
+        self.extent
+    }
+
+    /// The nicely rounded domain enclosing the values so far, or `None` if there are none.
+    pub fn domain(&self) -> Option<(T, T)> {
+        self.domain
+    }
+
+    /// The ticks of the domain, empty if there are no values yet.
+    pub fn ticks(&self) -> &[T] {
+        &self.ticks
+    }
+}
+
+/// Generates ticks for an interactive axis as it is panned and zoomed, keeping its step until the
+/// ideal step has moved well past the point where [`ticks`] would switch, so that the ticks do not
+/// flicker between steps at the boundaries of the 1, 2, 5 ladder.
+///
+/// [`ticks`] switches from a step of 1 to one of 2 once the exact step `span / count` reaches
+/// `√2`. With a hysteresis of `h` a step of 1 is kept until the exact step passes `√2 × (1 + h)`,
+/// and a step of 2 until it falls below `√2 / (1 + h)`, and so on for the other steps. The
+/// hysteresis is 0.2 by default.
+///
+/// ```
+/// use axis_ticks::{ticks, StableTicker};
+///
+/// let mut ticker = StableTicker::new(10);
+/// assert_eq!(ticker.ticks(0.0, 14.0).len(), 15);
+///
+/// // Zooming out a little would switch `ticks` to a step of 2, but the ticker keeps 1.
+/// assert_eq!(ticks(0.0, 14.5, 10).len(), 8);
+/// assert_eq!(ticker.ticks(0.0, 14.5).len(), 15);
+///
+/// // Zooming out further switches it.
+/// assert_eq!(ticker.ticks(0.0, 18.0), ticks(0.0, 18.0, 10));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StableTicker<T> {
+    count: usize,
+    hysteresis: T,
+    /// The step of the last ticks as `(mantissa, exponent)`, with a mantissa of 1, 2 or 5.
+    step: Option<(T, i32)>,
+}
+
+impl<T: Float + FloatConst + FromPrimitive> StableTicker<T> {
+    /// Creates a ticker aiming for about `count + 1` ticks, with a hysteresis of 0.2.
+    pub fn new(count: usize) -> Self {
+        StableTicker {
+            count,
+            hysteresis: T::from_f64(0.2).unwrap(),
+            step: None,
+        }
+    }
+
+    /// Sets how far, as a fraction, the exact step must move past a switching point of [`ticks`]
+    /// before the step changes. Zero switches exactly where [`ticks`] does.
+    pub fn hysteresis(mut self, hysteresis: T) -> Self {
+        self.hysteresis = hysteresis.max(T::zero());
+        self
+    }
+
+    /// Generates the ticks for the domain, keeping the last step if it is still within the
+    /// hysteresis. If `stop < start` the ticks are in descending order.
+    pub fn ticks(&mut self, start: T, stop: T) -> Vec<T> {
+        if self.count == 0 || !start.is_finite() || !stop.is_finite() {
+            return vec![];
+        }
+        if start == stop {
+            return vec![start];
+        }
+
+        let reverse = stop < start;
+        let (lo, hi) = if reverse {
+            (stop, start)
+        } else {
+            (start, stop)
+        };
+        let exact = (hi - lo) / T::from_usize(self.count).unwrap();
+
+        let step = match self.step {
+            Some(step) if self.keeps(step, exact) && len(lo, hi, step) <= MAX_TICKS => step,
+            _ => match TickRange::new(lo, hi, self.count).step {
+                Some((mantissa, exponent)) if mantissa >= T::from_f64(10.0).unwrap() => {
+                    (mantissa / T::from_f64(10.0).unwrap(), exponent + 1)
+                }
+                Some(step) => step,
+                None => return vec![],
+            },
+        };
+        self.step = Some(step);
+
+        let range = TickRange {
+            first: snap(lo / multiple(step, T::one())).ceil(),
+            len: len(lo, hi, step),
+            step: Some(step),
+            reverse,
+        };
+        (0..range.len()).map(|i| range.get(i)).collect()
+    }
+
+    /// The step of the last ticks, or `None` if there have been none with a step.
+    pub fn step(&self) -> Option<T> {
+        self.step.map(|step| multiple(step, T::one()))
+    }
+
+    /// Forgets the last step, so that the next ticks are those of [`ticks`].
+    pub fn reset(&mut self) {
+        self.step = None;
+    }
+
+    /// Whether `step` is still within the hysteresis for the `exact` step.
+    fn keeps(&self, step: (T, i32), exact: T) -> bool {
+        let (mantissa, _) = step;
+        let value = multiple(step, T::one());
+        // The ratios to the next smaller and larger steps on the 1, 2, 5 ladder.
+        let (smaller, larger) = if mantissa == T::from_f64(2.0).unwrap() {
+            (0.5, 2.5)
+        } else if mantissa == T::from_f64(5.0).unwrap() {
+            (0.4, 2.0)
+        } else {
+            (0.5, 2.0)
+        };
+        let widen = T::one() + self.hysteresis;
+        let lower = value * T::from_f64(smaller).unwrap().sqrt() / widen;
+        let upper = value * T::from_f64(larger).unwrap().sqrt() * widen;
+        lower <= exact && exact < upper
+    }
+}
+
+/// The number of multiples of `step` within `[lo, hi]`.
+fn len<T: Float + FromPrimitive>(lo: T, hi: T, step: (T, i32)) -> usize {
+    let value = multiple(step, T::one());
+    let (first, last) = (snap(lo / value).ceil(), snap(hi / value).floor());
+    (last - first + T::one())
+        .max(T::zero())
+        .to_usize()
+        .unwrap_or(usize::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn re_ticks_only_when_the_domain_is_escaped() {
+        let mut tracker = ExtentTracker::new(10);
+
+        assert!(tracker.push(0.25));
+        assert_eq!(tracker.domain(), Some((0.25, 0.25)));
+        assert_eq!(tracker.ticks(), [0.25]);
+
+        assert!(tracker.push(0.96));
+        assert_eq!(tracker.domain(), Some((0.2, 1.0)));
+        let ticks = tracker.ticks().to_vec();
+
+        assert!(!tracker.extend([0.3, 0.99, 0.2, 1.0]));
+        assert_eq!(tracker.ticks(), ticks);
+        assert_eq!(tracker.extent(), Some((0.2, 1.0)));
+
+        assert!(tracker.extend([0.5, -0.4]));
+        assert_eq!(tracker.domain(), Some((-0.4, 1.0)));
+    }
+
+    #[test]
+    fn skips_non_finite_values_and_resets() {
+        let mut tracker = ExtentTracker::new(5);
+
+        assert!(!tracker.extend([f64::NAN, f64::INFINITY]));
+        assert_eq!(tracker.domain(), None);
+        assert!(tracker.ticks().is_empty());
+
+        tracker.extend([10.0, 90.0]);
+        tracker.reset();
+        assert_eq!(tracker.extent(), None);
+        assert!(tracker.ticks().is_empty());
+        assert!(tracker.push(3.0));
+        assert_eq!(tracker.domain(), Some((3.0, 3.0)));
+    }
+
+    #[test]
+    fn stable_ticker_applies_hysteresis() {
+        let mut ticker = StableTicker::new(10);
+
+        assert_eq!(ticker.ticks(0.0, 14.1), ticks(0.0, 14.1, 10));
+        assert_eq!(ticker.step(), Some(1.0));
+        assert_eq!(ticker.ticks(0.0, 16.9).len(), 17);
+        assert_eq!(
+            ticker.ticks(0.0, 17.0),
+            [0.0, 2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0]
+        );
+        assert_eq!(
+            ticker.ticks(12.0, 0.0),
+            [12.0, 10.0, 8.0, 6.0, 4.0, 2.0, 0.0]
+        );
+        assert_eq!(ticker.ticks(0.0, 14.0).len(), 8);
+        assert_eq!(ticker.ticks(0.0, 11.0).len(), 12);
+
+        ticker.reset();
+        assert_eq!(ticker.ticks(0.0, 14.5), ticks(0.0, 14.5, 10));
+    }
+
+    #[test]
+    fn stable_ticker_keeps_exact_decimal_ticks() {
+        let mut ticker = StableTicker::new(10).hysteresis(0.5);
+
+        assert_eq!(ticker.ticks(0.0, 1.0)[3], 0.3);
+        assert_eq!(ticker.ticks(0.0, 2.0)[7], 0.7);
+        assert_eq!(ticker.step(), Some(0.1));
+
+        let mut exact = StableTicker::new(10).hysteresis(0.0);
+        for stop in [1.0, 1.5, 3.0, 7.0, 20.0, 0.3] {
+            exact.ticks(0.0f32, stop);
+            assert_eq!(exact.step(), Some(crate::tick_step(0.0, stop, 10)));
+        }
+    }
+
+    #[test]
+    fn stable_ticker_handles_degenerate_domains() {
+        let mut ticker = StableTicker::new(5);
+
+        assert!(ticker.ticks(f64::NAN, 1.0).is_empty());
+        assert_eq!(ticker.ticks(2.0, 2.0), [2.0]);
+        assert_eq!(ticker.step(), None);
+        assert!(StableTicker::new(0).ticks(0.0, 1.0).is_empty());
+
+        ticker.ticks(0.0, 1.0);
+        assert!(ticker.ticks(0.0, 1e300).len() <= MAX_TICKS);
+    }
+}
@@ -0,0 +1,130 @@
+use core::fmt;
+use num_traits::float::Float;
+
+/// The reason a tick set failed [`validate_ticks`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The tick at `index` does not continue in the direction from `start` to `stop`.
+    NotMonotonic { index: usize },
+    /// The tick at `index` lies outside `[start, stop]`.
+    OutOfRange { index: usize },
+    /// The gap between the ticks at `index - 1` and `index` differs from the first gap.
+    NonUniformSpacing { index: usize },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::NotMonotonic { index } => {
+                write!(f, "tick {} is out of order", index)
+            }
+            ValidationError::OutOfRange { index } => {
+                write!(f, "tick {} lies outside the domain", index)
+            }
+            ValidationError::NonUniformSpacing { index } => {
+                write!(
+                    f,
+                    "tick {} is not evenly spaced from the previous tick",
+                    index
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+/// Checks that `ticks` is consistent with the domain `[start, stop]`.
+///
+/// The ticks must run in the direction from `start` to `stop`, lie within the domain and be evenly
+/// spaced. Comparisons allow a small relative tolerance for floating point error. Note that
+/// [`ticks`](crate::ticks) can round outwards past the domain, so validate against the niced
+/// domain in that case.
+pub fn validate_ticks<T: Float>(ticks: &[T], start: T, stop: T) -> Result<(), ValidationError> {
+    let (lo, hi) = if stop < start {
+        (stop, start)
+    } else {
+        (start, stop)
+    };
+    let tolerance = T::epsilon().sqrt();
+    let slack = (hi - lo) * tolerance;
+
+    for (index, &tick) in ticks.iter().enumerate() {
+        if !(tick >= lo - slack && tick <= hi + slack) {
+            return Err(ValidationError::OutOfRange { index });
+        }
+    }
+
+    let first_gap = match ticks {
+        [first, second, ..] => *second - *first,
+        _ => return Ok(()),
+    };
+
+    for (index, pair) in ticks.windows(2).enumerate().map(|(i, p)| (i + 1, p)) {
+        let gap = pair[1] - pair[0];
+        let ascending = gap > T::zero();
+        if gap.is_zero() || ascending == (stop < start) {
+            return Err(ValidationError::NotMonotonic { index });
+        }
+        if (gap - first_gap).abs() > first_gap.abs() * tolerance {
+            return Err(ValidationError::NonUniformSpacing { index });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::ticks;
+
+    #[test]
+    fn accepts_generated_ticks() {
+        assert_eq!(validate_ticks(&ticks(0.0, 1.0, 10), 0.0, 1.0), Ok(()));
+        assert_eq!(validate_ticks(&ticks(10.0, -10.0, 5), 10.0, -10.0), Ok(()));
+        assert_eq!(validate_ticks::<f64>(&[], 0.0, 1.0), Ok(()));
+        assert_eq!(validate_ticks(&[0.5], 0.0, 1.0), Ok(()));
+    }
+
+    #[test]
+    fn rejects_non_monotonic_ticks() {
+        assert_eq!(
+            validate_ticks(&[0.0, 0.5, 0.0], 0.0, 1.0),
+            Err(ValidationError::NotMonotonic { index: 2 })
+        );
+        assert_eq!(
+            validate_ticks(&[0.0, 0.5, 1.0], 1.0, 0.0),
+            Err(ValidationError::NotMonotonic { index: 1 })
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_range_ticks() {
+        assert_eq!(
+            validate_ticks(&[0.0, 0.5, 1.0, 1.5], 0.0, 1.0),
+            Err(ValidationError::OutOfRange { index: 3 })
+        );
+        assert_eq!(
+            validate_ticks(&[f64::NAN], 0.0, 1.0),
+            Err(ValidationError::OutOfRange { index: 0 })
+        );
+    }
+
+    #[test]
+    fn rejects_non_uniform_ticks() {
+        assert_eq!(
+            validate_ticks(&[0.0, 0.1, 0.2, 0.4], 0.0, 1.0),
+            Err(ValidationError::NonUniformSpacing { index: 3 })
+        );
+    }
+
+    #[test]
+    fn describes_errors() {
+        assert_eq!(
+            ValidationError::OutOfRange { index: 3 }.to_string(),
+            "tick 3 lies outside the domain"
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{multiple, ticks_int, ticks_uint, TickRange};
+use alloc::{vec, vec::Vec};
+use core::convert::TryFrom;
+
+/// A type that can be ticked by [`tick_values`], such as a float, an integer, a decimal or a
+/// user type like an amount of money.
+///
+/// The step is chosen, as by [`ticks`](crate::ticks), from the [`position`](TickValue::position)s
+/// of the ends of the domain on a linear axis, and each tick is then built by
+/// [`step_multiple`](TickValue::step_multiple) so that the type can represent it exactly. Types
+/// with their own notion of a nice step, such as dates, override [`ticks`](TickValue::ticks).
+///
+/// ```
+/// use axis_ticks::{tick_values, TickValue};
+/// use std::convert::TryFrom;
+///
+/// /// An amount of money in cents.
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Cents(i64);
+///
+/// impl TickValue for Cents {
+///     fn position(&self) -> Option<f64> {
+///         Some(self.0 as f64 / 100.0)
+///     }
+///
+///     fn step_multiple(index: i128, mantissa: u32, exponent: i32) -> Option<Self> {
+///         let scale = 10i128.checked_pow(u32::try_from(exponent + 2).ok()?)?;
+///         Some(Cents(i64::try_from(index * i128::from(mantissa) * scale).ok()?))
+///     }
+/// }
+///
+/// assert_eq!(tick_values(Cents(0), Cents(100), 4), [0, 20, 40, 60, 80, 100].map(Cents));
+/// ```
+pub trait TickValue: Clone {
+    /// The position of the value on a linear axis, or `None` if it has none, such as NaN.
+    fn position(&self) -> Option<f64>;
+
+    /// The value at `index * mantissa * 10^exponent` on the axis of
+    /// [`position`](TickValue::position), or `None` if the type cannot represent it.
+    fn step_multiple(index: i128, mantissa: u32, exponent: i32) -> Option<Self>;
+
+    /// The ticks between `start` and `stop`, in the direction from `start` to `stop`.
+    ///
+    /// By default these are the ticks [`ticks`](crate::ticks) would return for the positions of
+    /// `start` and `stop`, leaving out any the type cannot represent.
+    fn ticks(start: &Self, stop: &Self, count: usize) -> Vec<Self> {
+        let (start_position, stop_position) = match (start.position(), stop.position()) {
+            (Some(start), Some(stop)) => (start, stop),
+            _ => return vec![],
+        };
+        let range = TickRange::new(start_position, stop_position, count);
+        let (mantissa, exponent) = match range.step {
+            Some(step) => step,
+            None => {
+                return if range.len == 0 {
+                    vec![]
+                } else {
+                    vec![start.clone()]
+                }
+            }
+        };
+
+        let first = range.first as i128;
+        let mut ticks: Vec<Self> = (0..range.len)
+            .filter_map(|i| Self::step_multiple(first + i as i128, mantissa as u32, exponent))
+            .collect();
+        if range.reverse {
+            ticks.reverse();
+        }
+
+        ticks
+    }
+}
+
+/// Generates the ticks between `start` and `stop` for any [`TickValue`], with one API for floats,
+/// integers, decimals, dates and user types.
+///
+/// ```
+/// use axis_ticks::tick_values;
+///
+/// assert_eq!(tick_values(0.0, 1.0, 5), [0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+/// assert_eq!(tick_values(0u8, 250, 5), [0, 50, 100, 150, 200, 250]);
+/// ```
+pub fn tick_values<V: TickValue>(start: V, stop: V, count: usize) -> Vec<V> {
+    V::ticks(&start, &stop, count)
+}
+
+macro_rules! impl_float {
+    ($($float:ty),*) => {$(
+        impl TickValue for $float {
+            fn position(&self) -> Option<f64> {
+                if self.is_finite() {
+                    Some(f64::from(*self))
+                } else {
+                    None
+                }
+            }
+
+            fn step_multiple(index: i128, mantissa: u32, exponent: i32) -> Option<Self> {
+                Some(multiple((mantissa as $float, exponent), index as $float))
+            }
+
+            fn ticks(start: &Self, stop: &Self, count: usize) -> Vec<Self> {
+                crate::ticks(*start, *stop, count)
+            }
+        }
+    )*};
+}
+
+impl_float!(f32, f64);
+
+macro_rules! impl_int {
+    ($ticks:ident, $wide:ty, $($int:ty),*) => {$(
+        impl TickValue for $int {
+            fn position(&self) -> Option<f64> {
+                Some(*self as f64)
+            }
+
+            fn step_multiple(index: i128, mantissa: u32, exponent: i32) -> Option<Self> {
+                let scale = 10i128.checked_pow(u32::try_from(exponent).ok()?)?;
+                let value = index.checked_mul(i128::from(mantissa))?.checked_mul(scale)?;
+                <$int>::try_from(value).ok()
+            }
+
+            /// Ticks with integer arithmetic throughout, as [`ticks_int`] and [`ticks_uint`].
+            fn ticks(start: &Self, stop: &Self, count: usize) -> Vec<Self> {
+                $ticks(<$wide>::from(*start), <$wide>::from(*stop), count)
+                    .into_iter()
+                    .filter_map(|tick| <$int>::try_from(tick).ok())
+                    .collect()
+            }
+        }
+    )*};
+}
+
+impl_int!(ticks_int, i64, i8, i16, i32, i64);
+impl_int!(ticks_uint, u64, u8, u16, u32, u64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ticks;
+
+    #[test]
+    fn matches_the_specific_functions() {
+        assert_eq!(tick_values(-0.125, 0.25, 10), ticks(-0.125, 0.25, 10));
+        assert_eq!(tick_values(9.5f32, 1.5, 10), ticks(9.5f32, 1.5, 10));
+        assert_eq!(
+            tick_values(i64::MAX - 1000, i64::MAX, 2),
+            ticks_int(i64::MAX - 1000, i64::MAX, 2)
+        );
+        assert_eq!(tick_values(-100i8, 100, 4), [-100, -50, 0, 50, 100]);
+    }
+
+    #[test]
+    fn the_default_ticks_match_float_ticks() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Metres(f64);
+
+        impl TickValue for Metres {
+            fn position(&self) -> Option<f64> {
+                self.0.position()
+            }
+
+            fn step_multiple(index: i128, mantissa: u32, exponent: i32) -> Option<Self> {
+                f64::step_multiple(index, mantissa, exponent).map(Metres)
+            }
+        }
+
+        for &(start, stop, count) in &[(0.0, 1.0, 10), (-0.125, 0.25, 10), (1e6, -1e6, 3)] {
+            let expected: Vec<Metres> = ticks(start, stop, count).into_iter().map(Metres).collect();
+
+            assert_eq!(tick_values(Metres(start), Metres(stop), count), expected);
+        }
+        assert_eq!(tick_values(Metres(1.0), Metres(1.0), 5), [Metres(1.0)]);
+        assert!(tick_values(Metres(f64::NAN), Metres(1.0), 5).is_empty());
+    }
+
+    #[test]
+    fn leaves_out_unrepresentable_multiples() {
+        assert_eq!(i32::step_multiple(3, 5, -1), None);
+        assert_eq!(u8::step_multiple(-1, 1, 0), None);
+        assert_eq!(u8::step_multiple(5, 5, 1), Some(250));
+    }
+}